@@ -126,9 +126,14 @@ pub struct BountyCommand {
 #[derive(Clone, Debug, Clap)]
 pub enum BountySubCommand {
     PostBounty(bounty::BountyPostCommand),
+    PostOrgBounty(bounty::BountyPostOrgCommand),
     ContributeToBounty(bounty::BountyContributeCommand),
     SubmitForBounty(bounty::BountySubmitCommand),
     ApproveApplication(bounty::BountyApproveCommand),
+    TriggerApprovalVote(bounty::BountyTriggerVoteCommand),
+    RejectSubmission(bounty::BountyRejectCommand),
+    WithdrawSubmission(bounty::BountyWithdrawCommand),
+    CancelBounty(bounty::BountyCancelCommand),
     // storage helpers
     GetBounty(bounty::GetBountyCommand),
     GetSubmission(bounty::GetSubmissionCommand),
@@ -84,6 +84,9 @@ async fn main() -> Result<()> {
         SubCommand::Bounty(BountyCommand { cmd }) => {
             match cmd {
                 BountySubCommand::PostBounty(cmd) => cmd.exec(&client).await?,
+                BountySubCommand::PostOrgBounty(cmd) => {
+                    cmd.exec(&client).await?
+                }
                 BountySubCommand::ContributeToBounty(cmd) => {
                     cmd.exec(&client).await?
                 }
@@ -93,6 +96,18 @@ async fn main() -> Result<()> {
                 BountySubCommand::ApproveApplication(cmd) => {
                     cmd.exec(&client).await?
                 }
+                BountySubCommand::TriggerApprovalVote(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::RejectSubmission(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::WithdrawSubmission(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::CancelBounty(cmd) => {
+                    cmd.exec(&client).await?
+                }
                 BountySubCommand::GetBounty(cmd) => cmd.exec(&client).await?,
                 BountySubCommand::GetSubmission(cmd) => {
                     cmd.exec(&client).await?
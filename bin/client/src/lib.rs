@@ -22,9 +22,13 @@ use substrate_subxt::{
 use sunshine_bounty_client::{
     bank::Bank,
     bounty::Bounty,
+    bounty2::Bounty2,
     donate::Donate,
+    kickback::Kickback,
+    moloch::Moloch,
     org::Org,
     vote::Vote,
+    vote_direct::VoteDirect,
 };
 use sunshine_client_utils::{
     cid::CidBytes,
@@ -77,6 +81,8 @@ impl Org for Runtime {
     type IpfsReference = CidBytes;
     type OrgId = u64;
     type Shares = u64;
+    type ElectionId = u64;
+    type OrderId = u64;
     type Constitution = TextBlock;
 }
 
@@ -87,10 +93,13 @@ impl Vote for Runtime {
     type Percent = sp_runtime::Permill;
     type VoteTopic = TextBlock;
     type VoterView = utils::vote::VoterView;
+    type Conviction = utils::vote::Conviction;
     type VoteJustification = TextBlock;
 }
 
-impl Donate for Runtime {}
+impl Donate for Runtime {
+    type CurrencyId = u32;
+}
 
 impl Bank for Runtime {
     type BankId = u64;
@@ -103,6 +112,34 @@ impl Bounty for Runtime {
     type BountyPost = GithubIssue;
     type SubmissionId = u64;
     type BountySubmission = GithubIssue;
+    type RejectionReason = TextBlock;
+}
+
+impl Moloch for Runtime {
+    type BankId = u64;
+    type SpendId = u64;
+    type MemId = u64;
+}
+
+impl VoteDirect for Runtime {
+    type Cid = CidBytes;
+    type VoteId = u64;
+    type Signal = u128;
+    type Percent = sp_runtime::Permill;
+    type VoterView = utils::vote::VoterView;
+}
+
+impl Bounty2 for Runtime {
+    type BountyId = u64;
+    type BountyPost = GithubIssue;
+    type SubmissionId = u64;
+    type BountySubmission = GithubIssue;
+}
+
+impl Kickback for Runtime {
+    type IpfsReference = CidBytes;
+    type KickbackEventId = u64;
+    type KickbackPost = TextBlock;
 }
 
 impl sunshine_identity_client::Identity for Runtime {
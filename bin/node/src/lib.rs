@@ -1,28 +1,34 @@
 use sc_executor::native_executor_instance;
 use sc_service::ChainType;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{
     sr25519,
     Pair,
     Public,
 };
+use sp_finality_grandpa::AuthorityId as GrandpaId;
 use sp_runtime::traits::{
     IdentifyAccount,
     Verify,
 };
-use sunshine_node_utils::node_service;
 use sunshine_pallet_utils::cid::CidBytes;
 use test_runtime::{
     AccountId,
     AuraConfig,
     Balance,
     BalancesConfig,
+    BankConfig,
     BlockNumber,
+    Bounty2Config,
     GenesisConfig,
     GrandpaConfig,
     GrantConfig,
+    MolochConfig,
     OrgConfig,
+    ProposalsConfig,
     Signature,
     SystemConfig,
+    TokensConfig,
     TreasuryConfig,
     WASM_BINARY,
 };
@@ -39,13 +45,17 @@ native_executor_instance!(
     pub Executor,
     test_runtime::api::dispatch,
     test_runtime::native_version,
+    frame_benchmarking::benchmarking::HostFunctions,
 );
 
-node_service!(
-    test_runtime::opaque::Block,
-    test_runtime::RuntimeApi,
-    Executor
-);
+mod rpc;
+mod service;
+
+pub use service::{
+    new_full,
+    new_full_params,
+    new_light,
+};
 
 /// Specialized `ChainSpec`.
 pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig>;
@@ -181,10 +191,21 @@ pub fn testnet_genesis(
             code: WASM_BINARY.to_vec(),
             changes_trie_config: Default::default(),
         }),
+        bank: Some(BankConfig {
+            spend_poll_frequency: 10,
+        }),
+        bounty2: Some(Bounty2Config {
+            bounty_poll_frequency: 10,
+            submission_poll_frequency: 10,
+        }),
         grant: Some(GrantConfig {
             application_poll_frequency: 10,
             milestone_poll_frequency: 10,
         }),
+        moloch: Some(MolochConfig {
+            spend_poll_frequency: 10,
+            member_poll_frequency: 10,
+        }),
         org: Some(OrgConfig {
             first_organization_supervisor: root_key,
             first_organization_value_constitution: first_org_value_constitution,
@@ -209,6 +230,12 @@ pub fn testnet_genesis(
                 .map(|x| (x.1.clone(), 1))
                 .collect(),
         }),
+        proposals: Some(ProposalsConfig {
+            proposal_poll_frequency: 10,
+        }),
+        tokens: Some(TokensConfig {
+            endowed_accounts: vec![],
+        }),
         treasury: Some(TreasuryConfig {
             minting_interval: treasury_mint_rate.0,
             mint_amount: treasury_mint_rate.1,
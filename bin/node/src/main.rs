@@ -1,8 +1,8 @@
+use frame_benchmarking_cli::BenchmarkCmd;
 use sc_cli::{
     RunCmd,
     Runner,
     RuntimeVersion,
-    Subcommand,
     SubstrateCli,
 };
 use sc_service::{
@@ -22,6 +22,16 @@ pub struct Cli {
     pub run: RunCmd,
 }
 
+#[derive(Debug, StructOpt)]
+pub enum Subcommand {
+    #[structopt(flatten)]
+    Base(sc_cli::Subcommand),
+
+    /// Benchmark runtime pallets
+    #[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
+    Benchmark(BenchmarkCmd),
+}
+
 impl SubstrateCli for Cli {
     fn impl_name() -> String {
         test_node::IMPL_NAME.into()
@@ -74,7 +84,7 @@ impl SubstrateCli for Cli {
 fn main() -> sc_cli::Result<()> {
     let cli = <Cli as SubstrateCli>::from_args();
     match &cli.subcommand {
-        Some(subcommand) => {
+        Some(Subcommand::Base(subcommand)) => {
             let mut runner = cli.create_runner(subcommand)?;
             force_parity_db(&mut runner);
             runner.run_subcommand(subcommand, |config| {
@@ -88,6 +98,20 @@ fn main() -> sc_cli::Result<()> {
                 Ok((client, backend, import_queue, task_manager))
             })
         }
+        Some(Subcommand::Benchmark(cmd)) => {
+            if cfg!(feature = "runtime-benchmarks") {
+                let runner = cli.create_runner(cmd)?;
+                runner.sync_run(|config| {
+                    cmd.run::<test_runtime::Block, test_node::Executor>(config)
+                })
+            } else {
+                println!(
+                    "Benchmarking wasn't enabled when building the node. \
+                     You can enable it with `--features runtime-benchmarks`."
+                );
+                Ok(())
+            }
+        }
         None => {
             let mut runner = cli.create_runner(&cli.run)?;
             force_parity_db(&mut runner);
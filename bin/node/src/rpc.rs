@@ -0,0 +1,196 @@
+//! RPC extension for querying org membership, vote tallies, bounties and banks.
+//!
+//! Results are the SCALE encoded return values of the runtime APIs in
+//! `test_runtime::runtime_api`, so clients decode them with the same types
+//! they already use for storage.
+use codec::Encode;
+use jsonrpc_core::{
+    Error as RpcError,
+    ErrorCode,
+    Result,
+};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{
+    generic::BlockId,
+    traits::Block as BlockT,
+};
+use std::sync::Arc;
+use test_runtime::{
+    opaque::Block,
+    runtime_api::{
+        BankApi,
+        BountyApi,
+        BountyId,
+        OrgApi,
+        OrgId,
+        VoteApi,
+        VoteId,
+    },
+    Balance,
+};
+
+/// Error code returned when a runtime API call fails
+const RUNTIME_ERROR: i64 = 1;
+
+#[rpc]
+pub trait SunshineApi<BlockHash> {
+    /// Members of the org and their shares
+    #[rpc(name = "sunshine_orgMembers")]
+    fn org_members(&self, org: OrgId, at: Option<BlockHash>) -> Result<Bytes>;
+
+    /// Live tally and outcome of the vote
+    #[rpc(name = "sunshine_voteTally")]
+    fn vote_tally(&self, vote_id: VoteId, at: Option<BlockHash>)
+        -> Result<Bytes>;
+
+    /// Winning option of a multi-option vote
+    #[rpc(name = "sunshine_multiVoteWinner")]
+    fn multi_vote_winner(
+        &self,
+        vote_id: VoteId,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes>;
+
+    /// Open bounties with at least `min` total funding
+    #[rpc(name = "sunshine_openBounties")]
+    fn open_bounties(&self, min: Balance, at: Option<BlockHash>)
+        -> Result<Bytes>;
+
+    /// Submissions posted for the bounty
+    #[rpc(name = "sunshine_bountySubmissions")]
+    fn bounty_submissions(
+        &self,
+        bounty_id: BountyId,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes>;
+
+    /// Banks registered for the org
+    #[rpc(name = "sunshine_orgBanks")]
+    fn org_banks(&self, org: OrgId, at: Option<BlockHash>) -> Result<Bytes>;
+}
+
+/// Implements `SunshineApi` by calling into the runtime
+pub struct Sunshine<C> {
+    client: Arc<C>,
+}
+
+impl<C> Sunshine<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> Sunshine<C>
+where
+    C: HeaderBackend<Block>,
+{
+    fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Runtime API call failed".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
+
+impl<C> SunshineApi<<Block as BlockT>::Hash> for Sunshine<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: OrgApi<Block> + VoteApi<Block> + BountyApi<Block> + BankApi<Block>,
+{
+    fn org_members(
+        &self,
+        org: OrgId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let members = self
+            .client
+            .runtime_api()
+            .org_members(&self.block_id(at), org)
+            .map_err(runtime_error)?;
+        Ok(members.encode().into())
+    }
+
+    fn vote_tally(
+        &self,
+        vote_id: VoteId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let tally = self
+            .client
+            .runtime_api()
+            .vote_tally(&self.block_id(at), vote_id)
+            .map_err(runtime_error)?;
+        Ok(tally.encode().into())
+    }
+
+    fn multi_vote_winner(
+        &self,
+        vote_id: VoteId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let winner = self
+            .client
+            .runtime_api()
+            .multi_vote_winner(&self.block_id(at), vote_id)
+            .map_err(runtime_error)?;
+        Ok(winner.encode().into())
+    }
+
+    fn open_bounties(
+        &self,
+        min: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let bounties = self
+            .client
+            .runtime_api()
+            .open_bounties(&self.block_id(at), min)
+            .map_err(runtime_error)?;
+        Ok(bounties.encode().into())
+    }
+
+    fn bounty_submissions(
+        &self,
+        bounty_id: BountyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let submissions = self
+            .client
+            .runtime_api()
+            .bounty_submissions(&self.block_id(at), bounty_id)
+            .map_err(runtime_error)?;
+        Ok(submissions.encode().into())
+    }
+
+    fn org_banks(
+        &self,
+        org: OrgId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let banks = self
+            .client
+            .runtime_api()
+            .org_banks(&self.block_id(at), org)
+            .map_err(runtime_error)?;
+        Ok(banks.encode().into())
+    }
+}
+
+/// Builds the full node RPC extensions
+pub fn create_full<C>(client: Arc<C>) -> jsonrpc_core::IoHandler<sc_rpc::Metadata>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: OrgApi<Block> + VoteApi<Block> + BountyApi<Block> + BankApi<Block>,
+{
+    let mut io = jsonrpc_core::IoHandler::default();
+    io.extend_with(SunshineApi::to_delegate(Sunshine::new(client)));
+    io
+}
@@ -40,6 +40,7 @@ use sp_std::prelude::*;
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use sunshine_pallet_utils::cid::CidBytes;
+use util::traits::ShareInformation;
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
@@ -90,6 +91,9 @@ pub type AccountIndex = u32;
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Identifier for assets held in the tokens module.
+pub type CurrencyId = u32;
+
 /// Index of a transaction in the chain.
 pub type Index = u32;
 
@@ -99,6 +103,8 @@ pub type Hash = sp_core::H256;
 /// Digest item type.
 pub type DigestItem = generic::DigestItem<Hash>;
 
+pub mod runtime_api;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -301,17 +307,58 @@ impl pallet_transaction_payment::Trait for Runtime {
     type WeightToFee = IdentityFee<Balance>;
     type FeeMultiplierUpdate = ();
 }
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: BlockNumber = 7 * DAYS;
+    pub const SupervisorTermLength: BlockNumber = 90 * DAYS;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
 impl org::Trait for Runtime {
     type Event = Event;
     type Cid = CidBytes;
     type OrgId = u64;
     type Shares = u64;
+    type OrgOrigin = org::EnsureOrg<Runtime>;
+    type ElectionId = u64;
+    type SupervisorVote = Vote;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = org::default_weights::SubstrateWeight<Runtime>;
+}
+parameter_types! {
+    pub const ConvictionLockPeriod: BlockNumber = DAYS;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const MaxDelegators: u32 = 4;
+    pub const MaxOptions: u32 = 16;
+    pub const VoteRetentionPeriod: Option<BlockNumber> = Some(28 * DAYS);
 }
 impl vote::Trait for Runtime {
     type Event = Event;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type MaxDelegators = MaxDelegators;
+    type MaxOptions = MaxOptions;
+    type VoteRetentionPeriod = VoteRetentionPeriod;
+    type WeightInfo = vote::default_weights::SubstrateWeight<Runtime>;
+}
+parameter_types! {
+    pub const MinProposalThreshold: Permill = Permill::from_percent(51);
+    pub const ProposalDuration: BlockNumber = 7 * DAYS;
+}
+impl proposals::Trait for Runtime {
+    type Event = Event;
+    type Origin = Origin;
+    type Proposal = Call;
+    type MinProposalThreshold = MinProposalThreshold;
+    type ProposalDuration = ProposalDuration;
+    type WeightInfo = proposals::default_weights::SubstrateWeight<Runtime>;
 }
 parameter_types! {
     pub const MinimumDisputeAmount: u128 = 10;
@@ -321,11 +368,24 @@ impl court::Trait for Runtime {
     type Currency = Balances;
     type DisputeId = u64;
     type MinimumDisputeAmount = MinimumDisputeAmount;
+    type WeightInfo = court::default_weights::SubstrateWeight<Runtime>;
+}
+parameter_types! {
+    pub const NativeCurrencyId: CurrencyId = 0;
+}
+impl tokens::Trait for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type CurrencyId = CurrencyId;
+    type NativeCurrency = Balances;
+    type GetNativeCurrencyId = NativeCurrencyId;
+    type WeightInfo = tokens::default_weights::SubstrateWeight<Runtime>;
 }
 impl drip::Trait for Runtime {
     type Event = Event;
     type DripId = u64;
-    type Currency = Balances;
+    type Currency = Tokens;
+    type WeightInfo = drip::default_weights::SubstrateWeight<Runtime>;
 }
 parameter_types! {
     pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
@@ -337,36 +397,49 @@ impl treasury::Trait for Runtime {
 }
 impl donate::Trait for Runtime {
     type Event = Event;
-    type Currency = Balances;
+    type Currency = Tokens;
+    type WeightInfo = donate::default_weights::SubstrateWeight<Runtime>;
 }
 parameter_types! {
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
     pub const MaxTreasuryPerOrg: u32 = 50;
     pub const MinimumDeposit: u128 = 20;
+    pub const SpendExpiry: BlockNumber = 7 * DAYS;
+    pub const SpendVoteDuration: BlockNumber = 7 * DAYS;
+    pub const MaxBankProposals: u32 = 4;
 }
 impl bank::Trait for Runtime {
     type Event = Event;
-    type Currency = Balances;
+    type Currency = Tokens;
     type BigBank = BigBank;
     type BankId = u64;
     type SpendId = u64;
     type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
     type MinDeposit = MinimumDeposit;
+    type SpendExpiry = SpendExpiry;
+    type SpendVoteDuration = SpendVoteDuration;
+    type MaxBankProposals = MaxBankProposals;
+    type WeightInfo = bank::default_weights::SubstrateWeight<Runtime>;
 }
 parameter_types! {
     pub const Foundation: ModuleId = ModuleId(*b"fundacon");
     pub const MinDeposit: u128 = 10;
     pub const MinContribution: u128 = 5;
+    pub const ApprovalVoteDuration: BlockNumber = 7 * DAYS;
+    pub const RefundRetryPeriod: BlockNumber = DAYS;
 }
 impl bounty::Trait for Runtime {
     type Event = Event;
     type IpfsReference = CidBytes;
-    type Currency = Balances;
+    type Currency = Tokens;
     type BountyId = u64;
     type SubmissionId = u64;
     type Foundation = Foundation;
     type MinDeposit = MinDeposit;
     type MinContribution = MinContribution;
+    type ApprovalVoteDuration = ApprovalVoteDuration;
+    type RefundRetryPeriod = RefundRetryPeriod;
+    type WeightInfo = bounty::default_weights::SubstrateWeight<Runtime>;
 }
 parameter_types! {
     pub const BigFoundation: ModuleId = ModuleId(*b"big/fund");
@@ -381,6 +454,64 @@ impl grant::Trait for Runtime {
     type BigFoundation = BigFoundation;
     type MinDeposit = MinGrantDeposit;
     type MinContribution = MinGrantContribution;
+    type WeightInfo = grant::default_weights::SubstrateWeight<Runtime>;
+}
+parameter_types! {
+    pub const MolochBank: ModuleId = ModuleId(*b"mol/bank");
+    pub const MinMolochDeposit: u128 = 20;
+}
+impl moloch::Trait for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type BigBank = MolochBank;
+    type BankId = u64;
+    type SpendId = u64;
+    type MemId = u64;
+    type MinDeposit = MinMolochDeposit;
+    type WeightInfo = moloch::default_weights::SubstrateWeight<Runtime>;
+}
+impl vote_direct::Trait for Runtime {
+    type Event = Event;
+    type Cid = CidBytes;
+    type VoteId = u64;
+    type Signal = u128;
+    type WeightInfo = vote_direct::default_weights::SubstrateWeight<Runtime>;
+}
+parameter_types! {
+    pub const ChallengePeriod: BlockNumber = DAYS;
+    pub const Bounty2Foundation: ModuleId = ModuleId(*b"bounty/2");
+    pub const MinBounty2Deposit: u128 = 10;
+    pub const MinBounty2Contribution: u128 = 5;
+    pub const MinVetoThreshold: Permill = Permill::from_percent(20);
+    pub const MinRefundThreshold: Permill = Permill::from_percent(20);
+}
+impl bounty2::Trait for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type BountyId = u64;
+    type SubmissionId = u64;
+    type ChallengePeriod = ChallengePeriod;
+    type Foundation = Bounty2Foundation;
+    type MinDeposit = MinBounty2Deposit;
+    type MinContribution = MinBounty2Contribution;
+    type MinVetoThreshold = MinVetoThreshold;
+    type MinRefundThreshold = MinRefundThreshold;
+    type WeightInfo = bounty2::default_weights::SubstrateWeight<Runtime>;
+}
+parameter_types! {
+    pub const EventPool: ModuleId = ModuleId(*b"kik/pool");
+    pub const MinReservationReq: u128 = 10;
+    pub const MaxAttendance: u32 = 1000;
+}
+impl kickback::Trait for Runtime {
+    type Event = Event;
+    type IpfsReference = CidBytes;
+    type Currency = Balances;
+    type KickbackEventId = u64;
+    type EventPool = EventPool;
+    type MinReservationReq = MinReservationReq;
+    type MaxAttendance = MaxAttendance;
+    type WeightInfo = kickback::default_weights::SubstrateWeight<Runtime>;
 }
 
 construct_runtime!(
@@ -397,15 +528,21 @@ construct_runtime!(
         Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Module, Storage},
         // sunshine-bounty modules
-        Org: org::{Module, Call, Config<T>, Storage, Event<T>},
+        Tokens: tokens::{Module, Call, Config<T>, Storage, Event<T>},
+        Org: org::{Module, Call, Config<T>, Storage, Event<T>, Origin<T>},
         Vote: vote::{Module, Call, Storage, Event<T>},
+        Proposals: proposals::{Module, Call, Config<T>, Storage, Event<T>},
         Court: court::{Module, Call, Storage, Event<T>},
         Drip: drip::{Module, Call, Storage, Event<T>},
         Treasury: treasury::{Module, Call, Config<T>, Storage, Event<T>},
         Donate: donate::{Module, Call, Event<T>},
-        Bank: bank::{Module, Call, Storage, Event<T>},
+        Bank: bank::{Module, Call, Config<T>, Storage, Event<T>},
         Bounty: bounty::{Module, Call, Storage, Event<T>},
         Grant: grant::{Module, Call, Config<T>, Storage, Event<T>},
+        Moloch: moloch::{Module, Call, Config<T>, Storage, Event<T>},
+        VoteDirect: vote_direct::{Module, Call, Storage, Event<T>},
+        Bounty2: bounty2::{Module, Call, Config<T>, Storage, Event<T>},
+        Kickback: kickback::{Module, Call, Storage, Event<T>},
     }
 );
 
@@ -550,4 +687,89 @@ impl_runtime_apis! {
             None
         }
     }
+
+    impl runtime_api::OrgApi<Block> for Runtime {
+        fn org_members(org: runtime_api::OrgId) -> Option<runtime_api::Membership> {
+            Org::get_membership_with_shape(org)
+        }
+    }
+
+    impl runtime_api::VoteApi<Block> for Runtime {
+        fn vote_tally(vote_id: runtime_api::VoteId) -> Option<runtime_api::Tally> {
+            Vote::vote_states(vote_id)
+        }
+        fn multi_vote_winner(vote_id: runtime_api::VoteId) -> Option<u32> {
+            Vote::multi_vote_winner(vote_id).ok().flatten()
+        }
+    }
+
+    impl runtime_api::BountyApi<Block> for Runtime {
+        fn open_bounties(min: Balance) -> Vec<(runtime_api::BountyId, runtime_api::Bounty)> {
+            Bounty::open_bounties(min)
+        }
+        fn bounty_submissions(
+            bounty_id: runtime_api::BountyId,
+        ) -> Vec<(runtime_api::SubmissionId, runtime_api::Submission)> {
+            Bounty::bounty_submissions(bounty_id)
+        }
+    }
+
+    impl runtime_api::BankApi<Block> for Runtime {
+        fn org_banks(org: runtime_api::OrgId) -> Vec<runtime_api::BankId> {
+            Bank::get_banks_for_org(org).unwrap_or_default()
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn dispatch_benchmark(
+            pallet: Vec<u8>,
+            benchmark: Vec<u8>,
+            lowest_range_values: Vec<u32>,
+            highest_range_values: Vec<u32>,
+            steps: Vec<u32>,
+            repeat: u32,
+            extra: bool,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{
+                add_benchmark,
+                Benchmarking,
+                BenchmarkBatch,
+            };
+
+            let whitelist: Vec<Vec<u8>> = vec![
+                // Block Number
+                hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef702a5c1b19ab7a04f536c519aca4983ac").to_vec(),
+                // Total Issuance
+                hex_literal::hex!("c2261276cc9d1f8598ea4b6a74b15c2f57c875e4cff74148e4628f264b974c80").to_vec(),
+                // Execution Phase
+                hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef7ff553b5a9862a516939d82b3d3d8661a").to_vec(),
+                // Event Count
+                hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef70a98fdbe9ce6c55837576c60c7af3850").to_vec(),
+                // System Events
+                hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7").to_vec(),
+            ];
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&pallet, &benchmark, &lowest_range_values, &highest_range_values, &steps, repeat, &whitelist, extra);
+
+            add_benchmark!(params, batches, b"tokens", Tokens);
+            add_benchmark!(params, batches, b"org", Org);
+            add_benchmark!(params, batches, b"vote", Vote);
+            add_benchmark!(params, batches, b"proposals", Proposals);
+            add_benchmark!(params, batches, b"court", Court);
+            add_benchmark!(params, batches, b"drip", Drip);
+            add_benchmark!(params, batches, b"donate", Donate);
+            add_benchmark!(params, batches, b"bank", Bank);
+            add_benchmark!(params, batches, b"bounty", Bounty);
+            add_benchmark!(params, batches, b"grant", Grant);
+            add_benchmark!(params, batches, b"moloch", Moloch);
+            add_benchmark!(params, batches, b"vote-direct", VoteDirect);
+            add_benchmark!(params, batches, b"bounty2", Bounty2);
+            add_benchmark!(params, batches, b"kickback", Kickback);
+
+            if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
+            Ok(batches)
+        }
+    }
 }
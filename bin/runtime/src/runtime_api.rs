@@ -0,0 +1,68 @@
+//! Runtime APIs for querying sunshine-bounty state without scanning storage client side
+use crate::{
+    AccountId,
+    Balance,
+    BlockNumber,
+};
+use sp_std::prelude::*;
+use sunshine_pallet_utils::cid::CidBytes;
+use util::{
+    bounty::{
+        BountyInformation,
+        BountySubmission,
+        SubmissionState,
+    },
+    share::WeightedVector,
+    vote::VoteState,
+};
+
+// type aliases
+pub type OrgId = u64;
+pub type Shares = u64;
+pub type VoteId = u64;
+pub type Signal = u64;
+pub type BankId = u64;
+pub type BountyId = u64;
+pub type SubmissionId = u64;
+pub type Membership = WeightedVector<AccountId, Shares>;
+pub type Tally = VoteState<Signal, BlockNumber, CidBytes>;
+pub type Bounty = BountyInformation<BountyId, CidBytes, AccountId, Balance>;
+pub type Submission = BountySubmission<
+    BountyId,
+    SubmissionId,
+    CidBytes,
+    AccountId,
+    Balance,
+    SubmissionState,
+>;
+
+sp_api::decl_runtime_apis! {
+    /// Org membership queries
+    pub trait OrgApi {
+        /// Members of the org and their shares, `None` if the org does not exist
+        fn org_members(org: OrgId) -> Option<Membership>;
+    }
+
+    /// Vote queries
+    pub trait VoteApi {
+        /// Live tally and outcome of the vote, `None` if the vote does not exist
+        fn vote_tally(vote_id: VoteId) -> Option<Tally>;
+        /// Index of the winning option of a multi-option vote, `None` if the vote
+        /// does not exist, no signal was cast or first place is tied
+        fn multi_vote_winner(vote_id: VoteId) -> Option<u32>;
+    }
+
+    /// Bounty queries
+    pub trait BountyApi {
+        /// Open bounties with at least `min` total funding
+        fn open_bounties(min: Balance) -> Vec<(BountyId, Bounty)>;
+        /// Submissions posted for the bounty
+        fn bounty_submissions(bounty_id: BountyId) -> Vec<(SubmissionId, Submission)>;
+    }
+
+    /// Bank queries
+    pub trait BankApi {
+        /// Banks registered for the org
+        fn org_banks(org: OrgId) -> Vec<BankId>;
+    }
+}
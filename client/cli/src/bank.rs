@@ -20,6 +20,7 @@ use sunshine_bounty_client::{
         Bank,
         BankClient,
     },
+    donate::Donate,
     org::Org,
     vote::Vote,
 };
@@ -38,8 +39,9 @@ use sunshine_client_utils::{
 
 #[derive(Clone, Debug, Clap)]
 pub struct OpenCommand {
-    pub seed: u128,
     pub hosting_org: u64,
+    pub currency_id: u32,
+    pub seed: u128,
     pub bank_operator: Option<String>,
     pub percent_threshold: u8,
 }
@@ -52,6 +54,7 @@ impl OpenCommand {
     where
         <R as System>::AccountId: Ss58Codec,
         <R as Org>::OrgId: From<u64> + Display,
+        <R as Donate>::CurrencyId: From<u32> + Display,
         <R as Vote>::Percent: From<Permill>,
         <R as Balances>::Balance: From<u128> + Display,
     {
@@ -76,15 +79,16 @@ impl OpenCommand {
         );
         let event = client
             .open(
-                self.seed.into(),
                 self.hosting_org.into(),
+                self.currency_id.into(),
+                self.seed.into(),
                 bank_operator,
                 threshold_config,
             )
             .await?;
         println!(
-            "Account {} initialized new bank account {:?} with balance {} for Org {} with bank operator {:?}",
-            event.seeder, event.new_bank_id, event.seed, event.hosting_org, event.bank_operator
+            "Account {} initialized new bank account {:?} with balance {} of asset {} for Org {} with bank operator {:?}",
+            event.seeder, event.new_bank_id, event.seed, event.currency_id, event.hosting_org, event.bank_operator
         );
         Ok(())
     }
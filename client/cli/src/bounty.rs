@@ -17,11 +17,16 @@ use substrate_subxt::{
     Runtime,
 };
 use sunshine_bounty_client::{
+    bank::Bank,
     bounty::{
         Bounty,
         BountyClient,
     },
+    donate::Donate,
+    org::Org,
+    vote::Vote,
     GithubIssue,
+    TextBlock,
 };
 use sunshine_client_utils::{
     cid::CidBytes,
@@ -31,7 +36,9 @@ use sunshine_client_utils::{
 #[derive(Clone, Debug, Clap)]
 pub struct BountyPostCommand {
     pub issue_url: String,
+    pub currency_id: u32,
     pub amount: u128,
+    pub expiry: Option<u32>,
 }
 
 impl BountyPostCommand {
@@ -41,6 +48,8 @@ impl BountyPostCommand {
     ) -> Result<()>
     where
         <R as System>::AccountId: Ss58Codec,
+        <R as System>::BlockNumber: From<u32>,
+        <R as Donate>::CurrencyId: From<u32> + Display,
         <R as Balances>::Balance: From<u128> + Display,
         <R as Bounty>::BountyId: Display,
         <R as Bounty>::BountyPost: From<GithubIssue>,
@@ -53,10 +62,63 @@ impl BountyPostCommand {
             issue_number: metadata.issue,
         }
         .into();
-        let event = client.post_bounty(bounty, self.amount.into()).await?;
+        let event = client
+            .post_bounty(
+                bounty,
+                self.currency_id.into(),
+                self.amount.into(),
+                self.expiry.map(|e| e.into()),
+            )
+            .await?;
+        println!(
+            "Depositer with AccountId {} posted new BountyId {}, Balance {} of asset {}",
+            event.depositer, event.id, event.amount, event.currency_id,
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyPostOrgCommand {
+    pub bank_id: u64,
+    pub issue_url: String,
+    pub amount: u128,
+    pub expiry: Option<u32>,
+}
+
+impl BountyPostOrgCommand {
+    pub async fn exec<R: Runtime + Bounty, C: BountyClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as System>::BlockNumber: From<u32>,
+        <R as Balances>::Balance: From<u128> + Display,
+        <R as Org>::OrgId: Display,
+        <R as Bank>::BankId: From<u64> + Display,
+        <R as Bounty>::BountyId: Display,
+        <R as Bounty>::BountyPost: From<GithubIssue>,
+    {
+        let metadata: GithubIssueMetadata =
+            self.issue_url.as_str().try_into()?;
+        let bounty: <R as Bounty>::BountyPost = GithubIssue {
+            repo_owner: metadata.owner,
+            repo_name: metadata.repo,
+            issue_number: metadata.issue,
+        }
+        .into();
+        let event = client
+            .post_org_bounty(
+                self.bank_id.into(),
+                bounty,
+                self.amount.into(),
+                self.expiry.map(|e| e.into()),
+            )
+            .await?;
         println!(
-            "Depositer with AccountId {} posted new BountyId {}, Balance {}",
-            event.depositer, event.id, event.amount,
+            "AccountId {} posted new BountyId {} for OrgId {}, funded by BankId {}",
+            event.poster, event.bounty_id, event.org, event.bank_id,
         );
         Ok(())
     }
@@ -134,6 +196,7 @@ impl BountySubmitCommand {
 #[derive(Clone, Debug, Clap)]
 pub struct BountyApproveCommand {
     pub submission_id: u64,
+    pub amount: Option<u128>,
 }
 
 impl BountyApproveCommand {
@@ -148,7 +211,10 @@ impl BountyApproveCommand {
         <R as Bounty>::BountyId: Display,
     {
         let event = client
-            .approve_bounty_submission(self.submission_id.into())
+            .approve_bounty_submission(
+                self.submission_id.into(),
+                self.amount.map(|a| a.into()),
+            )
             .await?;
         println!(
             "Approved SubmissionId {} to transfer Balance {} to AccountId {}. Remaining Balance {} for BountyId {} ",
@@ -158,6 +224,124 @@ impl BountyApproveCommand {
     }
 }
 
+#[derive(Clone, Debug, Clap)]
+pub struct BountyTriggerVoteCommand {
+    pub submission_id: u64,
+    pub amount: Option<u128>,
+}
+
+impl BountyTriggerVoteCommand {
+    pub async fn exec<R: Runtime + Bounty, C: BountyClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Balances>::Balance: From<u128> + Display,
+        <R as Vote>::VoteId: Display,
+        <R as Bounty>::SubmissionId: From<u64> + Display,
+        <R as Bounty>::BountyId: Display,
+    {
+        let event = client
+            .trigger_approval_vote(
+                self.submission_id.into(),
+                self.amount.map(|a| a.into()),
+            )
+            .await?;
+        println!(
+            "AccountId {} triggered VoteId {} to approve Balance {} for SubmissionId {} to BountyId {}",
+            event.trigger_er, event.vote_id, event.amount, event.submission_id, event.bounty_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyRejectCommand {
+    pub submission_id: u64,
+    pub reason: Option<String>,
+}
+
+impl BountyRejectCommand {
+    pub async fn exec<R: Runtime + Bounty, C: BountyClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Balances>::Balance: From<u128> + Display,
+        <R as Bounty>::SubmissionId: From<u64> + Display,
+        <R as Bounty>::BountyId: Display,
+        <R as Bounty>::RejectionReason: From<TextBlock>,
+    {
+        let reason = self.reason.as_ref().map(|text| {
+            TextBlock {
+                text: text.to_string(),
+            }
+            .into()
+        });
+        let event = client
+            .reject_submission(self.submission_id.into(), reason)
+            .await?;
+        println!(
+            "Depositer with AccountId {} rejected SubmissionId {} for BountyId {}",
+            event.depositer, event.submission_id, event.bounty_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyWithdrawCommand {
+    pub submission_id: u64,
+}
+
+impl BountyWithdrawCommand {
+    pub async fn exec<R: Runtime + Bounty, C: BountyClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Balances>::Balance: From<u128> + Display,
+        <R as Bounty>::SubmissionId: From<u64> + Display,
+        <R as Bounty>::BountyId: Display,
+    {
+        let event = client
+            .withdraw_submission(self.submission_id.into())
+            .await?;
+        println!(
+            "Submitter with AccountId {} withdrew SubmissionId {} for BountyId {}",
+            event.submitter, event.submission_id, event.bounty_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyCancelCommand {
+    pub bounty_id: u64,
+}
+
+impl BountyCancelCommand {
+    pub async fn exec<R: Runtime + Bounty, C: BountyClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Balances>::Balance: From<u128> + Display,
+        <R as Bounty>::BountyId: From<u64> + Display,
+    {
+        let event = client.cancel_bounty(self.bounty_id.into()).await?;
+        println!(
+            "Depositer with AccountId {} cancelled BountyId {}, refunding Balance {} to contributors and Balance {} to the depositer",
+            event.depositer, event.bounty_id, event.to_contributors, event.remainder
+        );
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Clap)]
 pub struct GetBountyCommand {
     pub bounty_id: u64,
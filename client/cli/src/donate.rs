@@ -25,6 +25,7 @@ use sunshine_client_utils::{
 pub struct PropDonateCommand {
     pub org: u64,
     pub rem_recipient: String,
+    pub currency_id: u32,
     pub amt: u128,
 }
 
@@ -36,6 +37,7 @@ impl PropDonateCommand {
     where
         <R as System>::AccountId: Ss58Codec,
         <R as Org>::OrgId: From<u64> + Display,
+        <R as Donate>::CurrencyId: From<u32> + Display,
         <R as Balances>::Balance: From<u128> + Display,
     {
         let remainder_recipient: Ss58<R> = self.rem_recipient.parse()?;
@@ -43,12 +45,13 @@ impl PropDonateCommand {
             .make_prop_donation(
                 self.org.into(),
                 remainder_recipient.0,
+                self.currency_id.into(),
                 self.amt.into(),
             )
             .await?;
         println!(
-            "AccountId {:?} donated {} to weighted OrgId {} and {} to the Remainder Recipient {}",
-            event.sender, event.amt_to_org, event.org, event.amt_to_recipient, event.rem_recipient,
+            "AccountId {:?} donated {} of asset {} to weighted OrgId {} and {} to the Remainder Recipient {}",
+            event.sender, event.amt_to_org, event.currency_id, event.org, event.amt_to_recipient, event.rem_recipient,
         );
         Ok(())
     }
@@ -58,6 +61,7 @@ impl PropDonateCommand {
 pub struct EqualDonateCommand {
     pub org: u64,
     pub rem_recipient: String,
+    pub currency_id: u32,
     pub amt: u128,
 }
 
@@ -69,6 +73,7 @@ impl EqualDonateCommand {
     where
         <R as System>::AccountId: Ss58Codec,
         <R as Org>::OrgId: From<u64> + Display,
+        <R as Donate>::CurrencyId: From<u32> + Display,
         <R as Balances>::Balance: From<u128> + Display,
     {
         let remainder_recipient: Ss58<R> = self.rem_recipient.parse()?;
@@ -76,12 +81,13 @@ impl EqualDonateCommand {
             .make_equal_donation(
                 self.org.into(),
                 remainder_recipient.0,
+                self.currency_id.into(),
                 self.amt.into(),
             )
             .await?;
         println!(
-            "AccountId {:?} donated {} to flat OrgId {} and {} to the Remainder Recipient {}",
-            event.sender, event.amt_to_org, event.org, event.amt_to_recipient, event.rem_recipient,
+            "AccountId {:?} donated {} of asset {} to flat OrgId {} and {} to the Remainder Recipient {}",
+            event.sender, event.amt_to_org, event.currency_id, event.org, event.amt_to_recipient, event.rem_recipient,
         );
         Ok(())
     }
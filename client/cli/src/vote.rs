@@ -75,26 +75,15 @@ impl VoteCreateSignalThresholdCommand {
             } else {
                 None
             };
-        // 0 is false, every other integer is true
-        let event = if self.weighted != 0 {
-            client
-                .create_signal_vote(
-                    topic,
-                    OrgRep::Weighted(self.organization.into()),
-                    threshold,
-                    duration,
-                )
-                .await?
-        } else {
-            client
-                .create_signal_vote(
-                    topic,
-                    OrgRep::Equal(self.organization.into()),
-                    threshold,
-                    duration,
-                )
-                .await?
+        // 0 is equal, 2 is quadratic and everything else is weighted
+        let organization = match self.weighted {
+            0 => OrgRep::Equal(self.organization.into()),
+            2 => OrgRep::Quadratic(self.organization.into()),
+            _ => OrgRep::Weighted(self.organization.into()),
         };
+        let event = client
+            .create_signal_vote(topic, organization, threshold, duration)
+            .await?;
         println!(
             "Account {} created a signal threshold vote with VoteId {}",
             event.caller, event.new_vote_id
@@ -165,26 +154,15 @@ impl VoteCreatePercentThresholdCommand {
                 .into();
         let threshold: Threshold<<R as Vote>::Percent> =
             Threshold::new(support_t, rt);
-        // 0 is false and everything else is true
-        let event = if self.weighted != 0 {
-            client
-                .create_percent_vote(
-                    topic,
-                    OrgRep::Weighted(self.organization.into()),
-                    threshold,
-                    duration,
-                )
-                .await?
-        } else {
-            client
-                .create_percent_vote(
-                    topic,
-                    OrgRep::Equal(self.organization.into()),
-                    threshold,
-                    duration,
-                )
-                .await?
+        // 0 is equal, 2 is quadratic and everything else is weighted
+        let organization = match self.weighted {
+            0 => OrgRep::Equal(self.organization.into()),
+            2 => OrgRep::Quadratic(self.organization.into()),
+            _ => OrgRep::Weighted(self.organization.into()),
         };
+        let event = client
+            .create_percent_vote(topic, organization, threshold, duration)
+            .await?;
         println!(
             "Account {} created a percent threshold vote with VoteId {}",
             event.caller, event.new_vote_id
@@ -3,6 +3,7 @@ mod subxt;
 pub use subxt::*;
 
 use crate::{
+    donate::Donate,
     error::Error,
     org::Org,
 };
@@ -22,8 +23,9 @@ use sunshine_client_utils::{
 pub trait BankClient<T: Runtime + Bank>: Client<T> {
     async fn open(
         &self,
-        seed: BalanceOf<T>,
         hosting_org: <T as Org>::OrgId,
+        currency_id: <T as Donate>::CurrencyId,
+        seed: BalanceOf<T>,
         bank_operator: Option<<T as System>::AccountId>,
         threshold: Threshold<T>,
     ) -> Result<AccountOpenedEvent<T>>;
@@ -47,6 +49,43 @@ pub trait BankClient<T: Runtime + Bank>: Client<T> {
         &self,
         bank_id: <T as Bank>::BankId,
     ) -> Result<AccountClosedEvent<T>>;
+    async fn ragequit(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        shares: <T as Org>::Shares,
+    ) -> Result<MemberExitedEvent<T>>;
+    async fn propose_scheduled_spend(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+        schedule: Schedule<T>,
+    ) -> Result<SpendProposedEvent<T>>;
+    async fn cancel_scheduled_spend(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<ScheduledSpendCancelledEvent<T>>;
+    async fn spend_allowance(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+    ) -> Result<AllowanceSpentEvent<T>>;
+    async fn propose_close(
+        &self,
+        bank_id: <T as Bank>::BankId,
+    ) -> Result<CloseProposedEvent<T>>;
+    async fn propose_controller_change(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        new_controller: Option<<T as System>::AccountId>,
+    ) -> Result<ControllerChangeProposedEvent<T>>;
+    async fn cancel_spend(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<SpendCancelledEvent<T>>;
     async fn bank(&self, bank_id: <T as Bank>::BankId) -> Result<BankSt<T>>;
     async fn spend_proposal(
         &self,
@@ -69,8 +108,9 @@ where
 {
     async fn open(
         &self,
-        seed: BalanceOf<T>,
         hosting_org: <T as Org>::OrgId,
+        currency_id: <T as Donate>::CurrencyId,
+        seed: BalanceOf<T>,
         bank_operator: Option<<T as System>::AccountId>,
         threshold: Threshold<T>,
     ) -> Result<AccountOpenedEvent<T>> {
@@ -78,8 +118,9 @@ where
         self.chain_client()
             .open_and_watch(
                 &signer,
-                seed,
                 hosting_org,
+                currency_id,
+                seed,
                 bank_operator,
                 threshold,
             )
@@ -135,6 +176,98 @@ where
             .account_closed()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn ragequit(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        shares: <T as Org>::Shares,
+    ) -> Result<MemberExitedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .ragequit_and_watch(&signer, bank_id, shares)
+            .await?
+            .member_exited()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn propose_scheduled_spend(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+        schedule: Schedule<T>,
+    ) -> Result<SpendProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .propose_scheduled_spend_and_watch(
+                &signer, bank_id, amount, dest, schedule,
+            )
+            .await?
+            .spend_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn cancel_scheduled_spend(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<ScheduledSpendCancelledEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .cancel_scheduled_spend_and_watch(&signer, bank_id, spend_id)
+            .await?
+            .scheduled_spend_cancelled()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn spend_allowance(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+    ) -> Result<AllowanceSpentEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .spend_allowance_and_watch(&signer, bank_id, amount, dest)
+            .await?
+            .allowance_spent()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn propose_close(
+        &self,
+        bank_id: <T as Bank>::BankId,
+    ) -> Result<CloseProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .propose_close_and_watch(&signer, bank_id)
+            .await?
+            .close_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn propose_controller_change(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        new_controller: Option<<T as System>::AccountId>,
+    ) -> Result<ControllerChangeProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .propose_controller_change_and_watch(
+                &signer,
+                bank_id,
+                new_controller,
+            )
+            .await?
+            .controller_change_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn cancel_spend(
+        &self,
+        bank_id: <T as Bank>::BankId,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<SpendCancelledEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .cancel_spend_and_watch(&signer, bank_id, spend_id)
+            .await?
+            .spend_cancelled()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn bank(&self, bank_id: <T as Bank>::BankId) -> Result<BankSt<T>> {
         Ok(self.chain_client().banks(bank_id, None).await?)
     }
@@ -42,8 +42,12 @@ use substrate_subxt::{
 };
 use sunshine_bounty_utils::{
     bank::{
+        Allowance,
+        BankProposal,
         BankState,
+        SpendCap,
         SpendProposal,
+        SpendSchedule,
         SpendState,
     },
     organization::OrgRep,
@@ -71,6 +75,9 @@ pub type SpendProp<T> = SpendProposal<
     <T as System>::AccountId,
     SpendState<<T as Vote>::VoteId>,
 >;
+pub type Schedule<T> = SpendSchedule<<T as System>::BlockNumber>;
+pub type Cap<T> = SpendCap<BalanceOf<T>, <T as System>::BlockNumber>;
+pub type Allow<T> = Allowance<BalanceOf<T>, <T as System>::BlockNumber>;
 
 #[module]
 pub trait Bank: System + Balances + Org + Vote + Donate {
@@ -106,6 +113,12 @@ pub struct BanksStore<T: Bank> {
     pub id: T::BankId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BankCurrencyStore<T: Bank> {
+    #[store(returns = <T as Donate>::CurrencyId)]
+    pub id: T::BankId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct SpendProposalsStore<T: Bank> {
     #[store(returns = SpendProp<T>)]
@@ -113,12 +126,53 @@ pub struct SpendProposalsStore<T: Bank> {
     pub spend_id: T::SpendId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SpendSchedulesStore<T: Bank> {
+    #[store(returns = Option<Schedule<T>>)]
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ControllerAllowancesStore<T: Bank> {
+    #[store(returns = Option<Allow<T>>)]
+    pub bank_id: T::BankId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct VoteRequiredAboveStore<T: Bank> {
+    #[store(returns = Option<BalanceOf<T>>)]
+    pub bank_id: T::BankId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SpenderAllowancesStore<'a, T: Bank> {
+    #[store(returns = Option<Allow<T>>)]
+    pub bank_id: T::BankId,
+    pub spender: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BankProposalsStore<T: Bank> {
+    #[store(returns = Option<BankProposal<<T as System>::AccountId>>)]
+    pub bank_id: T::BankId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SpendProposersStore<T: Bank> {
+    #[store(returns = Option<(<T as System>::AccountId, <T as System>::BlockNumber)>)]
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
 // ~~ (Calls, Events) ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct OpenCall<T: Bank> {
-    pub seed: BalanceOf<T>,
     pub hosting_org: <T as Org>::OrgId,
+    pub currency_id: <T as Donate>::CurrencyId,
+    pub seed: BalanceOf<T>,
     pub bank_operator: Option<<T as System>::AccountId>,
     pub threshold: Threshold<T>,
 }
@@ -127,6 +181,7 @@ pub struct OpenCall<T: Bank> {
 pub struct AccountOpenedEvent<T: Bank> {
     pub seeder: <T as System>::AccountId,
     pub new_bank_id: T::BankId,
+    pub currency_id: <T as Donate>::CurrencyId,
     pub seed: BalanceOf<T>,
     pub hosting_org: <T as Org>::OrgId,
     pub bank_operator: Option<<T as System>::AccountId>,
@@ -193,3 +248,170 @@ pub struct AccountClosedEvent<T: Bank> {
     pub bank_id: T::BankId,
     pub org: <T as Org>::OrgId,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct OrgSpentEvent<T: Bank> {
+    pub org: <T as Org>::OrgId,
+    pub bank_id: T::BankId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RagequitCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub shares: <T as Org>::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct WithdrawnPortionEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub amount: BalanceOf<T>,
+    pub left: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MemberExitedEvent<T: Bank> {
+    pub member: <T as System>::AccountId,
+    pub org: <T as Org>::OrgId,
+    pub shares: <T as Org>::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeScheduledSpendCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+    pub schedule: Schedule<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CancelScheduledSpendCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ScheduledSpendPaidEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ScheduledSpendCancelledEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SetSpendPolicyCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub controller_cap: Option<Cap<T>>,
+    pub vote_required_above: Option<BalanceOf<T>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendPolicySetEvent<T: Bank> {
+    pub org: <T as Org>::OrgId,
+    pub bank_id: T::BankId,
+    pub controller_capped: bool,
+    pub vote_required_above: Option<BalanceOf<T>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SetSpenderAllowanceCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spender: <T as System>::AccountId,
+    pub cap: Option<Cap<T>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpenderAllowanceSetEvent<T: Bank> {
+    pub org: <T as Org>::OrgId,
+    pub bank_id: T::BankId,
+    pub spender: <T as System>::AccountId,
+    pub allowed: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SpendAllowanceCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct AllowanceSpentEvent<T: Bank> {
+    pub spender: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeCloseCall<T: Bank> {
+    pub bank_id: T::BankId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct CloseProposedEvent<T: Bank> {
+    pub proposer: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeControllerChangeCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub new_controller: Option<<T as System>::AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ControllerChangeProposedEvent<T: Bank> {
+    pub proposer: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub vote_id: <T as Vote>::VoteId,
+    pub new_controller: Option<<T as System>::AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ControllerChangedEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub new_controller: Option<<T as System>::AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BankProposalRejectedEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CancelSpendCall<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendCancelledEvent<T: Bank> {
+    pub proposer: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendExpiredEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendArchivedEvent<T: Bank> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+    pub state: SpendState<<T as Vote>::VoteId>,
+}
@@ -1,8 +1,16 @@
 mod subxt;
 
-use crate::error::Error;
-use codec::Encode;
+use crate::{
+    donate::Donate,
+    error::Error,
+};
+use codec::{
+    Decode,
+    Encode,
+};
 use substrate_subxt::{
+    sp_runtime::Permill,
+    Event,
     Runtime,
     SignedExtension,
     SignedExtra,
@@ -19,8 +27,17 @@ pub trait BountyClient<T: Runtime + Bounty>: Client<T> {
     async fn post_bounty(
         &self,
         bounty: T::BountyPost,
+        currency_id: <T as Donate>::CurrencyId,
         amount: BalanceOf<T>,
+        expiry: Option<T::BlockNumber>,
     ) -> Result<BountyPostedEvent<T>>;
+    async fn post_org_bounty(
+        &self,
+        bank_id: T::BankId,
+        bounty: T::BountyPost,
+        amount: BalanceOf<T>,
+        expiry: Option<T::BlockNumber>,
+    ) -> Result<OrgBountyPostedEvent<T>>;
     async fn contribute_to_bounty(
         &self,
         bounty_id: T::BountyId,
@@ -35,7 +52,37 @@ pub trait BountyClient<T: Runtime + Bounty>: Client<T> {
     async fn approve_bounty_submission(
         &self,
         submission_id: T::SubmissionId,
+        amount: Option<BalanceOf<T>>,
     ) -> Result<BountyPaymentExecutedEvent<T>>;
+    async fn approve_split(
+        &self,
+        bounty_id: T::BountyId,
+        split: Vec<(T::SubmissionId, Permill)>,
+    ) -> Result<Vec<BountyPaymentExecutedEvent<T>>>;
+    async fn trigger_approval_vote(
+        &self,
+        submission_id: T::SubmissionId,
+        amount: Option<BalanceOf<T>>,
+    ) -> Result<SubmissionApprovalVoteTriggeredEvent<T>>;
+    async fn cancel_bounty(
+        &self,
+        bounty_id: T::BountyId,
+    ) -> Result<BountyCancelledEvent<T>>;
+    async fn reject_submission(
+        &self,
+        submission_id: T::SubmissionId,
+        reason: Option<T::RejectionReason>,
+    ) -> Result<SubmissionRejectedEvent<T>>;
+    async fn withdraw_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<SubmissionWithdrawnEvent<T>>;
+    async fn update_submission(
+        &self,
+        submission_id: T::SubmissionId,
+        submission: T::BountySubmission,
+        amount: BalanceOf<T>,
+    ) -> Result<SubmissionUpdatedEvent<T>>;
     async fn bounty(&self, bounty_id: T::BountyId) -> Result<BountyState<T>>;
     async fn submission(
         &self,
@@ -78,22 +125,57 @@ where
         > + ipld_block_builder::Cache<
             ipld_block_builder::Codec,
             <T as Bounty>::BountySubmission,
+        > + ipld_block_builder::Cache<
+            ipld_block_builder::Codec,
+            <T as Bounty>::RejectionReason,
         >,
 {
     async fn post_bounty(
         &self,
         bounty: T::BountyPost,
+        currency_id: <T as Donate>::CurrencyId,
         amount: BalanceOf<T>,
+        expiry: Option<T::BlockNumber>,
     ) -> Result<BountyPostedEvent<T>> {
         let signer = self.chain_signer()?;
         let issue = Encode::encode(&bounty);
         let info = crate::post(self, bounty).await?;
         self.chain_client()
-            .post_bounty_and_watch(&signer, issue, info.into(), amount)
+            .post_bounty_and_watch(
+                &signer,
+                issue,
+                info.into(),
+                currency_id,
+                amount,
+                expiry,
+            )
             .await?
             .bounty_posted()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn post_org_bounty(
+        &self,
+        bank_id: T::BankId,
+        bounty: T::BountyPost,
+        amount: BalanceOf<T>,
+        expiry: Option<T::BlockNumber>,
+    ) -> Result<OrgBountyPostedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let issue = Encode::encode(&bounty);
+        let info = crate::post(self, bounty).await?;
+        self.chain_client()
+            .post_org_bounty_and_watch(
+                &signer,
+                bank_id,
+                issue,
+                info.into(),
+                amount,
+                expiry,
+            )
+            .await?
+            .org_bounty_posted()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn contribute_to_bounty(
         &self,
         bounty_id: T::BountyId,
@@ -130,14 +212,111 @@ where
     async fn approve_bounty_submission(
         &self,
         submission_id: T::SubmissionId,
+        amount: Option<BalanceOf<T>>,
     ) -> Result<BountyPaymentExecutedEvent<T>> {
         let signer = self.chain_signer()?;
         self.chain_client()
-            .approve_bounty_submission_and_watch(&signer, submission_id)
+            .approve_bounty_submission_and_watch(&signer, submission_id, amount)
             .await?
             .bounty_payment_executed()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn approve_split(
+        &self,
+        bounty_id: T::BountyId,
+        split: Vec<(T::SubmissionId, Permill)>,
+    ) -> Result<Vec<BountyPaymentExecutedEvent<T>>> {
+        let signer = self.chain_signer()?;
+        let result = self
+            .chain_client()
+            .approve_split_and_watch(&signer, bounty_id, split)
+            .await?;
+        // one payment event is emitted per submission in the split
+        let mut payments = Vec::new();
+        for raw in result.events.iter().filter(|e| {
+            e.module == <BountyPaymentExecutedEvent<T> as Event<T>>::MODULE
+                && e.variant == <BountyPaymentExecutedEvent<T> as Event<T>>::EVENT
+        }) {
+            payments.push(BountyPaymentExecutedEvent::<T>::decode(
+                &mut &raw.data[..],
+            )?);
+        }
+        if payments.is_empty() {
+            Err(Error::EventNotFound.into())
+        } else {
+            Ok(payments)
+        }
+    }
+    async fn trigger_approval_vote(
+        &self,
+        submission_id: T::SubmissionId,
+        amount: Option<BalanceOf<T>>,
+    ) -> Result<SubmissionApprovalVoteTriggeredEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .trigger_approval_vote_and_watch(&signer, submission_id, amount)
+            .await?
+            .submission_approval_vote_triggered()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn cancel_bounty(
+        &self,
+        bounty_id: T::BountyId,
+    ) -> Result<BountyCancelledEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .cancel_bounty_and_watch(&signer, bounty_id)
+            .await?
+            .bounty_cancelled()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn reject_submission(
+        &self,
+        submission_id: T::SubmissionId,
+        reason: Option<T::RejectionReason>,
+    ) -> Result<SubmissionRejectedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let reason = if let Some(r) = reason {
+            Some(crate::post(self, r).await?.into())
+        } else {
+            None
+        };
+        self.chain_client()
+            .reject_submission_and_watch(&signer, submission_id, reason)
+            .await?
+            .submission_rejected()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn withdraw_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<SubmissionWithdrawnEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .withdraw_submission_and_watch(&signer, submission_id)
+            .await?
+            .submission_withdrawn()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn update_submission(
+        &self,
+        submission_id: T::SubmissionId,
+        submission: T::BountySubmission,
+        amount: BalanceOf<T>,
+    ) -> Result<SubmissionUpdatedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let submission_ref = crate::post(self, submission).await?;
+        self.chain_client()
+            .update_submission_and_watch(
+                &signer,
+                submission_id,
+                submission_ref.into(),
+                amount,
+            )
+            .await?
+            .submission_updated()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn bounty(&self, bounty_id: T::BountyId) -> Result<BountyState<T>> {
         Ok(self.chain_client().bounties(bounty_id, None).await?)
     }
@@ -179,15 +358,18 @@ where
         &self,
         bounty_id: T::BountyId,
     ) -> Result<Option<Vec<(T::SubmissionId, SubState<T>)>>> {
-        let mut submissions =
-            self.chain_client().submissions_iter(None).await?;
+        let submission_ids = crate::iter_prefix::<
+            T,
+            SubmissionsByBountyStore<T>,
+            T::BountyId,
+            T::SubmissionId,
+        >(self.chain_client(), &bounty_id)
+        .await?;
         let mut submissions_for_bounty =
             Vec::<(T::SubmissionId, SubState<T>)>::new();
-        while let Some((_, submission)) = submissions.next().await? {
-            if submission.bounty_id() == bounty_id {
-                submissions_for_bounty
-                    .push((submission.submission_id(), submission));
-            }
+        for submission_id in submission_ids {
+            let submission = self.submission(submission_id).await?;
+            submissions_for_bounty.push((submission_id, submission));
         }
         if submissions_for_bounty.is_empty() {
             Ok(None)
@@ -199,13 +381,17 @@ where
         &self,
         bounty_id: T::BountyId,
     ) -> Result<Option<Vec<Contrib<T>>>> {
-        let mut contributions =
-            self.chain_client().contributions_iter(None).await?;
+        let accounts = crate::iter_prefix::<
+            T,
+            ContributionsStore<T>,
+            T::BountyId,
+            T::AccountId,
+        >(self.chain_client(), &bounty_id)
+        .await?;
         let mut contributions_for_bounty = Vec::<Contrib<T>>::new();
-        while let Some((_, contrib)) = contributions.next().await? {
-            if contrib.id() == bounty_id {
-                contributions_for_bounty.push(contrib);
-            }
+        for account in accounts {
+            contributions_for_bounty
+                .push(self.contribution(bounty_id, account).await?);
         }
         if contributions_for_bounty.is_empty() {
             Ok(None)
@@ -217,13 +403,17 @@ where
         &self,
         account_id: T::AccountId,
     ) -> Result<Option<Vec<Contrib<T>>>> {
-        let mut contributions =
-            self.chain_client().contributions_iter(None).await?;
+        let bounty_ids = crate::iter_prefix::<
+            T,
+            ContributionsByAccountStore<T>,
+            T::AccountId,
+            T::BountyId,
+        >(self.chain_client(), &account_id)
+        .await?;
         let mut contributions_by_account = Vec::<Contrib<T>>::new();
-        while let Some((_, contrib)) = contributions.next().await? {
-            if contrib.account() == account_id {
-                contributions_by_account.push(contrib);
-            }
+        for bounty_id in bounty_ids {
+            contributions_by_account
+                .push(self.contribution(bounty_id, account_id.clone()).await?);
         }
         if contributions_by_account.is_empty() {
             Ok(None)
@@ -289,9 +479,10 @@ mod tests {
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 124,
         };
-        let event = client.post_bounty(bounty, 10u128).await.unwrap();
+        let event = client.post_bounty(bounty, 0, 10u128, None).await.unwrap();
         let expected_event = BountyPostedEvent {
             depositer: alice_account_id,
+            currency_id: 0,
             amount: 10,
             id: 1,
             description: event.description.clone(),
@@ -309,13 +500,15 @@ mod tests {
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 125,
         };
-        let event1 = client.post_bounty(bounty1, 10u128).await.unwrap();
+        let event1 =
+            client.post_bounty(bounty1, 0, 10u128, None).await.unwrap();
         let bounty2 = GithubIssue {
             repo_owner: "sunshine-protocol".to_string(),
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 126,
         };
-        let event2 = client.post_bounty(bounty2, 10u128).await.unwrap();
+        let event2 =
+            client.post_bounty(bounty2, 0, 10u128, None).await.unwrap();
         let bounties = client.open_bounties(9u128).await.unwrap().unwrap();
         assert_eq!(bounties.len(), 2);
         let expected_bounty1 = BountyInformation::new(
@@ -358,9 +551,10 @@ mod tests {
             .free;
         println!("{}", b);
 
-        let event1 = client.post_bounty(bounty, 1000).await.unwrap();
+        let event1 = client.post_bounty(bounty, 0, 1000, None).await.unwrap();
         let expected_event1 = BountyPostedEvent {
             depositer: alice_account_id.clone(),
+            currency_id: 0,
             amount: 1000,
             id: 1,
             description: event1.description.clone(),
@@ -1,3 +1,10 @@
+use crate::{
+    bank::{
+        Bank,
+        BankEventsDecoder,
+    },
+    donate::Donate,
+};
 use codec::{
     Codec,
     Decode,
@@ -51,7 +58,7 @@ use sunshine_identity_client::{
 pub type BalanceOf<T> = <T as Balances>::Balance;
 
 #[module]
-pub trait Bounty: System + Balances + Identity + Faucet {
+pub trait Bounty: System + Balances + Identity + Faucet + Bank {
     /// Cid type
     type IpfsReference: Parameter + Member + Default;
 
@@ -98,6 +105,16 @@ pub trait Bounty: System + Balances + Identity + Faucet {
         + DagDecode<DagCborCodec>
         + Send
         + Sync;
+
+    /// The reason given for rejecting a submission
+    type RejectionReason: 'static
+        + Codec
+        + Default
+        + Clone
+        + DagEncode<DagCborCodec>
+        + DagDecode<DagCborCodec>
+        + Send
+        + Sync;
 }
 
 // ~~ Storage ~~
@@ -128,6 +145,12 @@ pub struct BountiesStore<T: Bounty> {
     pub id: T::BountyId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyCurrencyStore<T: Bounty> {
+    #[store(returns = <T as Donate>::CurrencyId)]
+    pub id: T::BountyId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct SubmissionsStore<T: Bounty> {
     #[store(returns = SubState<T>)]
@@ -141,21 +164,67 @@ pub struct ContributionsStore<T: Bounty> {
     pub account: T::AccountId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ContributionsByAccountStore<T: Bounty> {
+    #[store(returns = ())]
+    pub account: T::AccountId,
+    pub id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SubmissionsByBountyStore<T: Bounty> {
+    #[store(returns = ())]
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct OrgBountiesStore<T: Bounty> {
+    #[store(returns = <T as Bank>::BankId)]
+    pub id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ApprovalVotesStore<T: Bounty> {
+    #[store(returns = (T::VoteId, BalanceOf<T>))]
+    pub id: T::SubmissionId,
+}
+
 // ~~ (Calls, Events) ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct PostBountyCall<T: Bounty> {
     pub issue: Vec<u8>,
-    pub info: T::IpfsReference,
+    pub info: <T as Bounty>::IpfsReference,
+    pub currency_id: <T as Donate>::CurrencyId,
     pub amount: BalanceOf<T>,
+    pub expiry: Option<<T as System>::BlockNumber>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct BountyPostedEvent<T: Bounty> {
     pub depositer: <T as System>::AccountId,
+    pub currency_id: <T as Donate>::CurrencyId,
     pub amount: BalanceOf<T>,
     pub id: T::BountyId,
-    pub description: T::IpfsReference,
+    pub description: <T as Bounty>::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PostOrgBountyCall<T: Bounty> {
+    pub bank_id: <T as Bank>::BankId,
+    pub issue: Vec<u8>,
+    pub info: <T as Bounty>::IpfsReference,
+    pub amount: BalanceOf<T>,
+    pub expiry: Option<<T as System>::BlockNumber>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct OrgBountyPostedEvent<T: Bounty> {
+    pub poster: <T as System>::AccountId,
+    pub bank_id: <T as Bank>::BankId,
+    pub org: T::OrgId,
+    pub bounty_id: T::BountyId,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
@@ -170,14 +239,14 @@ pub struct BountyRaiseContributionEvent<T: Bounty> {
     pub amount: BalanceOf<T>,
     pub bounty_id: T::BountyId,
     pub total: BalanceOf<T>,
-    pub bounty_ref: T::IpfsReference,
+    pub bounty_ref: <T as Bounty>::IpfsReference,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct SubmitForBountyCall<T: Bounty> {
     pub bounty_id: T::BountyId,
     pub issue: Vec<u8>,
-    pub submission_ref: T::IpfsReference,
+    pub submission_ref: <T as Bounty>::IpfsReference,
     pub amount: BalanceOf<T>,
 }
 
@@ -187,13 +256,48 @@ pub struct BountySubmissionPostedEvent<T: Bounty> {
     pub bounty_id: T::BountyId,
     pub amount: BalanceOf<T>,
     pub id: T::SubmissionId,
-    pub bounty_ref: T::IpfsReference,
-    pub submission_ref: T::IpfsReference,
+    pub bounty_ref: <T as Bounty>::IpfsReference,
+    pub submission_ref: <T as Bounty>::IpfsReference,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct ApproveBountySubmissionCall<T: Bounty> {
     pub submission_id: T::SubmissionId,
+    pub amount: Option<BalanceOf<T>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ApproveSplitCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub split: Vec<(T::SubmissionId, sp_runtime::Permill)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct TriggerApprovalVoteCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+    pub amount: Option<BalanceOf<T>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionApprovalVoteTriggeredEvent<T: Bounty> {
+    pub trigger_er: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub amount: BalanceOf<T>,
+    pub vote_id: T::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionRejectedByVoteEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub vote_id: T::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionApprovedByVoteButPaymentFailedEvent<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+    pub vote_id: T::VoteId,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -203,6 +307,88 @@ pub struct BountyPaymentExecutedEvent<T: Bounty> {
     pub submission_id: T::SubmissionId,
     pub amount: BalanceOf<T>,
     pub submitter: <T as System>::AccountId,
-    pub bounty_ref: T::IpfsReference,
-    pub submission_ref: T::IpfsReference,
+    pub bounty_ref: <T as Bounty>::IpfsReference,
+    pub submission_ref: <T as Bounty>::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CancelBountyCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyExpirySetEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub expiry: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyCancelledEvent<T: Bounty> {
+    pub depositer: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub to_contributors: BalanceOf<T>,
+    pub remainder: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyExpiredEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub to_contributors: BalanceOf<T>,
+    pub remainder: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyExpiredButRefundFailedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub total: BalanceOf<T>,
+    pub retry: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ContributionRefundedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub contributor: <T as System>::AccountId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RejectSubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+    pub reason: Option<<T as Bounty>::IpfsReference>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionRejectedEvent<T: Bounty> {
+    pub depositer: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub reason: Option<<T as Bounty>::IpfsReference>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct WithdrawSubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionWithdrawnEvent<T: Bounty> {
+    pub submitter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct UpdateSubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+    pub submission_ref: <T as Bounty>::IpfsReference,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionUpdatedEvent<T: Bounty> {
+    pub submitter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub amount: BalanceOf<T>,
+    pub submission_ref: <T as Bounty>::IpfsReference,
 }
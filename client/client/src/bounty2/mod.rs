@@ -0,0 +1,205 @@
+mod subxt;
+
+use crate::{
+    error::Error,
+    vote_direct::VoteDirect,
+};
+use sp_runtime::Permill;
+use substrate_subxt::{
+    sp_runtime,
+    system::System,
+    Runtime,
+    SignedExtension,
+    SignedExtra,
+};
+pub use subxt::*;
+use sunshine_client_utils::{
+    async_trait,
+    Client,
+    Result,
+};
+
+#[async_trait]
+pub trait Bounty2Client<T: Runtime + Bounty2>: Client<T> {
+    async fn post_bounty2(
+        &self,
+        bounty: T::BountyPost,
+        amount: BalanceOf<T>,
+        veto_threshold: Permill,
+        refund_threshold: Permill,
+    ) -> Result<BountyPostedEvent<T>>;
+    async fn contribute_to_bounty2(
+        &self,
+        bounty_id: T::BountyId,
+        amount: BalanceOf<T>,
+    ) -> Result<BountyRaiseContributionEvent<T>>;
+    async fn submit_for_bounty2(
+        &self,
+        bounty_id: T::BountyId,
+        submission: T::BountySubmission,
+        amount: BalanceOf<T>,
+    ) -> Result<BountySubmissionPostedEvent<T>>;
+    async fn approve_bounty2_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<SubmissionApprovedAndScheduledEvent<T>>;
+    async fn reject_bounty2_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<SubmissionRejectedEvent<T>>;
+    async fn challenge_bounty2_approval(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<SubmissionApprovalChallengedEvent<T>>;
+    async fn trigger_bounty2_refund_vote(
+        &self,
+        bounty_id: T::BountyId,
+    ) -> Result<BountyRefundVoteTriggeredEvent<T>>;
+    async fn bounty2(&self, bounty_id: T::BountyId)
+        -> Result<Bounty2State<T>>;
+    async fn bounty2_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<Sub2State<T>>;
+    async fn bounty2_tip(
+        &self,
+        bounty_id: T::BountyId,
+        account: <T as System>::AccountId,
+    ) -> Result<BalanceOf<T>>;
+}
+
+#[async_trait]
+impl<T, C> Bounty2Client<T> for C
+where
+    T: Runtime + Bounty2,
+    <<T::Extra as SignedExtra<T>>::Extra as SignedExtension>::AdditionalSigned:
+        Send + Sync,
+    <T as VoteDirect>::Cid: From<libipld::cid::Cid>,
+    C: Client<T>,
+    C::OffchainClient: ipld_block_builder::Cache<
+            ipld_block_builder::Codec,
+            <T as Bounty2>::BountyPost,
+        > + ipld_block_builder::Cache<
+            ipld_block_builder::Codec,
+            <T as Bounty2>::BountySubmission,
+        >,
+{
+    async fn post_bounty2(
+        &self,
+        bounty: T::BountyPost,
+        amount: BalanceOf<T>,
+        veto_threshold: Permill,
+        refund_threshold: Permill,
+    ) -> Result<BountyPostedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let info = crate::post(self, bounty).await?;
+        self.chain_client()
+            .post_bounty_and_watch(
+                &signer,
+                info.into(),
+                amount,
+                veto_threshold,
+                refund_threshold,
+            )
+            .await?
+            .bounty_posted()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn contribute_to_bounty2(
+        &self,
+        bounty_id: T::BountyId,
+        amount: BalanceOf<T>,
+    ) -> Result<BountyRaiseContributionEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .contribute_to_bounty_and_watch(&signer, bounty_id, amount)
+            .await?
+            .bounty_raise_contribution()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn submit_for_bounty2(
+        &self,
+        bounty_id: T::BountyId,
+        submission: T::BountySubmission,
+        amount: BalanceOf<T>,
+    ) -> Result<BountySubmissionPostedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let submission_ref = crate::post(self, submission).await?;
+        self.chain_client()
+            .submit_for_bounty_and_watch(
+                &signer,
+                bounty_id,
+                submission_ref.into(),
+                amount,
+            )
+            .await?
+            .bounty_submission_posted()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn approve_bounty2_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<SubmissionApprovedAndScheduledEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .approve_bounty_submission_and_watch(&signer, submission_id)
+            .await?
+            .submission_approved_and_scheduled()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn reject_bounty2_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<SubmissionRejectedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .reject_bounty_submission_and_watch(&signer, submission_id)
+            .await?
+            .submission_rejected()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn challenge_bounty2_approval(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<SubmissionApprovalChallengedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .reject_bounty_submission_and_watch(&signer, submission_id)
+            .await?
+            .submission_approval_challenged()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn trigger_bounty2_refund_vote(
+        &self,
+        bounty_id: T::BountyId,
+    ) -> Result<BountyRefundVoteTriggeredEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .trigger_refund_vote_and_watch(&signer, bounty_id)
+            .await?
+            .bounty_refund_vote_triggered()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn bounty2(
+        &self,
+        bounty_id: T::BountyId,
+    ) -> Result<Bounty2State<T>> {
+        Ok(self.chain_client().bounties(bounty_id, None).await?)
+    }
+    async fn bounty2_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<Sub2State<T>> {
+        Ok(self.chain_client().submissions(submission_id, None).await?)
+    }
+    async fn bounty2_tip(
+        &self,
+        bounty_id: T::BountyId,
+        account: <T as System>::AccountId,
+    ) -> Result<BalanceOf<T>> {
+        Ok(self
+            .chain_client()
+            .bounty_tips(bounty_id, account, None)
+            .await?)
+    }
+}
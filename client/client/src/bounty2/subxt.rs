@@ -0,0 +1,280 @@
+use crate::vote_direct::{
+    VoteDirect,
+    VoteDirectEventsDecoder,
+};
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
+use frame_support::Parameter;
+use libipld::{
+    cbor::DagCborCodec,
+    codec::{
+        Decode as DagEncode,
+        Encode as DagDecode,
+    },
+};
+use sp_runtime::{
+    traits::{
+        AtLeast32Bit,
+        MaybeSerializeDeserialize,
+        Member,
+        Zero,
+    },
+    Permill,
+};
+use std::fmt::Debug;
+use substrate_subxt::{
+    balances::{
+        Balances,
+        BalancesEventsDecoder,
+    },
+    module,
+    sp_runtime,
+    system::{
+        System,
+        SystemEventsDecoder,
+    },
+    Call,
+    Event,
+    Store,
+};
+use sunshine_bounty_utils::{
+    bounty::{
+        BountyInfo2,
+        BountyState,
+        BountySubmission,
+        SubmissionState2,
+    },
+    grant::ChallengeNorms,
+};
+
+pub type BalanceOf<T> = <T as Balances>::Balance;
+
+#[module]
+pub trait Bounty2: System + Balances + VoteDirect {
+    type BountyId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+
+    /// The shape of bounty postings
+    type BountyPost: 'static
+        + Codec
+        + Default
+        + Clone
+        + DagEncode<DagCborCodec>
+        + DagDecode<DagCborCodec>
+        + Send
+        + Sync;
+
+    type SubmissionId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+
+    /// The shape of bounty submission
+    type BountySubmission: 'static
+        + Codec
+        + Default
+        + Clone
+        + DagEncode<DagCborCodec>
+        + DagDecode<DagCborCodec>
+        + Send
+        + Sync;
+}
+
+// ~~ Storage ~~
+
+pub type Bounty2State<T> = BountyInfo2<
+    <T as VoteDirect>::Cid,
+    ChallengeNorms<<T as System>::AccountId, Permill>,
+    BalanceOf<T>,
+    BountyState<<T as VoteDirect>::VoteId>,
+>;
+pub type Sub2State<T> = BountySubmission<
+    <T as Bounty2>::BountyId,
+    <T as Bounty2>::SubmissionId,
+    <T as VoteDirect>::Cid,
+    <T as System>::AccountId,
+    BalanceOf<T>,
+    SubmissionState2<
+        <T as System>::BlockNumber,
+        <T as VoteDirect>::VoteId,
+    >,
+>;
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountiesStore<T: Bounty2> {
+    #[store(returns = Bounty2State<T>)]
+    pub id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyTipsStore<T: Bounty2> {
+    #[store(returns = BalanceOf<T>)]
+    pub id: T::BountyId,
+    pub account: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SubmissionsStore<T: Bounty2> {
+    #[store(returns = Sub2State<T>)]
+    pub id: T::SubmissionId,
+}
+
+// ~~ (Calls, Events) ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PostBountyCall<T: Bounty2> {
+    pub info: <T as VoteDirect>::Cid,
+    pub amount: BalanceOf<T>,
+    pub veto_threshold: Permill,
+    pub refund_threshold: Permill,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyPostedEvent<T: Bounty2> {
+    pub depositer: <T as System>::AccountId,
+    pub amount: BalanceOf<T>,
+    pub id: T::BountyId,
+    pub description: <T as VoteDirect>::Cid,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ContributeToBountyCall<T: Bounty2> {
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyRaiseContributionEvent<T: Bounty2> {
+    pub contributor: <T as System>::AccountId,
+    pub amount: BalanceOf<T>,
+    pub bounty_id: T::BountyId,
+    pub total: BalanceOf<T>,
+    pub bounty_ref: <T as VoteDirect>::Cid,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyRefundedEvent<T: Bounty2> {
+    pub bounty_id: T::BountyId,
+    pub to_contributors: BalanceOf<T>,
+    pub to_depositer: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SubmitForBountyCall<T: Bounty2> {
+    pub bounty_id: T::BountyId,
+    pub submission_ref: <T as VoteDirect>::Cid,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionPostedEvent<T: Bounty2> {
+    pub submitter: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+    pub id: T::SubmissionId,
+    pub bounty_ref: <T as VoteDirect>::Cid,
+    pub submission_ref: <T as VoteDirect>::Cid,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ApproveBountySubmissionCall<T: Bounty2> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionApprovedAndScheduledEvent<T: Bounty2> {
+    pub submission_id: T::SubmissionId,
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+    pub scheduled: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RejectBountySubmissionCall<T: Bounty2> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionRejectedEvent<T: Bounty2> {
+    pub depositer: <T as System>::AccountId,
+    pub submission_id: T::SubmissionId,
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionApprovalChallengedEvent<T: Bounty2> {
+    pub contributor: <T as System>::AccountId,
+    pub submission_id: T::SubmissionId,
+    pub bounty_id: T::BountyId,
+    pub vote_id: <T as VoteDirect>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct TriggerRefundVoteCall<T: Bounty2> {
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyRefundVoteTriggeredEvent<T: Bounty2> {
+    pub contributor: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub vote_id: <T as VoteDirect>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionApprovedButPaymentFailedEvent<T: Bounty2> {
+    pub submission_id: T::SubmissionId,
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyRefundApprovedButFailedEvent<T: Bounty2> {
+    pub vote_id: <T as VoteDirect>::VoteId,
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendChallengePassedAndSubmissionRejectedEvent<T: Bounty2> {
+    pub submission_id: T::SubmissionId,
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyRefundChallengeRejectedEvent<T: Bounty2> {
+    pub vote_id: <T as VoteDirect>::VoteId,
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyPaymentExecutedEvent<T: Bounty2> {
+    pub bounty_id: T::BountyId,
+    pub new_total: BalanceOf<T>,
+    pub submission_id: T::SubmissionId,
+    pub amount: BalanceOf<T>,
+    pub submitter: <T as System>::AccountId,
+    pub bounty_ref: <T as VoteDirect>::Cid,
+    pub submission_ref: <T as VoteDirect>::Cid,
+}
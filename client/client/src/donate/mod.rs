@@ -24,12 +24,14 @@ pub trait DonateClient<T: Runtime + Donate>: Client<T> {
         &self,
         org: <T as Org>::OrgId,
         rem_recipient: <T as System>::AccountId,
+        currency_id: <T as Donate>::CurrencyId,
         amt: BalanceOf<T>,
     ) -> Result<PropDonationExecutedEvent<T>>;
     async fn make_equal_donation(
         &self,
         org: <T as Org>::OrgId,
         rem_recipient: <T as System>::AccountId,
+        currency_id: <T as Donate>::CurrencyId,
         amt: BalanceOf<T>,
     ) -> Result<EqualDonationExecutedEvent<T>>;
 }
@@ -46,11 +48,18 @@ where
         &self,
         org: <T as Org>::OrgId,
         rem_recipient: <T as System>::AccountId,
+        currency_id: <T as Donate>::CurrencyId,
         amt: BalanceOf<T>,
     ) -> Result<PropDonationExecutedEvent<T>> {
         let signer = self.chain_signer()?;
         self.chain_client()
-            .make_prop_donation_and_watch(&signer, org, rem_recipient, amt)
+            .make_prop_donation_and_watch(
+                &signer,
+                org,
+                rem_recipient,
+                currency_id,
+                amt,
+            )
             .await?
             .prop_donation_executed()?
             .ok_or_else(|| Error::EventNotFound.into())
@@ -59,11 +68,18 @@ where
         &self,
         org: <T as Org>::OrgId,
         rem_recipient: <T as System>::AccountId,
+        currency_id: <T as Donate>::CurrencyId,
         amt: BalanceOf<T>,
     ) -> Result<EqualDonationExecutedEvent<T>> {
         let signer = self.chain_signer()?;
         self.chain_client()
-            .make_equal_donation_and_watch(&signer, org, rem_recipient, amt)
+            .make_equal_donation_and_watch(
+                &signer,
+                org,
+                rem_recipient,
+                currency_id,
+                amt,
+            )
             .await?
             .equal_donation_executed()?
             .ok_or_else(|| Error::EventNotFound.into())
@@ -3,9 +3,15 @@ use crate::org::{
     OrgEventsDecoder,
 };
 use codec::{
+    Codec,
     Decode,
     Encode,
 };
+use frame_support::Parameter;
+use sp_runtime::traits::{
+    MaybeSerializeDeserialize,
+    Member,
+};
 use std::fmt::Debug;
 use substrate_subxt::{
     balances::{
@@ -13,6 +19,7 @@ use substrate_subxt::{
         BalancesEventsDecoder,
     },
     module,
+    sp_runtime,
     system::{
         System,
         SystemEventsDecoder,
@@ -25,7 +32,15 @@ use substrate_subxt::{
 pub type BalanceOf<T> = <T as Balances>::Balance;
 
 #[module]
-pub trait Donate: System + Balances + Org {}
+pub trait Donate: System + Balances + Org {
+    type CurrencyId: Parameter
+        + Member
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+}
 
 // ~~ Values (Constants) ~~
 
@@ -40,30 +55,34 @@ pub struct TransactionFee<T: Donate> {
 pub struct MakePropDonationCall<T: Donate> {
     pub org: <T as Org>::OrgId,
     pub rem_recipient: <T as System>::AccountId,
+    pub currency_id: <T as Donate>::CurrencyId,
     pub amt: BalanceOf<T>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct PropDonationExecutedEvent<T: Donate> {
     pub sender: <T as System>::AccountId,
-    pub org: <T as Org>::OrgId,
+    pub currency_id: <T as Donate>::CurrencyId,
     pub amt_to_org: BalanceOf<T>,
-    pub rem_recipient: <T as System>::AccountId,
+    pub org: <T as Org>::OrgId,
     pub amt_to_recipient: BalanceOf<T>,
+    pub rem_recipient: <T as System>::AccountId,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct MakeEqualDonationCall<T: Donate> {
     pub org: <T as Org>::OrgId,
     pub rem_recipient: <T as System>::AccountId,
+    pub currency_id: <T as Donate>::CurrencyId,
     pub amt: BalanceOf<T>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct EqualDonationExecutedEvent<T: Donate> {
     pub sender: <T as System>::AccountId,
-    pub org: <T as Org>::OrgId,
+    pub currency_id: <T as Donate>::CurrencyId,
     pub amt_to_org: BalanceOf<T>,
-    pub rem_recipient: <T as System>::AccountId,
+    pub org: <T as Org>::OrgId,
     pub amt_to_recipient: BalanceOf<T>,
+    pub rem_recipient: <T as System>::AccountId,
 }
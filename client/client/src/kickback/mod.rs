@@ -0,0 +1,105 @@
+mod subxt;
+
+use crate::error::Error;
+use substrate_subxt::{
+    system::System,
+    Runtime,
+    SignedExtension,
+    SignedExtra,
+};
+pub use subxt::*;
+use sunshine_client_utils::{
+    async_trait,
+    Client,
+    Result,
+};
+
+#[async_trait]
+pub trait KickbackClient<T: Runtime + Kickback>: Client<T> {
+    async fn post_kickback_event(
+        &self,
+        event: T::KickbackPost,
+        reservation_req: BalanceOf<T>,
+        attendance_limit: u32,
+    ) -> Result<EventPostedEvent<T>>;
+    async fn reserve_seat(
+        &self,
+        event_id: T::KickbackEventId,
+    ) -> Result<EventSeatReservedEvent<T>>;
+    async fn publish_attendance_and_execute_redistribution(
+        &self,
+        event_id: T::KickbackEventId,
+        present: Vec<<T as System>::AccountId>,
+    ) -> Result<EventClosedEvent<T>>;
+    async fn kickback_event(
+        &self,
+        event_id: T::KickbackEventId,
+    ) -> Result<KickbackEventFor<T>>;
+}
+
+#[async_trait]
+impl<T, C> KickbackClient<T> for C
+where
+    T: Runtime + Kickback,
+    <<T::Extra as SignedExtra<T>>::Extra as SignedExtension>::AdditionalSigned:
+        Send + Sync,
+    <T as Kickback>::IpfsReference: From<libipld::cid::Cid>,
+    C: Client<T>,
+    C::OffchainClient: ipld_block_builder::Cache<
+        ipld_block_builder::Codec,
+        <T as Kickback>::KickbackPost,
+    >,
+{
+    async fn post_kickback_event(
+        &self,
+        event: T::KickbackPost,
+        reservation_req: BalanceOf<T>,
+        attendance_limit: u32,
+    ) -> Result<EventPostedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let info = crate::post(self, event).await?;
+        self.chain_client()
+            .post_kickback_event_and_watch(
+                &signer,
+                info.into(),
+                reservation_req,
+                attendance_limit,
+            )
+            .await?
+            .event_posted()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn reserve_seat(
+        &self,
+        event_id: T::KickbackEventId,
+    ) -> Result<EventSeatReservedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .reserve_seat_and_watch(&signer, event_id)
+            .await?
+            .event_seat_reserved()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn publish_attendance_and_execute_redistribution(
+        &self,
+        event_id: T::KickbackEventId,
+        mut present: Vec<<T as System>::AccountId>,
+    ) -> Result<EventClosedEvent<T>> {
+        let signer = self.chain_signer()?;
+        // the runtime binary searches the attendance list
+        present.sort();
+        self.chain_client()
+            .publish_attendance_and_execute_redistribution_and_watch(
+                &signer, event_id, present,
+            )
+            .await?
+            .event_closed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn kickback_event(
+        &self,
+        event_id: T::KickbackEventId,
+    ) -> Result<KickbackEventFor<T>> {
+        Ok(self.chain_client().kickback_events(event_id, None).await?)
+    }
+}
@@ -0,0 +1,128 @@
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
+use frame_support::Parameter;
+use libipld::{
+    cbor::DagCborCodec,
+    codec::{
+        Decode as DagEncode,
+        Encode as DagDecode,
+    },
+};
+use sp_runtime::traits::{
+    AtLeast32Bit,
+    MaybeSerializeDeserialize,
+    Member,
+    Zero,
+};
+use std::fmt::Debug;
+use substrate_subxt::{
+    balances::{
+        Balances,
+        BalancesEventsDecoder,
+    },
+    module,
+    sp_runtime,
+    system::{
+        System,
+        SystemEventsDecoder,
+    },
+    Call,
+    Event,
+    Store,
+};
+use sunshine_bounty_utils::kickback::KickbackEvent;
+
+pub type BalanceOf<T> = <T as Balances>::Balance;
+pub type KickbackEventFor<T> = KickbackEvent<
+    <T as Kickback>::IpfsReference,
+    <T as System>::AccountId,
+    BalanceOf<T>,
+>;
+
+#[module]
+pub trait Kickback: System + Balances {
+    /// Cid type
+    type IpfsReference: Parameter + Member + Default;
+
+    type KickbackEventId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+
+    /// The shape of event postings
+    type KickbackPost: 'static
+        + Codec
+        + Default
+        + Clone
+        + DagEncode<DagCborCodec>
+        + DagDecode<DagCborCodec>
+        + Send
+        + Sync;
+}
+
+// ~~ Maps ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct KickbackEventsStore<T: Kickback> {
+    #[store(returns = KickbackEventFor<T>)]
+    pub id: T::KickbackEventId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct KickbackReservationsStore<T: Kickback> {
+    #[store(returns = ())]
+    pub id: T::KickbackEventId,
+    pub account: <T as System>::AccountId,
+}
+
+// ~~ (Calls, Events) ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PostKickbackEventCall<T: Kickback> {
+    pub info: T::IpfsReference,
+    pub reservation_req: BalanceOf<T>,
+    pub attendance_limit: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct EventPostedEvent<T: Kickback> {
+    pub supervisor: <T as System>::AccountId,
+    pub reservation_req: BalanceOf<T>,
+    pub id: T::KickbackEventId,
+    pub info: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ReserveSeatCall<T: Kickback> {
+    pub event_id: T::KickbackEventId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct EventSeatReservedEvent<T: Kickback> {
+    pub event_id: T::KickbackEventId,
+    pub reserver: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PublishAttendanceAndExecuteRedistributionCall<T: Kickback> {
+    pub id: T::KickbackEventId,
+    pub present: Vec<<T as System>::AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct EventClosedEvent<T: Kickback> {
+    pub id: T::KickbackEventId,
+    pub reservation_req: BalanceOf<T>,
+    pub amt_per_present: BalanceOf<T>,
+    pub remainder_for_publisher: BalanceOf<T>,
+}
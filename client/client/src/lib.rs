@@ -3,10 +3,14 @@ mod error;
 pub use error::Error;
 pub mod bank;
 pub mod bounty;
+pub mod bounty2;
 pub mod court;
 pub mod donate;
+pub mod kickback;
+pub mod moloch;
 pub mod org;
 pub mod vote;
+pub mod vote_direct;
 pub use sunshine_bounty_utils as utils;
 
 use codec::{
@@ -26,9 +30,14 @@ use libipld::{
     DagCbor,
 };
 use substrate_subxt::{
+    sp_core::{
+        hashing::blake2_128,
+        storage::StorageKey,
+    },
     sp_runtime::traits::SignedExtension,
     Runtime,
     SignedExtra,
+    Store,
 };
 use sunshine_client_utils::{
     Client,
@@ -63,3 +72,46 @@ where
     client.offchain_client().flush().await?;
     Ok(cid)
 }
+
+/// Number of keys fetched per page when iterating a double map prefix
+const KEYS_PAGE_SIZE: u32 = 100;
+
+/// Returns the second keys of the `blake2_128_concat` double map `F` under `key1`.
+///
+/// Keys are returned in lexicographic order, so paging from the prefix of `key1`
+/// visits exactly the entries under it before moving on to the next first key.
+pub(crate) async fn iter_prefix<R, F, K1, K2>(
+    client: &substrate_subxt::Client<R>,
+    key1: &K1,
+) -> Result<Vec<K2>>
+where
+    R: Runtime,
+    F: Store<R>,
+    K1: Encode,
+    K2: Decode,
+{
+    let mut prefix = F::prefix(client.metadata())?.0;
+    let encoded_key1 = key1.encode();
+    prefix.extend_from_slice(&blake2_128(&encoded_key1));
+    prefix.extend_from_slice(&encoded_key1);
+    // the second key is hashed with blake2_128 before its encoding
+    let key2_offset = prefix.len() + 16;
+    let mut keys = Vec::new();
+    let mut start_key = StorageKey(prefix.clone());
+    loop {
+        let page = client
+            .fetch_keys::<F>(KEYS_PAGE_SIZE, Some(start_key.clone()), None)
+            .await?;
+        let last_page = page.len() < KEYS_PAGE_SIZE as usize;
+        for key in page {
+            if !key.0.starts_with(&prefix) {
+                return Ok(keys)
+            }
+            keys.push(K2::decode(&mut &key.0[key2_offset..])?);
+            start_key = key;
+        }
+        if last_page {
+            return Ok(keys)
+        }
+    }
+}
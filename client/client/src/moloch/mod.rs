@@ -0,0 +1,221 @@
+mod subxt;
+
+pub use subxt::*;
+
+use crate::{
+    error::Error,
+    org::Org,
+};
+use substrate_subxt::{
+    system::System,
+    Runtime,
+    SignedExtension,
+    SignedExtra,
+};
+use sunshine_client_utils::{
+    async_trait,
+    Client,
+    Result,
+};
+
+#[async_trait]
+pub trait MolochClient<T: Runtime + Moloch>: Client<T> {
+    async fn summon(
+        &self,
+        org: <T as Org>::OrgId,
+        deposit: BalanceOf<T>,
+        controller: Option<<T as System>::AccountId>,
+        threshold: Threshold<T>,
+    ) -> Result<AccountOpenedEvent<T>>;
+    async fn propose_spend(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+    ) -> Result<SpendProposedEvent<T>>;
+    async fn propose_member(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        tribute: BalanceOf<T>,
+        shares_requested: <T as Org>::Shares,
+        applicant: <T as System>::AccountId,
+    ) -> Result<MemberProposedEvent<T>>;
+    async fn spend_trigger_vote(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        spend_id: <T as Moloch>::SpendId,
+    ) -> Result<SpendVoteTriggeredEvent<T>>;
+    async fn member_trigger_vote(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        prop_id: <T as Moloch>::MemId,
+    ) -> Result<MemberVoteTriggeredEvent<T>>;
+    async fn sudo_approve_spend_proposal(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        spend_id: <T as Moloch>::SpendId,
+    ) -> Result<SpendSudoApprovedEvent<T>>;
+    async fn burn_shares(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+    ) -> Result<WithdrawnPortionEvent<T>>;
+    async fn close_org_bank_account(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+    ) -> Result<AccountClosedEvent<T>>;
+    async fn moloch_bank(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+    ) -> Result<BankSt<T>>;
+    async fn moloch_spend_proposal(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        spend_id: <T as Moloch>::SpendId,
+    ) -> Result<SpendProp<T>>;
+    async fn member_proposal(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        prop_id: <T as Moloch>::MemId,
+    ) -> Result<MemberProp<T>>;
+}
+
+#[async_trait]
+impl<T, C> MolochClient<T> for C
+where
+    T: Runtime + Moloch,
+    <<T::Extra as SignedExtra<T>>::Extra as SignedExtension>::AdditionalSigned:
+        Send + Sync,
+    C: Client<T>,
+{
+    async fn summon(
+        &self,
+        org: <T as Org>::OrgId,
+        deposit: BalanceOf<T>,
+        controller: Option<<T as System>::AccountId>,
+        threshold: Threshold<T>,
+    ) -> Result<AccountOpenedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .summon_and_watch(&signer, org, deposit, controller, threshold)
+            .await?
+            .account_opened()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn propose_spend(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+    ) -> Result<SpendProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .propose_spend_and_watch(&signer, bank_id, amount, dest)
+            .await?
+            .spend_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn propose_member(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        tribute: BalanceOf<T>,
+        shares_requested: <T as Org>::Shares,
+        applicant: <T as System>::AccountId,
+    ) -> Result<MemberProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .propose_member_and_watch(
+                &signer,
+                bank_id,
+                tribute,
+                shares_requested,
+                applicant,
+            )
+            .await?
+            .member_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn spend_trigger_vote(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        spend_id: <T as Moloch>::SpendId,
+    ) -> Result<SpendVoteTriggeredEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .spend_trigger_vote_and_watch(&signer, bank_id, spend_id)
+            .await?
+            .spend_vote_triggered()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn member_trigger_vote(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        prop_id: <T as Moloch>::MemId,
+    ) -> Result<MemberVoteTriggeredEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .member_trigger_vote_and_watch(&signer, bank_id, prop_id)
+            .await?
+            .member_vote_triggered()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn sudo_approve_spend_proposal(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        spend_id: <T as Moloch>::SpendId,
+    ) -> Result<SpendSudoApprovedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .sudo_approve_spend_proposal_and_watch(&signer, bank_id, spend_id)
+            .await?
+            .spend_sudo_approved()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn burn_shares(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+    ) -> Result<WithdrawnPortionEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .burn_shares_and_watch(&signer, bank_id)
+            .await?
+            .withdrawn_portion()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn close_org_bank_account(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+    ) -> Result<AccountClosedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .close_org_bank_account_and_watch(&signer, bank_id)
+            .await?
+            .account_closed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn moloch_bank(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+    ) -> Result<BankSt<T>> {
+        Ok(self.chain_client().bank_stores(bank_id, None).await?)
+    }
+    async fn moloch_spend_proposal(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        spend_id: <T as Moloch>::SpendId,
+    ) -> Result<SpendProp<T>> {
+        Ok(self
+            .chain_client()
+            .spend_props(bank_id, spend_id, None)
+            .await?)
+    }
+    async fn member_proposal(
+        &self,
+        bank_id: <T as Moloch>::BankId,
+        prop_id: <T as Moloch>::MemId,
+    ) -> Result<MemberProp<T>> {
+        Ok(self
+            .chain_client()
+            .member_props(bank_id, prop_id, None)
+            .await?)
+    }
+}
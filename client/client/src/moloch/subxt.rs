@@ -0,0 +1,282 @@
+use crate::{
+    donate::{
+        Donate,
+        DonateEventsDecoder,
+    },
+    org::{
+        Org,
+        OrgEventsDecoder,
+    },
+    vote::{
+        Vote,
+        VoteEventsDecoder,
+    },
+};
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
+use frame_support::Parameter;
+use sp_runtime::traits::{
+    AtLeast32Bit,
+    MaybeSerializeDeserialize,
+    Member,
+    Zero,
+};
+use std::fmt::Debug;
+use substrate_subxt::{
+    balances::{
+        Balances,
+        BalancesEventsDecoder,
+    },
+    module,
+    sp_runtime,
+    system::{
+        System,
+        SystemEventsDecoder,
+    },
+    Call,
+    Event,
+    Store,
+};
+use sunshine_bounty_utils::{
+    bank::{
+        BankState,
+        SpendProposal,
+        SpendState,
+    },
+    moloch::{
+        MembershipProposal,
+        ProposalState,
+    },
+    organization::OrgRep,
+    vote::{
+        ThresholdInput,
+        XorThreshold,
+    },
+};
+
+pub type BalanceOf<T> = <T as Balances>::Balance;
+pub type BankSt<T> = BankState<
+    <T as Moloch>::BankId,
+    <T as System>::AccountId,
+    <T as Org>::OrgId,
+    <T as Vote>::ThresholdId,
+>;
+pub type Threshold<T> = ThresholdInput<
+    OrgRep<<T as Org>::OrgId>,
+    XorThreshold<<T as Vote>::Signal, <T as Vote>::Percent>,
+>;
+pub type SpendProp<T> = SpendProposal<
+    <T as Moloch>::BankId,
+    <T as Moloch>::SpendId,
+    BalanceOf<T>,
+    <T as System>::AccountId,
+    SpendState<<T as Vote>::VoteId>,
+>;
+pub type MemberProp<T> = MembershipProposal<
+    <T as Moloch>::BankId,
+    <T as Moloch>::MemId,
+    BalanceOf<T>,
+    <T as Org>::Shares,
+    <T as System>::AccountId,
+    ProposalState<<T as Vote>::VoteId>,
+>;
+
+#[module]
+pub trait Moloch: System + Balances + Org + Vote + Donate {
+    type BankId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+    type SpendId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+    type MemId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+}
+
+// ~~ Maps ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BankStoresStore<T: Moloch> {
+    #[store(returns = BankSt<T>)]
+    pub id: T::BankId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SpendPropsStore<T: Moloch> {
+    #[store(returns = SpendProp<T>)]
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct MemberPropsStore<T: Moloch> {
+    #[store(returns = MemberProp<T>)]
+    pub bank_id: T::BankId,
+    pub prop_id: T::MemId,
+}
+
+// ~~ (Calls, Events) ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SummonCall<T: Moloch> {
+    pub org: <T as Org>::OrgId,
+    pub deposit: BalanceOf<T>,
+    pub controller: Option<<T as System>::AccountId>,
+    pub threshold: Threshold<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct AccountOpenedEvent<T: Moloch> {
+    pub opener: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub deposit: BalanceOf<T>,
+    pub org: <T as Org>::OrgId,
+    pub controller: Option<<T as System>::AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeSpendCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendProposedEvent<T: Moloch> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeMemberCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub tribute: BalanceOf<T>,
+    pub shares_requested: <T as Org>::Shares,
+    pub applicant: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MemberProposedEvent<T: Moloch> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub prop_id: T::MemId,
+    pub tribute: BalanceOf<T>,
+    pub shares_requested: <T as Org>::Shares,
+    pub applicant: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SpendTriggerVoteCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendVoteTriggeredEvent<T: Moloch> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct MemberTriggerVoteCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub prop_id: T::MemId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MemberVoteTriggeredEvent<T: Moloch> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub prop_id: T::MemId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SudoApproveSpendProposalCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendSudoApprovedEvent<T: Moloch> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendProposalPolledEvent<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub state: SpendState<<T as Vote>::VoteId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MemberProposalPolledEvent<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub prop_id: T::MemId,
+    pub state: ProposalState<<T as Vote>::VoteId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct BurnSharesCall<T: Moloch> {
+    pub bank_id: T::BankId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SharesBurnedEvent<T: Moloch> {
+    pub org: <T as Org>::OrgId,
+    pub shares: <T as Org>::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct WithdrawnPortionEvent<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub amount_withdrawn: BalanceOf<T>,
+    pub amount_left: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CloseOrgBankAccountCall<T: Moloch> {
+    pub bank_id: T::BankId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct AccountClosedEvent<T: Moloch> {
+    pub closer: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub org: <T as Org>::OrgId,
+}
@@ -55,6 +55,50 @@ pub trait OrgClient<T: Runtime + Org>: Client<T> {
         organization: <T as Org>::OrgId,
         old_accounts: &[(<T as System>::AccountId, <T as Org>::Shares)],
     ) -> Result<SharesBatchBurnedEvent<T>>;
+    async fn put_supervisor(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+    ) -> Result<SupervisorPutEvent<T>>;
+    async fn propose_supervisor(
+        &self,
+        organization: <T as Org>::OrgId,
+        candidate: <T as System>::AccountId,
+    ) -> Result<SupervisorProposedEvent<T>>;
+    async fn recall_supervisor(
+        &self,
+        organization: <T as Org>::OrgId,
+    ) -> Result<SupervisorRecallProposedEvent<T>>;
+    async fn transfer_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        to: <T as System>::AccountId,
+        amount: <T as Org>::Shares,
+    ) -> Result<SharesTransferredEvent<T>>;
+    async fn list_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        amount: <T as Org>::Shares,
+        price: BalanceOf<T>,
+    ) -> Result<SharesListedEvent<T>>;
+    async fn buy_shares(
+        &self,
+        order_id: <T as Org>::OrderId,
+        amount: <T as Org>::Shares,
+    ) -> Result<SharesBoughtEvent<T>>;
+    async fn issue_vested_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+        shares: <T as Org>::Shares,
+        cliff: <T as System>::BlockNumber,
+        duration: <T as System>::BlockNumber,
+    ) -> Result<VestedSharesIssuedEvent<T>>;
+    async fn revoke_vesting(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+    ) -> Result<VestingRevokedEvent<T>>;
 }
 
 #[async_trait]
@@ -162,6 +206,113 @@ where
             .shares_batch_burned()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn put_supervisor(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+    ) -> Result<SupervisorPutEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .put_supervisor_and_watch(&signer, organization, &who)
+            .await?
+            .supervisor_put()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn propose_supervisor(
+        &self,
+        organization: <T as Org>::OrgId,
+        candidate: <T as System>::AccountId,
+    ) -> Result<SupervisorProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .propose_supervisor_and_watch(&signer, organization, &candidate)
+            .await?
+            .supervisor_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn recall_supervisor(
+        &self,
+        organization: <T as Org>::OrgId,
+    ) -> Result<SupervisorRecallProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .recall_supervisor_and_watch(&signer, organization)
+            .await?
+            .supervisor_recall_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn transfer_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        to: <T as System>::AccountId,
+        amount: <T as Org>::Shares,
+    ) -> Result<SharesTransferredEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .transfer_shares_and_watch(&signer, organization, &to, amount)
+            .await?
+            .shares_transferred()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn list_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        amount: <T as Org>::Shares,
+        price: BalanceOf<T>,
+    ) -> Result<SharesListedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .list_shares_and_watch(&signer, organization, amount, price)
+            .await?
+            .shares_listed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn buy_shares(
+        &self,
+        order_id: <T as Org>::OrderId,
+        amount: <T as Org>::Shares,
+    ) -> Result<SharesBoughtEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .buy_shares_and_watch(&signer, order_id, amount)
+            .await?
+            .shares_bought()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn issue_vested_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+        shares: <T as Org>::Shares,
+        cliff: <T as System>::BlockNumber,
+        duration: <T as System>::BlockNumber,
+    ) -> Result<VestedSharesIssuedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .issue_vested_shares_and_watch(
+                &signer,
+                organization,
+                &who,
+                shares,
+                cliff,
+                duration,
+            )
+            .await?
+            .vested_shares_issued()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn revoke_vesting(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+    ) -> Result<VestingRevokedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .revoke_vesting_and_watch(&signer, organization, &who)
+            .await?
+            .vesting_revoked()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
 }
 
 #[cfg(test)]
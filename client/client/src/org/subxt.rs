@@ -19,6 +19,10 @@ use sp_runtime::traits::{
 };
 use std::fmt::Debug;
 use substrate_subxt::{
+    balances::{
+        Balances,
+        BalancesEventsDecoder,
+    },
     module,
     sp_runtime,
     system::{
@@ -33,13 +37,19 @@ use sunshine_bounty_utils::{
     organization::Organization,
     share::{
         ProfileState,
+        SellOrder,
         ShareProfile,
+        TransferPolicy,
+        VestingSchedule,
+        VestingVotePolicy,
     },
 };
 
+pub type BalanceOf<T> = <T as Balances>::Balance;
+
 /// The subset of the org trait and its inherited traits that the client must inherit
 #[module]
-pub trait Org: System {
+pub trait Org: System + Balances {
     /// Cid type
     type IpfsReference: Parameter + Member + Default;
 
@@ -65,6 +75,24 @@ pub trait Org: System {
         + Debug
         + Zero;
 
+    /// Identifier for the votes electing and recalling supervisors
+    type ElectionId: Parameter
+        + Member
+        + Copy
+        + Default
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// Identifier for sell orders
+    type OrderId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+
     /// Constitution associated type, text block
     type Constitution: 'static
         + Codec
@@ -207,3 +235,199 @@ pub struct SharesBatchBurnedEvent<T: Org> {
     pub organization: T::OrgId,
     pub total_new_shares_burned: T::Shares,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PutSupervisorCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SupervisorPutEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub who: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeSupervisorCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub candidate: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SupervisorProposedEvent<T: Org> {
+    pub proposer: <T as System>::AccountId,
+    pub organization: T::OrgId,
+    pub candidate: <T as System>::AccountId,
+    pub election_id: T::ElectionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RecallSupervisorCall<T: Org> {
+    pub organization: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SupervisorRecallProposedEvent<T: Org> {
+    pub proposer: <T as System>::AccountId,
+    pub organization: T::OrgId,
+    pub election_id: T::ElectionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SupervisorElectedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub supervisor: <T as System>::AccountId,
+    pub term_ends: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SupervisorRecalledEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub supervisor: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SupervisorTermsStore<T: Org> {
+    #[store(returns = Option<<T as System>::BlockNumber>)]
+    pub org: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SetTransferPolicyCall<T: Org> {
+    pub organization: T::OrgId,
+    pub policy: TransferPolicy,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct TransferPolicySetEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub policy: TransferPolicy,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ApproveTransferCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub from: &'a <T as System>::AccountId,
+    pub to: &'a <T as System>::AccountId,
+    pub amount: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct TransferApprovedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub from: <T as System>::AccountId,
+    pub to: <T as System>::AccountId,
+    pub amount: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct TransferSharesCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub to: &'a <T as System>::AccountId,
+    pub amount: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SharesTransferredEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub from: <T as System>::AccountId,
+    pub to: <T as System>::AccountId,
+    pub amount: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ListSharesCall<T: Org> {
+    pub organization: T::OrgId,
+    pub amount: T::Shares,
+    pub price: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SharesListedEvent<T: Org> {
+    pub seller: <T as System>::AccountId,
+    pub organization: T::OrgId,
+    pub order_id: T::OrderId,
+    pub amount: T::Shares,
+    pub price: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CancelSellOrderCall<T: Org> {
+    pub order_id: T::OrderId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SellOrderCancelledEvent<T: Org> {
+    pub order_id: T::OrderId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct BuySharesCall<T: Org> {
+    pub order_id: T::OrderId,
+    pub amount: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SharesBoughtEvent<T: Org> {
+    pub buyer: <T as System>::AccountId,
+    pub order_id: T::OrderId,
+    pub amount: T::Shares,
+    pub paid: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SellOrdersStore<T: Org> {
+    #[store(returns = Option<SellOrder<T::OrgId, <T as System>::AccountId, T::Shares, BalanceOf<T>>>)]
+    pub order_id: T::OrderId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct IssueVestedSharesCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+    pub shares: T::Shares,
+    pub cliff: <T as System>::BlockNumber,
+    pub duration: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VestedSharesIssuedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub who: <T as System>::AccountId,
+    pub shares: T::Shares,
+    pub cliff_ends: <T as System>::BlockNumber,
+    pub vesting_ends: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RevokeVestingCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VestingRevokedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub who: <T as System>::AccountId,
+    pub returned: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SetVestingVotePolicyCall<T: Org> {
+    pub organization: T::OrgId,
+    pub policy: VestingVotePolicy,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VestingVotePolicySetEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub policy: VestingVotePolicy,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct VestingSchedulesStore<'a, T: Org> {
+    #[store(returns = Option<VestingSchedule<<T as System>::AccountId, T::Shares, <T as System>::BlockNumber>>)]
+    pub org: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+}
@@ -14,7 +14,10 @@ use substrate_subxt::{
 };
 use sunshine_bounty_utils::{
     organization::OrgRep,
-    vote::Threshold,
+    vote::{
+        TallyMethod,
+        Threshold,
+    },
 };
 use sunshine_client_utils::{
     async_trait,
@@ -38,12 +41,56 @@ pub trait VoteClient<T: Runtime + Vote>: Client<T> {
         threshold: Threshold<<T as Vote>::Percent>,
         duration: Option<<T as System>::BlockNumber>,
     ) -> Result<NewVoteStartedEvent<T>>;
+    async fn create_conviction_vote(
+        &self,
+        topic: Option<<T as Vote>::VoteTopic>,
+        organization: OrgRep<T::OrgId>,
+        threshold: Threshold<<T as Vote>::Percent>,
+        duration: <T as System>::BlockNumber,
+    ) -> Result<NewVoteStartedEvent<T>>;
     async fn submit_vote(
         &self,
         vote_id: <T as Vote>::VoteId,
         direction: <T as Vote>::VoterView,
         justification: Option<<T as Vote>::VoteJustification>,
     ) -> Result<VotedEvent<T>>;
+    async fn submit_conviction_vote(
+        &self,
+        vote_id: <T as Vote>::VoteId,
+        direction: <T as Vote>::VoterView,
+        conviction: <T as Vote>::Conviction,
+        justification: Option<<T as Vote>::VoteJustification>,
+    ) -> Result<ConvictionVotedEvent<T>>;
+    async fn delegate(
+        &self,
+        org: T::OrgId,
+        delegate: <T as System>::AccountId,
+    ) -> Result<SignalDelegatedEvent<T>>;
+    async fn undelegate(
+        &self,
+        org: T::OrgId,
+    ) -> Result<SignalUndelegatedEvent<T>>;
+    async fn delegate_vote(
+        &self,
+        vote_id: <T as Vote>::VoteId,
+        delegate: <T as System>::AccountId,
+    ) -> Result<VoteDelegatedEvent<T>>;
+    async fn undelegate_vote(
+        &self,
+        vote_id: <T as Vote>::VoteId,
+    ) -> Result<VoteUndelegatedEvent<T>>;
+    async fn create_multi_vote(
+        &self,
+        options: Vec<<T as Vote>::VoteTopic>,
+        organization: OrgRep<T::OrgId>,
+        method: TallyMethod,
+        duration: <T as System>::BlockNumber,
+    ) -> Result<NewVoteStartedEvent<T>>;
+    async fn submit_ballot(
+        &self,
+        vote_id: <T as Vote>::VoteId,
+        choices: Vec<u32>,
+    ) -> Result<BallotSubmittedEvent<T>>;
     async fn vote_threshold(
         &self,
         threshold_id: <T as Vote>::ThresholdId,
@@ -120,6 +167,33 @@ where
             .new_vote_started()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn create_conviction_vote(
+        &self,
+        topic: Option<<T as Vote>::VoteTopic>,
+        organization: OrgRep<T::OrgId>,
+        threshold: Threshold<<T as Vote>::Percent>,
+        duration: <T as System>::BlockNumber,
+    ) -> Result<NewVoteStartedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let topic = if let Some(t) = topic {
+            let iref: <T as Org>::IpfsReference =
+                crate::post(self, t).await?.into();
+            Some(iref)
+        } else {
+            None
+        };
+        self.chain_client()
+            .create_conviction_vote_and_watch(
+                &signer,
+                topic,
+                organization,
+                threshold,
+                duration,
+            )
+            .await?
+            .new_vote_started()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn submit_vote(
         &self,
         vote_id: <T as Vote>::VoteId,
@@ -140,6 +214,117 @@ where
             .voted()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn submit_conviction_vote(
+        &self,
+        vote_id: <T as Vote>::VoteId,
+        direction: <T as Vote>::VoterView,
+        conviction: <T as Vote>::Conviction,
+        justification: Option<<T as Vote>::VoteJustification>,
+    ) -> Result<ConvictionVotedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let justification = if let Some(j) = justification {
+            let iref: <T as Org>::IpfsReference =
+                crate::post(self, j).await?.into();
+            Some(iref)
+        } else {
+            None
+        };
+        self.chain_client()
+            .submit_conviction_vote_and_watch(
+                &signer,
+                vote_id,
+                direction,
+                conviction,
+                justification,
+            )
+            .await?
+            .conviction_voted()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn delegate(
+        &self,
+        org: T::OrgId,
+        delegate: <T as System>::AccountId,
+    ) -> Result<SignalDelegatedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .delegate_and_watch(&signer, org, delegate)
+            .await?
+            .signal_delegated()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn undelegate(
+        &self,
+        org: T::OrgId,
+    ) -> Result<SignalUndelegatedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .undelegate_and_watch(&signer, org)
+            .await?
+            .signal_undelegated()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn delegate_vote(
+        &self,
+        vote_id: <T as Vote>::VoteId,
+        delegate: <T as System>::AccountId,
+    ) -> Result<VoteDelegatedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .delegate_vote_and_watch(&signer, vote_id, delegate)
+            .await?
+            .vote_delegated()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn undelegate_vote(
+        &self,
+        vote_id: <T as Vote>::VoteId,
+    ) -> Result<VoteUndelegatedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .undelegate_vote_and_watch(&signer, vote_id)
+            .await?
+            .vote_undelegated()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn create_multi_vote(
+        &self,
+        options: Vec<<T as Vote>::VoteTopic>,
+        organization: OrgRep<T::OrgId>,
+        method: TallyMethod,
+        duration: <T as System>::BlockNumber,
+    ) -> Result<NewVoteStartedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let mut irefs = Vec::with_capacity(options.len());
+        for option in options {
+            let iref: <T as Org>::IpfsReference =
+                crate::post(self, option).await?.into();
+            irefs.push(iref);
+        }
+        self.chain_client()
+            .create_multi_vote_and_watch(
+                &signer,
+                irefs,
+                organization,
+                method,
+                duration,
+            )
+            .await?
+            .new_vote_started()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn submit_ballot(
+        &self,
+        vote_id: <T as Vote>::VoteId,
+        choices: Vec<u32>,
+    ) -> Result<BallotSubmittedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .submit_ballot_and_watch(&signer, vote_id, choices)
+            .await?
+            .ballot_submitted()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn vote_threshold(
         &self,
         threshold_id: <T as Vote>::ThresholdId,
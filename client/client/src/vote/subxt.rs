@@ -39,9 +39,12 @@ use substrate_subxt::{
 use sunshine_bounty_utils::{
     organization::OrgRep,
     vote::{
+        MultiVoteState,
+        TallyMethod,
         Threshold,
         ThresholdConfig,
         Vote as VoteVector,
+        VoteOutcome,
         VoteState,
         XorThreshold,
     },
@@ -112,6 +115,17 @@ pub trait Vote: System + Org {
         + Send
         + Sync;
 
+    /// Lock multiplier for conviction votes
+    type Conviction: 'static
+        + Codec
+        + Default
+        + Debug
+        + Eq
+        + Copy
+        + Clone
+        + Send
+        + Sync;
+
     /// Vote justification
     type VoteJustification: 'static
         + Codec
@@ -150,6 +164,19 @@ pub struct VoteLoggerStore<T: Vote> {
     pub who: <T as System>::AccountId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct MultiVoteStatesStore<T: Vote> {
+    #[store(returns = MultiVoteState<T::Signal, <T as System>::BlockNumber, <T as Org>::IpfsReference>)]
+    pub vote: T::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BallotsStore<T: Vote> {
+    #[store(returns = Vec<u32>)]
+    pub vote: T::VoteId,
+    pub who: <T as System>::AccountId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct VoteThresholdsStore<T: Vote> {
     #[store(returns = ThreshConfig<T>)]
@@ -174,6 +201,14 @@ pub struct CreatePercentVoteCall<T: Vote> {
     pub duration: Option<<T as System>::BlockNumber>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CreateConvictionVoteCall<T: Vote> {
+    pub topic: Option<<T as Org>::IpfsReference>,
+    pub organization: OrgRep<T::OrgId>,
+    pub threshold: Threshold<T::Percent>,
+    pub duration: <T as System>::BlockNumber,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct SubmitVoteCall<T: Vote> {
     pub vote_id: T::VoteId,
@@ -181,6 +216,50 @@ pub struct SubmitVoteCall<T: Vote> {
     pub justification: Option<<T as Org>::IpfsReference>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SubmitConvictionVoteCall<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub direction: <T as Vote>::VoterView,
+    pub conviction: <T as Vote>::Conviction,
+    pub justification: Option<<T as Org>::IpfsReference>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct DelegateCall<T: Vote> {
+    pub org: T::OrgId,
+    pub delegate: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct UndelegateCall<T: Vote> {
+    pub org: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct DelegateVoteCall<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub delegate: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct UndelegateVoteCall<T: Vote> {
+    pub vote_id: T::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CreateMultiVoteCall<T: Vote> {
+    pub options: Vec<<T as Org>::IpfsReference>,
+    pub organization: OrgRep<T::OrgId>,
+    pub method: TallyMethod,
+    pub duration: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SubmitBallotCall<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub choices: Vec<u32>,
+}
+
 // ~~ Events ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -196,3 +275,55 @@ pub struct VotedEvent<T: Vote> {
     pub voter: <T as System>::AccountId,
     pub view: <T as Vote>::VoterView,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ConvictionVotedEvent<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub voter: <T as System>::AccountId,
+    pub view: <T as Vote>::VoterView,
+    pub conviction: <T as Vote>::Conviction,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ConvictionLockExpiredEvent<T: Vote> {
+    pub org: T::OrgId,
+    pub voter: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SignalDelegatedEvent<T: Vote> {
+    pub org: T::OrgId,
+    pub delegator: <T as System>::AccountId,
+    pub delegate: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SignalUndelegatedEvent<T: Vote> {
+    pub org: T::OrgId,
+    pub delegator: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VoteDelegatedEvent<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub delegator: <T as System>::AccountId,
+    pub delegate: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VoteUndelegatedEvent<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub delegator: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BallotSubmittedEvent<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub voter: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VoteClosedEvent<T: Vote> {
+    pub vote_id: T::VoteId,
+    pub outcome: VoteOutcome,
+}
@@ -0,0 +1,96 @@
+mod subxt;
+
+pub use subxt::*;
+
+use crate::error::Error;
+use substrate_subxt::{
+    system::System,
+    Runtime,
+    SignedExtension,
+    SignedExtra,
+};
+use sunshine_bounty_utils::vote::Threshold;
+use sunshine_client_utils::{
+    async_trait,
+    Client,
+    Result,
+};
+
+#[async_trait]
+pub trait VoteDirectClient<T: Runtime + VoteDirect>: Client<T> {
+    async fn create_direct_signal_vote(
+        &self,
+        topic: Option<<T as VoteDirect>::Cid>,
+        src: Electorate<T>,
+        threshold: Threshold<<T as VoteDirect>::Signal>,
+        duration: Option<<T as System>::BlockNumber>,
+    ) -> Result<NewVoteStartedEvent<T>>;
+    async fn create_direct_percent_vote(
+        &self,
+        topic: Option<<T as VoteDirect>::Cid>,
+        src: Electorate<T>,
+        threshold: Threshold<<T as VoteDirect>::Percent>,
+        duration: Option<<T as System>::BlockNumber>,
+    ) -> Result<NewVoteStartedEvent<T>>;
+    async fn submit_direct_vote(
+        &self,
+        vote_id: <T as VoteDirect>::VoteId,
+        direction: <T as VoteDirect>::VoterView,
+        justification: Option<<T as VoteDirect>::Cid>,
+    ) -> Result<VotedEvent<T>>;
+}
+
+#[async_trait]
+impl<T, C> VoteDirectClient<T> for C
+where
+    T: Runtime + VoteDirect,
+    <<T::Extra as SignedExtra<T>>::Extra as SignedExtension>::AdditionalSigned:
+        Send + Sync,
+    C: Client<T>,
+{
+    async fn create_direct_signal_vote(
+        &self,
+        topic: Option<<T as VoteDirect>::Cid>,
+        src: Electorate<T>,
+        threshold: Threshold<<T as VoteDirect>::Signal>,
+        duration: Option<<T as System>::BlockNumber>,
+    ) -> Result<NewVoteStartedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .create_signal_vote_and_watch(
+                &signer, topic, src, threshold, duration,
+            )
+            .await?
+            .new_vote_started()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn create_direct_percent_vote(
+        &self,
+        topic: Option<<T as VoteDirect>::Cid>,
+        src: Electorate<T>,
+        threshold: Threshold<<T as VoteDirect>::Percent>,
+        duration: Option<<T as System>::BlockNumber>,
+    ) -> Result<NewVoteStartedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .create_percent_vote_and_watch(
+                &signer, topic, src, threshold, duration,
+            )
+            .await?
+            .new_vote_started()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn submit_direct_vote(
+        &self,
+        vote_id: <T as VoteDirect>::VoteId,
+        direction: <T as VoteDirect>::VoterView,
+        justification: Option<<T as VoteDirect>::Cid>,
+    ) -> Result<VotedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .submit_vote_and_watch(&signer, vote_id, direction, justification)
+            .await?
+            .voted()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+}
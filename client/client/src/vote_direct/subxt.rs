@@ -0,0 +1,135 @@
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
+use frame_support::Parameter;
+use sp_runtime::{
+    traits::{
+        AtLeast32Bit,
+        MaybeSerializeDeserialize,
+        Member,
+        Zero,
+    },
+    PerThing,
+};
+use std::fmt::Debug;
+use substrate_subxt::{
+    module,
+    sp_runtime,
+    system::{
+        System,
+        SystemEventsDecoder,
+    },
+    Call,
+    Event,
+    Store,
+};
+use sunshine_bounty_utils::{
+    share::WeightedVector,
+    vote::{
+        Threshold,
+        Vote as VoteVector,
+        VoteState,
+    },
+};
+
+pub type Electorate<T> =
+    WeightedVector<<T as System>::AccountId, <T as VoteDirect>::Signal>;
+
+/// The subset of the `vote_direct::Trait` that a client must implement.
+#[module]
+pub trait VoteDirect: System {
+    /// Cid type for vote topics and justifications
+    type Cid: Parameter + Member + Default + Copy;
+
+    /// The identifier for each vote
+    type VoteId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// The native type for vote strength
+    type Signal: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + Zero;
+
+    /// The type for percentage vote thresholds
+    type Percent: 'static + PerThing + Codec + Send + Sync;
+
+    /// Vote views
+    type VoterView: 'static
+        + Codec
+        + Default
+        + Debug
+        + Eq
+        + Copy
+        + Clone
+        + Send
+        + Sync;
+}
+
+// ~~ Maps ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct VoteStatesStore<T: VoteDirect> {
+    #[store(returns = VoteState<T::Signal, <T as System>::BlockNumber, T::Cid>)]
+    pub vote: T::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct VoteLoggerStore<T: VoteDirect> {
+    #[store(returns = VoteVector<T::Signal, T::Cid>)]
+    pub vote: T::VoteId,
+    pub who: <T as System>::AccountId,
+}
+
+// ~~ Calls ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CreateSignalVoteCall<T: VoteDirect> {
+    pub topic: Option<T::Cid>,
+    pub src: Electorate<T>,
+    pub threshold: Threshold<T::Signal>,
+    pub duration: Option<<T as System>::BlockNumber>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CreatePercentVoteCall<T: VoteDirect> {
+    pub topic: Option<T::Cid>,
+    pub src: Electorate<T>,
+    pub threshold: Threshold<T::Percent>,
+    pub duration: Option<<T as System>::BlockNumber>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SubmitVoteCall<T: VoteDirect> {
+    pub vote_id: T::VoteId,
+    pub direction: T::VoterView,
+    pub justification: Option<T::Cid>,
+}
+
+// ~~ Events ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct NewVoteStartedEvent<T: VoteDirect> {
+    pub caller: <T as System>::AccountId,
+    pub new_vote_id: T::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VotedEvent<T: VoteDirect> {
+    pub vote_id: T::VoteId,
+    pub voter: <T as System>::AccountId,
+    pub view: T::VoterView,
+}
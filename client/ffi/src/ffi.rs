@@ -239,7 +239,13 @@ where
             .client
             .read()
             .await
-            .post_bounty(bounty, amount.parse::<u64>()?.into())
+            // the native token is the runtime's default asset
+            .post_bounty(
+                bounty,
+                Default::default(),
+                amount.parse::<u64>()?.into(),
+                None,
+            )
             .await?;
         info!("Bounty Created: {:?}", event);
         Ok(event.id.into())
@@ -299,7 +305,10 @@ where
             .client
             .read()
             .await
-            .approve_bounty_submission(submission_id.parse::<u64>()?.into())
+            .approve_bounty_submission(
+                submission_id.parse::<u64>()?.into(),
+                None,
+            )
             .await?;
         info!("Approved SubmissionId: {} with {:?}", submission_id, event);
         Ok(event.new_total.into())
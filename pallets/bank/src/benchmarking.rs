@@ -0,0 +1,211 @@
+//! Bank pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_support::traits::OnFinalize;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+use util::{
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+    vote::Threshold as VoteThreshold,
+};
+
+const SEED: u32 = 0;
+const MAX_MEMBERS: u32 = 100;
+const MAX_PER_BLOCK: u32 = 100;
+
+fn org_with_members<T: Trait>(caller: T::AccountId, m: u32) -> T::OrgId {
+    let mut members = vec![caller.clone()];
+    members.extend((1..m).map(|i| account("member", i, SEED)));
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        Some(caller),
+        T::Cid::default(),
+    )
+    .expect("org registration with supervisor works")
+}
+
+fn majority<T: Trait>(org: T::OrgId) -> Threshold<T> {
+    ThresholdInput::new(
+        OrgRep::Equal(org),
+        XorThreshold::Percent(VoteThreshold::new(
+            Permill::from_percent(51),
+            None,
+        )),
+    )
+}
+
+/// Opens a funded bank for an org of size `m` controlled by `caller`
+fn funded_bank<T: Trait>(caller: T::AccountId, m: u32) -> T::BankId {
+    <T as Trait>::Currency::deposit(
+        <T as Trait>::Currency::native_currency_id(),
+        &caller,
+        BalanceOf::<T>::max_value(),
+    )
+    .expect("native deposit works");
+    let org = org_with_members::<T>(caller.clone(), m);
+    let deposit = T::MinDeposit::get() * 1000u32.into();
+    Module::<T>::open_bank_account(
+        caller.clone(),
+        org,
+        deposit,
+        Some(caller),
+        majority::<T>(org),
+    )
+    .expect("bank opens for funded caller")
+}
+
+benchmarks! {
+    _ { }
+
+    open {
+        let caller: T::AccountId = whitelisted_caller();
+        let currency_id = <T as Trait>::Currency::native_currency_id();
+        <T as Trait>::Currency::deposit(currency_id, &caller, BalanceOf::<T>::max_value())?;
+        let org = org_with_members::<T>(caller.clone(), 1);
+        let count = Module::<T>::total_bank_count();
+    }: _(RawOrigin::Signed(caller.clone()), org, currency_id, T::MinDeposit::get(), Some(caller), majority::<T>(org))
+    verify {
+        assert_eq!(Module::<T>::total_bank_count(), count + 1);
+    }
+
+    propose_spend {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+    }: _(RawOrigin::Signed(caller.clone()), bank_id, T::MinDeposit::get(), caller)
+
+    trigger_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), m);
+        let spend_id = Module::<T>::_propose_spend(&caller, bank_id, T::MinDeposit::get(), caller.clone())?;
+    }: _(RawOrigin::Signed(caller), bank_id, spend_id)
+
+    sudo_approve {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let spend_id = Module::<T>::_propose_spend(&caller, bank_id, T::MinDeposit::get(), dest)?;
+    }: _(RawOrigin::Signed(caller), bank_id, spend_id)
+    verify {
+        assert_eq!(
+            Module::<T>::spend_proposals(bank_id, spend_id).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+    }
+
+    close {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), m);
+    }: _(RawOrigin::Signed(caller), bank_id)
+    verify {
+        assert!(!Module::<T>::is_bank(bank_id));
+    }
+
+    ragequit {
+        let b in 1 .. T::MaxTreasuryPerOrg::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 2);
+        let org = Module::<T>::banks(bank_id).unwrap().org();
+        for _ in 1..b {
+            Module::<T>::open_bank_account_in(
+                caller.clone(),
+                org,
+                <T as Trait>::Currency::native_currency_id(),
+                T::MinDeposit::get(),
+                Some(caller.clone()),
+                majority::<T>(org),
+            )?;
+        }
+    }: _(RawOrigin::Signed(caller.clone()), bank_id, 1u32.into())
+    verify {
+        assert!(!<org::Module<T>>::is_member_of_group(org, &caller));
+    }
+
+    propose_scheduled_spend {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        let schedule = SpendSchedule::Every(1u32.into(), 12);
+    }: _(RawOrigin::Signed(caller.clone()), bank_id, T::MinDeposit::get(), caller, schedule)
+
+    cancel_scheduled_spend {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        let spend_id = Module::<T>::_propose_spend(&caller, bank_id, T::MinDeposit::get(), caller.clone())?;
+        <SpendSchedules<T>>::insert(bank_id, spend_id, SpendSchedule::Every(1u32.into(), 12));
+    }: _(RawOrigin::Signed(caller), bank_id, spend_id)
+    verify {
+        assert!(!Module::<T>::is_spend(bank_id, spend_id));
+    }
+
+    spend_allowance {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        let cap = SpendCap::new(T::MinDeposit::get(), 10u32.into());
+        <SpenderAllowances<T>>::insert(bank_id, &caller, Allowance::new(cap));
+        let dest: T::AccountId = account("dest", 0, SEED);
+    }: _(RawOrigin::Signed(caller), bank_id, T::MinDeposit::get(), dest)
+
+    propose_close {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), m);
+    }: _(RawOrigin::Signed(caller), bank_id)
+
+    propose_controller_change {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), m);
+        let new_controller: T::AccountId = account("controller", 0, SEED);
+    }: _(RawOrigin::Signed(caller), bank_id, Some(new_controller))
+
+    cancel_spend {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        let spend_id = Module::<T>::_propose_spend(&caller, bank_id, T::MinDeposit::get(), caller.clone())?;
+    }: _(RawOrigin::Signed(caller), bank_id, spend_id)
+    verify {
+        assert!(!Module::<T>::is_spend(bank_id, spend_id));
+    }
+
+    pay_scheduled_spends {
+        let s in 1 .. MAX_PER_BLOCK;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        let now: T::BlockNumber = 1u32.into();
+        // no proposals are polled in this block
+        <SpendPollFrequency<T>>::put(now + 1u32.into());
+        for _ in 0..s {
+            let spend_id = Module::<T>::_propose_spend(&caller, bank_id, T::MinDeposit::get(), caller.clone())?;
+            let prop = Module::<T>::spend_proposals(bank_id, spend_id).unwrap();
+            <SpendProposals<T>>::insert(bank_id, spend_id, prop.set_state(SpendState::ApprovedAndScheduled));
+            <SpendSchedules<T>>::insert(bank_id, spend_id, SpendSchedule::Every(1u32.into(), 12));
+            <ScheduledSpendsDue<T>>::insert(now, (bank_id, spend_id), ());
+        }
+        <frame_system::Module<T>>::set_block_number(now);
+    }: { Module::<T>::on_finalize(now) }
+    verify {
+        assert_eq!(<ScheduledSpendsDue<T>>::iter_prefix(now + 1u32.into()).count() as u32, s);
+    }
+
+    poll_proposals {
+        let p in 1 .. MAX_PER_BLOCK;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        let now: T::BlockNumber = 1u32.into();
+        <SpendPollFrequency<T>>::put(now);
+        for _ in 0..p {
+            let spend_id = Module::<T>::_propose_spend(&caller, bank_id, T::MinDeposit::get(), caller.clone())?;
+            Module::<T>::_trigger_vote_on_spend_proposal(&caller, bank_id, spend_id)?;
+        }
+        <frame_system::Module<T>>::set_block_number(now);
+    }: { Module::<T>::on_finalize(now) }
+}
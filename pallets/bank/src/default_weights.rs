@@ -0,0 +1,231 @@
+//! Default weights for the bank pallet, to be regenerated from `benchmarking.rs`
+//! with the `benchmark` subcommand of a node built with `runtime-benchmarks`
+//!
+//! The runtime charges database access at its own `DbWeight` with `SubstrateWeight`,
+//! `()` charges `RocksDbWeight` for the mocks
+
+use frame_support::{
+    traits::Get,
+    weights::{
+        constants::RocksDbWeight as DbWeight,
+        Weight,
+    },
+};
+use sp_std::marker::PhantomData;
+
+impl crate::WeightInfo for () {
+    fn open() -> Weight {
+        (95_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(7 as Weight))
+    }
+    fn propose_spend() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn trigger_vote(m: u32) -> Weight {
+        (75_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn sudo_approve() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn close(m: u32) -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add((62_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads(2 * m as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn org_spend() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn ragequit(b: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((45_000_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads(4 * b as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 * b as Weight))
+    }
+    fn propose_scheduled_spend() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn cancel_scheduled_spend() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_spend_policy() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_spender_allowance() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn spend_allowance() -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn propose_close(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn propose_controller_change(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn cancel_spend() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn pay_scheduled_spends(s: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads(5 * s as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes(4 * s as Weight))
+    }
+    fn poll_proposals(p: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((40_000_000 as Weight).saturating_mul(p as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads(4 * p as Weight))
+            .saturating_add(DbWeight::get().writes(3 * p as Weight))
+    }
+}
+
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> crate::WeightInfo for SubstrateWeight<T> {
+    fn open() -> Weight {
+        (95_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
+    }
+    fn propose_spend() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn trigger_vote(m: u32) -> Weight {
+        (75_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn sudo_approve() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn close(m: u32) -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add((62_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 * m as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn org_spend() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn ragequit(b: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((45_000_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 * b as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 * b as Weight))
+    }
+    fn propose_scheduled_spend() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn cancel_scheduled_spend() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn set_spend_policy() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn set_spender_allowance() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn spend_allowance() -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn propose_close(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(8 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn propose_controller_change(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(8 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn cancel_spend() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn pay_scheduled_spends(s: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 * s as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 * s as Weight))
+    }
+    fn poll_proposals(p: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((40_000_000 as Weight).saturating_mul(p as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 * p as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 * p as Weight))
+    }
+}
@@ -4,6 +4,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Bank account for orgs w/ democratic escrow rules
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -19,11 +22,11 @@ use frame_support::{
         IterableStorageMap,
     },
     traits::{
-        Currency,
+        EnsureOrigin,
         ExistenceRequirement,
         Get,
-        ReservableCurrency,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -33,6 +36,7 @@ use sp_runtime::{
         AtLeast32Bit,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
@@ -46,29 +50,41 @@ use sp_std::{
 };
 use util::{
     bank::{
+        Allowance,
+        BankProposal,
         BankState,
+        SpendCap,
         SpendProposal,
+        SpendSchedule,
         SpendState,
     },
     organization::OrgRep,
     traits::{
         ConfigureThreshold,
+        EnsureOrgOrigin,
         GetVoteOutcome,
         GroupMembership,
+        MultiCurrency,
         OpenBankAccount,
+        ShareIssuance,
         SpendGovernance,
+        VoteVector,
     },
     vote::{
         ThresholdInput,
         VoteOutcome,
+        VoterView,
         XorThreshold,
     },
 };
 
 // type aliases
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+pub type BalanceOf<T> = <<T as Trait>::Currency as MultiCurrency<
     <T as frame_system::Trait>::AccountId,
 >>::Balance;
+pub type CurrencyIdOf<T> = <<T as Trait>::Currency as MultiCurrency<
+    <T as frame_system::Trait>::AccountId,
+>>::CurrencyId;
 type BankSt<T> = BankState<
     <T as Trait>::BankId,
     <T as frame_system::Trait>::AccountId,
@@ -86,6 +102,30 @@ type SpendProp<T> = SpendProposal<
     <T as frame_system::Trait>::AccountId,
     SpendState<<T as vote::Trait>::VoteId>,
 >;
+type Schedule<T> = SpendSchedule<<T as frame_system::Trait>::BlockNumber>;
+type Cap<T> = SpendCap<BalanceOf<T>, <T as frame_system::Trait>::BlockNumber>;
+type Allow<T> =
+    Allowance<BalanceOf<T>, <T as frame_system::Trait>::BlockNumber>;
+
+pub trait WeightInfo {
+    fn open() -> Weight;
+    fn propose_spend() -> Weight;
+    fn trigger_vote(m: u32) -> Weight;
+    fn sudo_approve() -> Weight;
+    fn close(m: u32) -> Weight;
+    fn org_spend() -> Weight;
+    fn ragequit(b: u32) -> Weight;
+    fn propose_scheduled_spend() -> Weight;
+    fn cancel_scheduled_spend() -> Weight;
+    fn set_spend_policy() -> Weight;
+    fn set_spender_allowance() -> Weight;
+    fn spend_allowance() -> Weight;
+    fn propose_close(m: u32) -> Weight;
+    fn propose_controller_change(m: u32) -> Weight;
+    fn cancel_spend() -> Weight;
+    fn pay_scheduled_spends(s: u32) -> Weight;
+    fn poll_proposals(p: u32) -> Weight;
+}
 
 pub trait Trait:
     frame_system::Trait + org::Trait + donate::Trait + vote::Trait
@@ -93,9 +133,11 @@ pub trait Trait:
     /// The overarching event types
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
-    /// The currency type for on-chain transactions
-    type Currency: Currency<Self::AccountId>
-        + ReservableCurrency<Self::AccountId>;
+    /// The multi-asset currency type, shares asset identifiers with `donate`
+    type Currency: MultiCurrency<
+        Self::AccountId,
+        CurrencyId = donate::CurrencyIdOf<Self>,
+    >;
 
     /// The base bank account for this module
     type BigBank: Get<ModuleId>;
@@ -130,6 +172,17 @@ pub trait Trait:
     type MaxTreasuryPerOrg: Get<u32>;
     /// Min to open bank account
     type MinDeposit: Get<BalanceOf<Self>>;
+    /// Blocks after which a spend proposal expires if no vote was triggered for it,
+    /// or after which an approved spend whose transfer keeps failing expires
+    type SpendExpiry: Get<Self::BlockNumber>;
+    /// Blocks a spend vote, or a vote to close the bank or change its controller, runs
+    /// before it closes, a vote still undecided then is rejected
+    type SpendVoteDuration: Get<Self::BlockNumber>;
+    /// Max closures and controller changes under vote at once for one bank
+    type MaxBankProposals: Get<u32>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -137,17 +190,51 @@ decl_event!(
     where
         <T as frame_system::Trait>::AccountId,
         <T as org::Trait>::OrgId,
+        <T as org::Trait>::Shares,
         <T as vote::Trait>::VoteId,
         <T as Trait>::BankId,
         <T as Trait>::SpendId,
+        CurrencyId = CurrencyIdOf<T>,
         Balance = BalanceOf<T>,
     {
-        AccountOpened(AccountId, BankId, Balance, OrgId, Option<AccountId>),
+        AccountOpened(AccountId, BankId, CurrencyId, Balance, OrgId, Option<AccountId>),
         SpendProposed(AccountId, BankId, SpendId, Balance, AccountId),
         VoteTriggered(AccountId, BankId, SpendId, VoteId),
         SudoApproved(AccountId, BankId, SpendId),
         ProposalPolled(BankId, SpendId, SpendState<VoteId>),
         AccountClosed(AccountId, BankId, OrgId),
+        /// Organization ID, Bank ID, Amount, Recipient
+        OrgSpent(OrgId, BankId, Balance, AccountId),
+        /// Bank ID, Amount Withdrawn, Amount Left
+        WithdrawnPortion(BankId, Balance, Balance),
+        /// Exiting Member, Organization ID, Shares Burned
+        MemberExited(AccountId, OrgId, Shares),
+        /// Bank ID, Spend ID, Amount, Recipient
+        ScheduledSpendPaid(BankId, SpendId, Balance, AccountId),
+        /// Bank ID, Spend ID
+        ScheduledSpendCancelled(BankId, SpendId),
+        /// Organization ID, Bank ID, Controller Cap Set, Amount Above Which Spends Require A Vote
+        SpendPolicySet(OrgId, BankId, bool, Option<Balance>),
+        /// Organization ID, Bank ID, Spender, Allowance Set
+        SpenderAllowanceSet(OrgId, BankId, AccountId, bool),
+        /// Spender, Bank ID, Amount, Recipient
+        AllowanceSpent(AccountId, BankId, Balance, AccountId),
+        /// Proposer, Bank ID, Vote ID
+        CloseProposed(AccountId, BankId, VoteId),
+        /// Proposer, Bank ID, Vote ID, New Controller
+        ControllerChangeProposed(AccountId, BankId, VoteId, Option<AccountId>),
+        /// Bank ID, New Controller
+        ControllerChanged(BankId, Option<AccountId>),
+        /// Bank ID, Vote ID, Result of executing the approved proposal
+        BankProposalExecuted(BankId, VoteId, DispatchResult),
+        /// Bank ID, Vote ID
+        BankProposalRejected(BankId, VoteId),
+        /// Proposer, Bank ID, Spend ID
+        SpendCancelled(AccountId, BankId, SpendId),
+        /// Bank ID, Spend ID
+        SpendExpired(BankId, SpendId),
+        /// Bank ID, Spend ID, Amount, Recipient, Final State
+        SpendArchived(BankId, SpendId, Balance, AccountId, SpendState<VoteId>),
     }
 );
 
@@ -164,6 +251,7 @@ decl_error! {
         CannotSpendIfBankDNE,
         OnlyControllerCanCloseBank,
         OnlyControllerCanSudoApproveSpendProposals,
+        OnlyBankOrgCanSpendWithoutProposal,
         // spend proposal stuff
         CannotProposeSpendIfBankDNE,
         BankMustExistToProposeSpendFrom,
@@ -178,6 +266,39 @@ decl_error! {
         // for getting banks for org
         NoBanksForOrg,
         ThresholdCannotBeSetForOrg,
+        // ragequit
+        CannotRagequitIfBankDNE,
+        CannotRagequitZeroShares,
+        MustBeMemberToRagequit,
+        CannotRagequitLockedShares,
+        NotEnoughSharesToRagequit,
+        CannotRagequitUnvestedShares,
+        CannotRagequitWhileSupportedSpendIsPending,
+        RagequitPayoutBelowExistentialDeposit,
+        // scheduled spends
+        CannotScheduleSpendForPastBlock,
+        SpendSchedulePeriodLengthMustBeGreaterThanZero,
+        SpendScheduleMustHaveAtLeastOnePayment,
+        CannotCancelScheduledSpendIfBankDNE,
+        NoScheduledSpendToCancel,
+        OnlyControllerOrOrgCanCancelScheduledSpend,
+        // spend limits
+        CannotSetSpendPolicyIfBankDNE,
+        OnlyBankOrgCanSetSpendPolicy,
+        SpendCapPeriodLengthMustBeGreaterThanZero,
+        SpendAboveLimitRequiresOrgVote,
+        ControllerSpendCapExceeded,
+        CannotSpendAllowanceIfBankDNE,
+        NoSpendAllowanceForBank,
+        SpendAllowanceExceeded,
+        // bank governance
+        CannotProposeBankChangeIfBankDNE,
+        NotPermittedToProposeBankChange,
+        TooManyPendingBankProposals,
+        // spend cancellation
+        CannotCancelSpendIfSpendProposalDNE,
+        OnlyProposerCanCancelSpend,
+        CannotCancelSpendFromCurrentSpendProposalState,
     }
 }
 
@@ -202,10 +323,57 @@ decl_storage! {
         pub Banks get(fn banks): map
             hasher(blake2_128_concat) T::BankId => Option<BankSt<T>>;
 
+        /// The asset held by each bank, spends and the close payout are made in it
+        pub BankCurrency get(fn bank_currency): map
+            hasher(blake2_128_concat) T::BankId => CurrencyIdOf<T>;
+
         /// Proposals to make spends from the bank account
         pub SpendProposals get(fn spend_proposals): double_map
             hasher(blake2_128_concat) T::BankId,
             hasher(blake2_128_concat) T::SpendId => Option<SpendProp<T>>;
+        /// Number of spend proposals kept for each bank
+        pub SpendProposalCount get(fn spend_proposal_count): map
+            hasher(blake2_128_concat) T::BankId => u32;
+        /// Number of spend proposals kept for all banks
+        pub TotalSpendProposals get(fn total_spend_proposals): u32;
+        /// Proposer of each spend proposal and the block it expires if no vote is triggered by then,
+        /// reset when an approved spend cannot be paid out
+        pub SpendProposers get(fn spend_proposers): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<(T::AccountId, T::BlockNumber)>;
+        /// Vote triggered for each spend proposal, kept after approval until the spend is removed
+        pub SpendVotes get(fn spend_votes): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<T::VoteId>;
+        /// Payout schedules of spend proposals which are not paid out immediately once approved
+        pub SpendSchedules get(fn spend_schedules): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<Schedule<T>>;
+        /// Approved scheduled spends indexed by the block number of their next payment
+        pub ScheduledSpendsDue get(fn scheduled_spends_due): double_map
+            hasher(twox_64_concat) T::BlockNumber,
+            hasher(blake2_128_concat) (T::BankId, T::SpendId) => Option<()>;
+
+        /// Caps the amount the controller may approve per period without a vote
+        pub ControllerAllowances get(fn controller_allowances): map
+            hasher(blake2_128_concat) T::BankId => Option<Allow<T>>;
+        /// Spends of more than this amount can only be approved by a vote with the bank threshold
+        pub VoteRequiredAbove get(fn vote_required_above): map
+            hasher(blake2_128_concat) T::BankId => Option<BalanceOf<T>>;
+        /// Accounts that may spend from the bank without a proposal up to their allowance per period
+        pub SpenderAllowances get(fn spender_allowances): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => Option<Allow<T>>;
+
+        /// Closures and controller changes waiting on the vote with the bank threshold
+        pub BankProposals get(fn bank_proposals): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::VoteId => Option<BankProposal<T::AccountId>>;
+        /// Number of bank proposals under vote for each bank
+        pub BankProposalCount get(fn bank_proposal_count): map
+            hasher(blake2_128_concat) T::BankId => u32;
+        /// Number of bank proposals under vote for all banks
+        pub TotalBankProposals get(fn total_bank_proposals): u32;
         /// Frequency for which all spend proposals are polled and pushed along
         SpendPollFrequency get(fn spend_poll_frequency) config(): T::BlockNumber;
     }
@@ -216,10 +384,11 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::open()]
         fn open(
             origin,
             org: T::OrgId,
+            currency_id: CurrencyIdOf<T>,
             deposit: BalanceOf<T>,
             controller: Option<T::AccountId>,
             threshold: Threshold<T>,
@@ -229,11 +398,11 @@ decl_module! {
                 <org::Module<T>>::is_member_of_group(org, &opener),
                 Error::<T>::NotPermittedToOpenBankAccountForOrg
             );
-            let bank_id = Self::open_bank_account(opener.clone(), org, deposit, controller.clone(), threshold)?;
-            Self::deposit_event(RawEvent::AccountOpened(opener, bank_id, deposit, org, controller));
+            let bank_id = Self::open_bank_account_in(opener.clone(), org, currency_id, deposit, controller.clone(), threshold)?;
+            Self::deposit_event(RawEvent::AccountOpened(opener, bank_id, currency_id, deposit, org, controller));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::propose_spend()]
         fn propose_spend(
             origin,
             bank_id: T::BankId,
@@ -245,7 +414,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SpendProposed(caller, bank_id, new_spend_id, amount, dest));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::trigger_vote(Self::bank_org_size(*bank_id))]
         fn trigger_vote(
             origin,
             bank_id: T::BankId,
@@ -256,53 +425,281 @@ decl_module! {
             Self::deposit_event(RawEvent::VoteTriggered(caller, bank_id, spend_id, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::sudo_approve()]
         fn sudo_approve(
             origin,
             bank_id: T::BankId,
             spend_id: T::SpendId,
         ) -> DispatchResult {
-            let caller = ensure_signed(origin)?;
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotSudoApproveSpendProposalIfBaseBankDNE)?;
+            // permissions for approving without a spend vote is the controller or a passed org vote
+            let caller = match org::EnsureOrgVotePassed::<T>::try_org_origin(origin, bank.org()) {
+                Ok(()) => Self::bank_account_id(bank_id),
+                Err(origin) => ensure_signed(origin)?,
+            };
             Self::_sudo_approve_spend_proposal(&caller, bank_id, spend_id)?;
             Self::deposit_event(RawEvent::SudoApproved(caller, bank_id, spend_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::close(Self::bank_org_size(*bank_id))]
         fn close(
             origin,
             bank_id: T::BankId,
         ) -> DispatchResult {
-            let closer = ensure_signed(origin)?;
             let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotCloseBankThatDNE)?;
-            // permissions for closing bank accounts is org supervisor status
+            // permissions for closing bank accounts is the controller or a passed org vote
+            let closer = match org::EnsureOrgVotePassed::<T>::try_org_origin(origin, bank.org()) {
+                Ok(()) => Self::bank_account_id(bank_id),
+                Err(origin) => {
+                    let closer = ensure_signed(origin)?;
+                    ensure!(
+                        bank.is_controller(&closer),
+                        Error::<T>::OnlyControllerCanCloseBank
+                    );
+                    closer
+                }
+            };
+            Self::close_bank(bank_id, bank.org(), &closer)?;
+            Self::deposit_event(RawEvent::AccountClosed(closer, bank_id, bank.org()));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::org_spend()]
+        fn org_spend(
+            origin,
+            bank_id: T::BankId,
+            amount: BalanceOf<T>,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            // the org itself spends without a proposal, e.g. from a passed governance proposal
+            let org = <T as org::Trait>::OrgOrigin::ensure_origin(origin)?;
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotSpendIfBankDNE)?;
+            ensure!(bank.org() == org, Error::<T>::OnlyBankOrgCanSpendWithoutProposal);
+            <T as Trait>::Currency::transfer(
+                <BankCurrency<T>>::get(bank_id),
+                &Self::bank_account_id(bank_id),
+                &dest,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            Self::deposit_event(RawEvent::OrgSpent(org, bank_id, amount, dest));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::ragequit(Self::bank_org_treasury_count(*bank_id))]
+        fn ragequit(
+            origin,
+            bank_id: T::BankId,
+            shares: T::Shares,
+        ) -> DispatchResult {
+            let member = ensure_signed(origin)?;
+            let org = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotRagequitIfBankDNE)?.org();
+            Self::_ragequit(&member, org, shares)?;
+            Self::deposit_event(RawEvent::MemberExited(member, org, shares));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::propose_scheduled_spend()]
+        fn propose_scheduled_spend(
+            origin,
+            bank_id: T::BankId,
+            amount: BalanceOf<T>,
+            dest: T::AccountId,
+            schedule: Schedule<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let now = <frame_system::Module<T>>::block_number();
+            match schedule {
+                SpendSchedule::At(block) => ensure!(block > now, Error::<T>::CannotScheduleSpendForPastBlock),
+                SpendSchedule::Every(period_length, payments) => {
+                    ensure!(!period_length.is_zero(), Error::<T>::SpendSchedulePeriodLengthMustBeGreaterThanZero);
+                    ensure!(payments > 0, Error::<T>::SpendScheduleMustHaveAtLeastOnePayment);
+                }
+            }
+            let new_spend_id = Self::_propose_spend(&caller, bank_id, amount, dest.clone())?;
+            <SpendSchedules<T>>::insert(bank_id, new_spend_id, schedule);
+            Self::deposit_event(RawEvent::SpendProposed(caller, bank_id, new_spend_id, amount, dest));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::cancel_scheduled_spend()]
+        fn cancel_scheduled_spend(
+            origin,
+            bank_id: T::BankId,
+            spend_id: T::SpendId,
+        ) -> DispatchResult {
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotCancelScheduledSpendIfBankDNE)?;
+            // same permissions as closing, a follow-up org vote or the controller
+            if let Err(origin) = org::EnsureOrgVotePassed::<T>::try_org_origin(origin, bank.org()) {
+                let caller = ensure_signed(origin)?;
+                ensure!(
+                    bank.is_controller(&caller),
+                    Error::<T>::OnlyControllerOrOrgCanCancelScheduledSpend
+                );
+            }
             ensure!(
-                bank.is_controller(&closer),
-                Error::<T>::OnlyControllerCanCloseBank
+                <SpendSchedules<T>>::contains_key(bank_id, spend_id),
+                Error::<T>::NoScheduledSpendToCancel
             );
-            let bank_account_id = Self::bank_account_id(bank_id);
-            let remaining_funds = <T as donate::Trait>::Currency::total_balance(&bank_account_id);
-            // distributes remaining funds equally among members in proportion to ownership (PropDonation)
-            let _ = <donate::Module<T>>::donate(
-                &bank_account_id,
-                OrgRep::Weighted(bank.org()),
-                &closer,
-                remaining_funds,
+            Self::remove_spend(bank_id, spend_id);
+            Self::deposit_event(RawEvent::ScheduledSpendCancelled(bank_id, spend_id));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::set_spend_policy()]
+        fn set_spend_policy(
+            origin,
+            bank_id: T::BankId,
+            controller_cap: Option<Cap<T>>,
+            vote_required_above: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            // only the org may change limits so a compromised controller cannot lift them
+            let org = <T as org::Trait>::OrgOrigin::ensure_origin(origin)?;
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotSetSpendPolicyIfBankDNE)?;
+            ensure!(bank.org() == org, Error::<T>::OnlyBankOrgCanSetSpendPolicy);
+            let allowance = Self::allowance_for(controller_cap)?;
+            if let Some(allowance) = allowance {
+                <ControllerAllowances<T>>::insert(bank_id, allowance);
+            } else {
+                <ControllerAllowances<T>>::remove(bank_id);
+            }
+            if let Some(limit) = vote_required_above {
+                <VoteRequiredAbove<T>>::insert(bank_id, limit);
+            } else {
+                <VoteRequiredAbove<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::SpendPolicySet(org, bank_id, allowance.is_some(), vote_required_above));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::set_spender_allowance()]
+        fn set_spender_allowance(
+            origin,
+            bank_id: T::BankId,
+            spender: T::AccountId,
+            cap: Option<Cap<T>>,
+        ) -> DispatchResult {
+            let org = <T as org::Trait>::OrgOrigin::ensure_origin(origin)?;
+            let bank = <Banks<T>>::get(bank_id).ok_or(Error::<T>::CannotSetSpendPolicyIfBankDNE)?;
+            ensure!(bank.org() == org, Error::<T>::OnlyBankOrgCanSetSpendPolicy);
+            let allowance = Self::allowance_for(cap)?;
+            if let Some(allowance) = allowance {
+                <SpenderAllowances<T>>::insert(bank_id, &spender, allowance);
+            } else {
+                <SpenderAllowances<T>>::remove(bank_id, &spender);
+            }
+            Self::deposit_event(RawEvent::SpenderAllowanceSet(org, bank_id, spender, allowance.is_some()));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::spend_allowance()]
+        fn spend_allowance(
+            origin,
+            bank_id: T::BankId,
+            amount: BalanceOf<T>,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let spender = ensure_signed(origin)?;
+            ensure!(Self::is_bank(bank_id), Error::<T>::CannotSpendAllowanceIfBankDNE);
+            let allowance = <SpenderAllowances<T>>::get(bank_id, &spender)
+                .ok_or(Error::<T>::NoSpendAllowanceForBank)?;
+            Self::ensure_below_vote_limit(bank_id, amount)?;
+            let now = <frame_system::Module<T>>::block_number();
+            let new_allowance = allowance
+                .spend(amount, now)
+                .ok_or(Error::<T>::SpendAllowanceExceeded)?;
+            <T as Trait>::Currency::transfer(
+                <BankCurrency<T>>::get(bank_id),
+                &Self::bank_account_id(bank_id),
+                &dest,
+                amount,
+                ExistenceRequirement::KeepAlive,
             )?;
-            <Banks<T>>::remove(bank_id);
-            <OrgTreasuryCount<T>>::mutate(bank.org(), |count| *count -= 1);
-            <TotalBankCount>::mutate(|count| *count -= 1);
-            Self::deposit_event(RawEvent::AccountClosed(closer, bank_id, bank.org()));
+            <SpenderAllowances<T>>::insert(bank_id, &spender, new_allowance);
+            Self::deposit_event(RawEvent::AllowanceSpent(spender, bank_id, amount, dest));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::propose_close(Self::bank_org_size(*bank_id))]
+        fn propose_close(
+            origin,
+            bank_id: T::BankId,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            let vote_id = Self::propose_bank_change(&proposer, bank_id, BankProposal::Close)?;
+            Self::deposit_event(RawEvent::CloseProposed(proposer, bank_id, vote_id));
             Ok(())
         }
+        #[weight = <T as Trait>::WeightInfo::propose_controller_change(Self::bank_org_size(*bank_id))]
+        fn propose_controller_change(
+            origin,
+            bank_id: T::BankId,
+            new_controller: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            let vote_id = Self::propose_bank_change(
+                &proposer,
+                bank_id,
+                BankProposal::ChangeController(new_controller.clone()),
+            )?;
+            Self::deposit_event(RawEvent::ControllerChangeProposed(proposer, bank_id, vote_id, new_controller));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::cancel_spend()]
+        fn cancel_spend(
+            origin,
+            bank_id: T::BankId,
+            spend_id: T::SpendId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let prop = <SpendProposals<T>>::get(bank_id, spend_id)
+                .ok_or(Error::<T>::CannotCancelSpendIfSpendProposalDNE)?;
+            ensure!(
+                <SpendProposers<T>>::get(bank_id, spend_id).map(|(proposer, _)| proposer == caller).unwrap_or(false),
+                Error::<T>::OnlyProposerCanCancelSpend
+            );
+            // approved spends are cancelled by org vote with `cancel_scheduled_spend`
+            match prop.state() {
+                SpendState::WaitingForApproval | SpendState::Voting(_) => (),
+                _ => return Err(Error::<T>::CannotCancelSpendFromCurrentSpendProposalState.into()),
+            }
+            Self::remove_spend(bank_id, spend_id);
+            Self::deposit_event(RawEvent::SpendCancelled(caller, bank_id, spend_id));
+            Ok(())
+        }
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            // charges the work `on_finalize` does in this block, counting it reads the same keys
+            let due = <ScheduledSpendsDue<T>>::iter_prefix(n).count() as u32;
+            let mut weight = <T as Trait>::WeightInfo::pay_scheduled_spends(due)
+                .saturating_add(T::DbWeight::get().reads(due as Weight));
+            if n % Self::spend_poll_frequency() == Zero::zero() {
+                let polled = <TotalSpendProposals>::get()
+                    .saturating_add(<TotalBankProposals>::get());
+                weight = weight
+                    .saturating_add(<T as Trait>::WeightInfo::poll_proposals(polled))
+                    .saturating_add(T::DbWeight::get().reads(2));
+            }
+            weight
+        }
         fn on_finalize(_n: T::BlockNumber) {
-            if <frame_system::Module<T>>::block_number() % Self::spend_poll_frequency() == Zero::zero() {
-                <SpendProposals<T>>::iter().for_each(|(_, _, prop)| {
+            let now = <frame_system::Module<T>>::block_number();
+            if now % Self::spend_poll_frequency() == Zero::zero() {
+                let props = <SpendProposals<T>>::iter()
+                    .map(|(_, _, prop)| prop)
+                    .collect::<Vec<_>>();
+                props.into_iter().for_each(|prop| {
                     let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
                     if let Ok(state) = Self::poll_spend_proposal(prop) {
                         Self::deposit_event(RawEvent::ProposalPolled(bank_id, spend_id, state));
                     }
                 });
+                let pending = <BankProposals<T>>::iter()
+                    .map(|(bank_id, vote_id, _)| (bank_id, vote_id))
+                    .collect::<Vec<_>>();
+                pending.into_iter().for_each(|(bank_id, vote_id)| {
+                    Self::poll_bank_proposal(bank_id, vote_id)
+                });
             }
+            // only the scheduled spends due in this block are read
+            let due = <ScheduledSpendsDue<T>>::iter_prefix(now)
+                .map(|(ids, _)| ids)
+                .collect::<Vec<_>>();
+            <ScheduledSpendsDue<T>>::remove_prefix(now);
+            due.into_iter().for_each(|(bank_id, spend_id)| {
+                Self::pay_scheduled_spend(bank_id, spend_id, now)
+            });
         }
     }
 }
@@ -313,7 +710,10 @@ impl<T: Trait> Module<T> {
         T::BigBank::get().into_sub_account(id)
     }
     pub fn bank_balance(bank: T::BankId) -> BalanceOf<T> {
-        <T as Trait>::Currency::total_balance(&Self::bank_account_id(bank))
+        <T as Trait>::Currency::total_balance(
+            <BankCurrency<T>>::get(bank),
+            &Self::bank_account_id(bank),
+        )
     }
     pub fn is_bank(id: T::BankId) -> bool {
         <Banks<T>>::get(id).is_some()
@@ -321,6 +721,426 @@ impl<T: Trait> Module<T> {
     pub fn is_spend(bank: T::BankId, spend: T::SpendId) -> bool {
         <SpendProposals<T>>::get(bank, spend).is_some()
     }
+    /// The member count of the org that owns the bank, used for weighing calls
+    fn bank_org_size(id: T::BankId) -> u32 {
+        <Banks<T>>::get(id)
+            .map(|bank| <org::Module<T>>::member_count(bank.org()))
+            .unwrap_or_default()
+    }
+    /// The number of banks owned by the org that owns the bank, used for weighing calls
+    fn bank_org_treasury_count(id: T::BankId) -> u32 {
+        <Banks<T>>::get(id)
+            .map(|bank| <OrgTreasuryCount<T>>::get(bank.org()))
+            .unwrap_or_default()
+    }
+    /// True if `who` voted in favor of a spend from the bank which is still being voted on
+    /// or was approved but has not been paid out in full, failed transfers are retried until
+    /// the spend expires
+    fn supports_pending_spend(bank_id: T::BankId, who: &T::AccountId) -> bool {
+        <SpendProposals<T>>::iter_prefix(bank_id).any(|(spend_id, prop)| {
+            match prop.state() {
+                SpendState::Voting(_)
+                | SpendState::ApprovedAndScheduled
+                | SpendState::ApprovedButNotExecuted => {
+                    <SpendVotes<T>>::get(bank_id, spend_id)
+                        .and_then(|vote_id| {
+                            <vote::Module<T>>::vote_logger(vote_id, who)
+                        })
+                        .map(|v| v.direction() == VoterView::InFavor)
+                        .unwrap_or(false)
+                }
+                _ => false,
+            }
+        })
+    }
+    /// Burns `shares` of the member and pays out their portion of every bank owned by the org
+    fn _ragequit(
+        member: &T::AccountId,
+        org: T::OrgId,
+        shares: T::Shares,
+    ) -> DispatchResult {
+        ensure!(!shares.is_zero(), Error::<T>::CannotRagequitZeroShares);
+        let profile = <org::Module<T>>::members(org, member)
+            .ok_or(Error::<T>::MustBeMemberToRagequit)?;
+        ensure!(
+            !<org::Module<T>>::shares_locked(org, member),
+            Error::<T>::CannotRagequitLockedShares
+        );
+        ensure!(
+            profile.total() >= shares,
+            Error::<T>::NotEnoughSharesToRagequit
+        );
+        ensure!(
+            <org::Module<T>>::vested_shares(org, member, profile.total())
+                >= shares,
+            Error::<T>::CannotRagequitUnvestedShares
+        );
+        let banks = Self::get_banks_for_org(org)?;
+        ensure!(
+            !banks
+                .iter()
+                .any(|bank_id| Self::supports_pending_spend(*bank_id, member)),
+            Error::<T>::CannotRagequitWhileSupportedSpendIsPending
+        );
+        // compute and check every payout before moving funds or burning shares
+        let portion = Permill::from_rational_approximation(
+            shares,
+            <org::Module<T>>::total_issuance(org),
+        );
+        let mut payouts = Vec::new();
+        for bank_id in banks {
+            let bank_account_id = Self::bank_account_id(bank_id);
+            let currency_id = <BankCurrency<T>>::get(bank_id);
+            let balance_in_bank = <T as Trait>::Currency::free_balance(
+                currency_id,
+                &bank_account_id,
+            );
+            let amt_due = portion.mul_floor(balance_in_bank);
+            // the portion never exceeds the free balance, only the member's account may be too small
+            if !amt_due.is_zero() {
+                ensure!(
+                    <T as Trait>::Currency::total_balance(currency_id, member)
+                        .saturating_add(amt_due)
+                        >= <T as Trait>::Currency::minimum_balance(currency_id),
+                    Error::<T>::RagequitPayoutBelowExistentialDeposit
+                );
+            }
+            payouts.push((
+                bank_id,
+                bank_account_id,
+                currency_id,
+                amt_due,
+                balance_in_bank - amt_due,
+            ));
+        }
+        for (bank_id, bank_account_id, currency_id, amt_due, amt_left) in
+            payouts
+        {
+            if !amt_due.is_zero() {
+                <T as Trait>::Currency::transfer(
+                    currency_id,
+                    &bank_account_id,
+                    member,
+                    amt_due,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+            Self::deposit_event(RawEvent::WithdrawnPortion(
+                bank_id, amt_due, amt_left,
+            ));
+        }
+        <org::Module<T>>::burn(org, member.clone(), Some(shares), false)?;
+        Ok(())
+    }
+    fn remove_spend(bank_id: T::BankId, spend_id: T::SpendId) {
+        if <SpendProposals<T>>::take(bank_id, spend_id).is_some() {
+            <SpendProposalCount<T>>::mutate(bank_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+            <TotalSpendProposals>::mutate(|count| {
+                *count = count.saturating_sub(1)
+            });
+        }
+        <SpendProposers<T>>::remove(bank_id, spend_id);
+        <SpendVotes<T>>::remove(bank_id, spend_id);
+        <SpendSchedules<T>>::remove(bank_id, spend_id);
+    }
+    /// Distributes the remaining funds among the org members and removes the bank
+    fn close_bank(
+        bank_id: T::BankId,
+        org: T::OrgId,
+        closer: &T::AccountId,
+    ) -> DispatchResult {
+        let bank_account_id = Self::bank_account_id(bank_id);
+        let currency_id = <BankCurrency<T>>::get(bank_id);
+        let remaining_funds = <T as donate::Trait>::Currency::total_balance(
+            currency_id,
+            &bank_account_id,
+        );
+        // distributes remaining funds equally among members in proportion to ownership (PropDonation)
+        let _ = <donate::Module<T>>::donate(
+            &bank_account_id,
+            OrgRep::Weighted(org),
+            closer,
+            currency_id,
+            remaining_funds,
+        )?;
+        <Banks<T>>::remove(bank_id);
+        <BankCurrency<T>>::remove(bank_id);
+        <SpendProposals<T>>::remove_prefix(bank_id);
+        let spends = <SpendProposalCount<T>>::take(bank_id);
+        <TotalSpendProposals>::mutate(|count| {
+            *count = count.saturating_sub(spends)
+        });
+        <SpendProposers<T>>::remove_prefix(bank_id);
+        <SpendVotes<T>>::remove_prefix(bank_id);
+        <SpendSchedules<T>>::remove_prefix(bank_id);
+        <ControllerAllowances<T>>::remove(bank_id);
+        <VoteRequiredAbove<T>>::remove(bank_id);
+        <SpenderAllowances<T>>::remove_prefix(bank_id);
+        <BankProposals<T>>::remove_prefix(bank_id);
+        let pending = <BankProposalCount<T>>::take(bank_id);
+        <TotalBankProposals>::mutate(|count| {
+            *count = count.saturating_sub(pending)
+        });
+        <OrgTreasuryCount<T>>::mutate(org, |count| *count -= 1);
+        <TotalBankCount>::mutate(|count| *count -= 1);
+        Ok(())
+    }
+    /// Opens a vote with the bank threshold on the proposal, only members of the bank org may propose
+    fn propose_bank_change(
+        proposer: &T::AccountId,
+        bank_id: T::BankId,
+        proposal: BankProposal<T::AccountId>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let bank = <Banks<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotProposeBankChangeIfBankDNE)?;
+        ensure!(
+            <org::Module<T>>::is_member_of_group(bank.org(), proposer),
+            Error::<T>::NotPermittedToProposeBankChange
+        );
+        let pending = <BankProposalCount<T>>::get(bank_id);
+        ensure!(
+            pending < T::MaxBankProposals::get(),
+            Error::<T>::TooManyPendingBankProposals
+        );
+        let vote_id = <vote::Module<T>>::invoke_threshold(
+            bank.threshold_id(),
+            None,
+            Some(T::SpendVoteDuration::get()),
+        )?;
+        <BankProposals<T>>::insert(bank_id, vote_id, proposal);
+        <BankProposalCount<T>>::insert(bank_id, pending + 1);
+        <TotalBankProposals>::mutate(|count| *count += 1);
+        Ok(vote_id)
+    }
+    fn take_bank_proposal(
+        bank_id: T::BankId,
+        vote_id: T::VoteId,
+    ) -> Option<BankProposal<T::AccountId>> {
+        let proposal = <BankProposals<T>>::take(bank_id, vote_id)?;
+        <BankProposalCount<T>>::mutate(bank_id, |count| {
+            *count = count.saturating_sub(1)
+        });
+        <TotalBankProposals>::mutate(|count| *count = count.saturating_sub(1));
+        Some(proposal)
+    }
+    /// Executes the proposal if its vote passed and removes it once the vote is decided
+    fn poll_bank_proposal(bank_id: T::BankId, vote_id: T::VoteId) {
+        match <vote::Module<T>>::get_vote_outcome(vote_id) {
+            Ok(VoteOutcome::Approved) => {
+                if let Some(proposal) =
+                    Self::take_bank_proposal(bank_id, vote_id)
+                {
+                    let result = Self::execute_bank_proposal(bank_id, proposal);
+                    Self::deposit_event(RawEvent::BankProposalExecuted(
+                        bank_id, vote_id, result,
+                    ));
+                }
+            }
+            Ok(VoteOutcome::NotStarted) | Ok(VoteOutcome::Voting) => (),
+            // rejected or the vote was pruned
+            _ => {
+                Self::take_bank_proposal(bank_id, vote_id);
+                Self::deposit_event(RawEvent::BankProposalRejected(
+                    bank_id, vote_id,
+                ));
+            }
+        }
+    }
+    fn execute_bank_proposal(
+        bank_id: T::BankId,
+        proposal: BankProposal<T::AccountId>,
+    ) -> DispatchResult {
+        let bank = <Banks<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotCloseBankThatDNE)?;
+        match proposal {
+            BankProposal::Close => {
+                let closer = Self::bank_account_id(bank_id);
+                Self::close_bank(bank_id, bank.org(), &closer)?;
+                Self::deposit_event(RawEvent::AccountClosed(
+                    closer,
+                    bank_id,
+                    bank.org(),
+                ));
+            }
+            BankProposal::ChangeController(controller) => {
+                <Banks<T>>::insert(
+                    bank_id,
+                    bank.set_controller(controller.clone()),
+                );
+                Self::deposit_event(RawEvent::ControllerChanged(
+                    bank_id, controller,
+                ));
+            }
+        }
+        Ok(())
+    }
+    /// Validates the cap and starts tracking spends against it
+    fn allowance_for(
+        cap: Option<Cap<T>>,
+    ) -> Result<Option<Allow<T>>, DispatchError> {
+        if let Some(cap) = cap {
+            ensure!(
+                !cap.period_length().is_zero(),
+                Error::<T>::SpendCapPeriodLengthMustBeGreaterThanZero
+            );
+            Ok(Some(Allowance::new(cap)))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Spends above the bank's vote limit must pass a vote with the bank's threshold
+    pub fn ensure_below_vote_limit(
+        bank_id: T::BankId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        if let Some(limit) = <VoteRequiredAbove<T>>::get(bank_id) {
+            ensure!(
+                amount <= limit,
+                Error::<T>::SpendAboveLimitRequiresOrgVote
+            );
+        }
+        Ok(())
+    }
+    /// Checks a spend the controller approves without a vote against the bank's limits
+    /// -> returns the controller allowance to record once the funds have moved
+    pub fn check_controller_spend(
+        bank_id: T::BankId,
+        amount: BalanceOf<T>,
+    ) -> Result<Option<Allow<T>>, DispatchError> {
+        Self::ensure_below_vote_limit(bank_id, amount)?;
+        if let Some(allowance) = <ControllerAllowances<T>>::get(bank_id) {
+            let now = <frame_system::Module<T>>::block_number();
+            Ok(Some(
+                allowance
+                    .spend(amount, now)
+                    .ok_or(Error::<T>::ControllerSpendCapExceeded)?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+    /// Stores the allowance returned by `check_controller_spend`
+    pub fn record_controller_spend(
+        bank_id: T::BankId,
+        allowance: Option<Allow<T>>,
+    ) {
+        if let Some(allowance) = allowance {
+            <ControllerAllowances<T>>::insert(bank_id, allowance);
+        }
+    }
+    /// The total paid out by the spend, recurring spends count every payment
+    fn committed_amount(
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+        amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        match <SpendSchedules<T>>::get(bank_id, spend_id) {
+            Some(SpendSchedule::Every(_, payments)) => {
+                amount.saturating_mul(payments.into())
+            }
+            _ => amount,
+        }
+    }
+    /// Approved spends with a schedule are paid by `pay_scheduled_spend` instead of immediately
+    fn execute_spend(prop: &SpendProp<T>) -> SpendProp<T> {
+        let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
+        if let Some(schedule) = <SpendSchedules<T>>::get(bank_id, spend_id) {
+            let now = <frame_system::Module<T>>::block_number();
+            <ScheduledSpendsDue<T>>::insert(
+                schedule.next_due(now),
+                (bank_id, spend_id),
+                (),
+            );
+            prop.set_state(SpendState::ApprovedAndScheduled)
+        } else if Self::transfer_spend(prop).is_ok() {
+            prop.set_state(SpendState::ApprovedAndExecuted)
+        } else {
+            // the transfer is retried by `poll_spend_proposal` until the spend expires
+            let expires = <frame_system::Module<T>>::block_number()
+                + T::SpendExpiry::get();
+            <SpendProposers<T>>::mutate(bank_id, spend_id, |proposer| {
+                if let Some((_, expiry)) = proposer {
+                    *expiry = expires;
+                }
+            });
+            prop.set_state(SpendState::ApprovedButNotExecuted)
+        }
+    }
+    fn transfer_spend(prop: &SpendProp<T>) -> DispatchResult {
+        <T as Trait>::Currency::transfer(
+            <BankCurrency<T>>::get(prop.bank_id()),
+            &Self::bank_account_id(prop.bank_id()),
+            &prop.dest(),
+            prop.amount(),
+            ExistenceRequirement::KeepAlive,
+        )
+    }
+    /// True if the spend is past the expiry recorded with its proposer
+    fn spend_expired(bank_id: T::BankId, spend_id: T::SpendId) -> bool {
+        <SpendProposers<T>>::get(bank_id, spend_id)
+            .map(|(_, expiry)| {
+                <frame_system::Module<T>>::block_number() >= expiry
+            })
+            .unwrap_or(true)
+    }
+    /// Makes one payment of an approved scheduled spend, failed payments are retried when next due
+    /// -> cancelled spends and closed banks have no schedule so their index entries are dropped
+    fn pay_scheduled_spend(
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+        now: T::BlockNumber,
+    ) {
+        let schedule = match <SpendSchedules<T>>::get(bank_id, spend_id) {
+            Some(schedule) => schedule,
+            None => return,
+        };
+        let prop = match <SpendProposals<T>>::get(bank_id, spend_id) {
+            Some(prop) if prop.state() == SpendState::ApprovedAndScheduled => {
+                prop
+            }
+            _ => return,
+        };
+        let after = now + 1u32.into();
+        if <T as Trait>::Currency::transfer(
+            <BankCurrency<T>>::get(bank_id),
+            &Self::bank_account_id(bank_id),
+            &prop.dest(),
+            prop.amount(),
+            ExistenceRequirement::KeepAlive,
+        )
+        .is_ok()
+        {
+            if let Some(next) = schedule.paid() {
+                <SpendSchedules<T>>::insert(bank_id, spend_id, next);
+                <ScheduledSpendsDue<T>>::insert(
+                    next.next_due(after),
+                    (bank_id, spend_id),
+                    (),
+                );
+            } else {
+                <SpendSchedules<T>>::remove(bank_id, spend_id);
+                <SpendProposals<T>>::insert(
+                    bank_id,
+                    spend_id,
+                    prop.set_state(SpendState::ApprovedAndExecuted),
+                );
+            }
+            Self::deposit_event(RawEvent::ScheduledSpendPaid(
+                bank_id,
+                spend_id,
+                prop.amount(),
+                prop.dest(),
+            ));
+        } else {
+            <ScheduledSpendsDue<T>>::insert(
+                schedule.next_due(after),
+                (bank_id, spend_id),
+                (),
+            );
+        }
+    }
     fn generate_bank_uid() -> T::BankId {
         let mut bank_nonce_id = <BankIdNonce<T>>::get() + 1u32.into();
         while Self::is_bank(bank_nonce_id) {
@@ -352,24 +1172,22 @@ impl<T: Trait> Module<T> {
     }
 }
 
-impl<T: Trait>
-    OpenBankAccount<T::OrgId, BalanceOf<T>, T::AccountId, Threshold<T>>
-    for Module<T>
-{
-    type BankId = T::BankId;
-    fn open_bank_account(
+impl<T: Trait> Module<T> {
+    /// Opens a bank account that holds the asset `currency_id`
+    pub fn open_bank_account_in(
         opener: T::AccountId,
         org: T::OrgId,
+        currency_id: CurrencyIdOf<T>,
         deposit: BalanceOf<T>,
         controller: Option<T::AccountId>,
         threshold: Threshold<T>,
-    ) -> Result<Self::BankId, DispatchError> {
+    ) -> Result<T::BankId, DispatchError> {
         ensure!(
             deposit >= T::MinDeposit::get(),
             Error::<T>::CannotOpenBankAccountIfDepositIsBelowModuleMinimum
         );
         ensure!(
-            <T as Trait>::Currency::free_balance(&opener) > deposit,
+            <T as Trait>::Currency::free_balance(currency_id, &opener) > deposit,
             Error::<T>::InsufficientBalanceToFundBankOpen
         );
         let new_count = <OrgTreasuryCount<T>>::get(org) + 1;
@@ -390,6 +1208,7 @@ impl<T: Trait>
         let bank = BankState::new(id, org, controller, threshold_id);
         // perform fallible transfer
         <T as Trait>::Currency::transfer(
+            currency_id,
             &opener,
             &Self::bank_account_id(id),
             deposit,
@@ -397,6 +1216,7 @@ impl<T: Trait>
         )?;
         // insert new bank object
         <Banks<T>>::insert(id, bank);
+        <BankCurrency<T>>::insert(id, currency_id);
         // put new org treasury count
         <OrgTreasuryCount<T>>::insert(org, new_count);
         // iterate total bank count
@@ -406,6 +1226,30 @@ impl<T: Trait>
     }
 }
 
+impl<T: Trait>
+    OpenBankAccount<T::OrgId, BalanceOf<T>, T::AccountId, Threshold<T>>
+    for Module<T>
+{
+    type BankId = T::BankId;
+    /// Opens a bank account that holds the native token
+    fn open_bank_account(
+        opener: T::AccountId,
+        org: T::OrgId,
+        deposit: BalanceOf<T>,
+        controller: Option<T::AccountId>,
+        threshold: Threshold<T>,
+    ) -> Result<Self::BankId, DispatchError> {
+        Self::open_bank_account_in(
+            opener,
+            org,
+            <T as Trait>::Currency::native_currency_id(),
+            deposit,
+            controller,
+            threshold,
+        )
+    }
+}
+
 impl<T: Trait>
     SpendGovernance<T::BankId, BalanceOf<T>, T::AccountId, SpendProp<T>>
     for Module<T>
@@ -427,7 +1271,12 @@ impl<T: Trait>
         );
         let id = Self::generate_spend_uid(bank_id);
         let proposal = SpendProposal::new(bank_id, id, amount, dest);
+        let expiry =
+            <frame_system::Module<T>>::block_number() + T::SpendExpiry::get();
         <SpendProposals<T>>::insert(bank_id, id, proposal);
+        <SpendProposalCount<T>>::mutate(bank_id, |count| *count += 1);
+        <TotalSpendProposals>::mutate(|count| *count += 1);
+        <SpendProposers<T>>::insert(bank_id, id, (caller.clone(), expiry));
         Ok(id)
     }
     fn _trigger_vote_on_spend_proposal(
@@ -449,7 +1298,7 @@ impl<T: Trait>
                 let new_vote_id = <vote::Module<T>>::invoke_threshold(
                     bank.threshold_id(),
                     None, // TODO: use vote info ref here instead of None
-                    None,
+                    Some(T::SpendVoteDuration::get()),
                 )?;
                 let new_spend_proposal =
                     spend_proposal.set_state(SpendState::Voting(new_vote_id));
@@ -458,6 +1307,7 @@ impl<T: Trait>
                     spend_id,
                     new_spend_proposal,
                 );
+                <SpendVotes<T>>::insert(bank_id, spend_id, new_vote_id);
                 Ok(new_vote_id)
             }
             _ => {
@@ -473,8 +1323,10 @@ impl<T: Trait>
     ) -> DispatchResult {
         let bank = <Banks<T>>::get(bank_id)
             .ok_or(Error::<T>::CannotSudoApproveSpendProposalIfBaseBankDNE)?;
+        // the bank account approves for the org, see `sudo_approve`
+        let by_org = caller == &Self::bank_account_id(bank_id);
         ensure!(
-            bank.is_controller(caller),
+            by_org || bank.is_controller(caller),
             Error::<T>::OnlyControllerCanSudoApproveSpendProposals
         );
         let spend_proposal = <SpendProposals<T>>::get(bank_id, spend_id)
@@ -483,18 +1335,28 @@ impl<T: Trait>
             )?;
         match spend_proposal.state() {
             SpendState::WaitingForApproval | SpendState::Voting(_) => {
-                // TODO: if Voting, remove the current live vote
-                let new_spend_proposal = if let Ok(()) =
-                    <T as Trait>::Currency::transfer(
-                        &Self::bank_account_id(bank_id),
-                        &spend_proposal.dest(),
-                        spend_proposal.amount(),
-                        ExistenceRequirement::KeepAlive,
-                    ) {
-                    spend_proposal.set_state(SpendState::ApprovedAndExecuted)
+                let committed = Self::committed_amount(
+                    bank_id,
+                    spend_id,
+                    spend_proposal.amount(),
+                );
+                // the controller limits do not apply to the org itself
+                let controller_allowance = if by_org {
+                    None
                 } else {
-                    spend_proposal.set_state(SpendState::ApprovedButNotExecuted)
+                    Self::check_controller_spend(bank_id, committed)?
                 };
+                // TODO: if Voting, remove the current live vote
+                let new_spend_proposal = Self::execute_spend(&spend_proposal);
+                // failed transfers do not count against the controller cap
+                if new_spend_proposal.state()
+                    != SpendState::ApprovedButNotExecuted
+                {
+                    Self::record_controller_spend(
+                        bank_id,
+                        controller_allowance,
+                    );
+                }
                 <SpendProposals<T>>::insert(
                     bank_id,
                     spend_id,
@@ -517,35 +1379,65 @@ impl<T: Trait>
         );
         let _ = <SpendProposals<T>>::get(prop.bank_id(), prop.spend_id())
             .ok_or(Error::<T>::CannotPollSpendProposalIfSpendProposalDNE)?;
+        let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
         match prop.state() {
+            SpendState::WaitingForApproval => {
+                if Self::spend_expired(bank_id, spend_id) {
+                    Self::remove_spend(bank_id, spend_id);
+                    Self::deposit_event(RawEvent::SpendExpired(
+                        bank_id, spend_id,
+                    ));
+                }
+                Ok(prop.state())
+            }
             SpendState::Voting(vote_id) => {
-                let vote_outcome =
-                    <vote::Module<T>>::get_vote_outcome(vote_id)?;
-                if vote_outcome == VoteOutcome::Approved {
-                    // approved so try to execute and if not, still approve
-                    let new_spend_proposal = if let Ok(()) =
-                        <T as Trait>::Currency::transfer(
-                            &Self::bank_account_id(prop.bank_id()),
-                            &prop.dest(),
-                            prop.amount(),
-                            ExistenceRequirement::KeepAlive,
-                        ) {
-                        prop.set_state(SpendState::ApprovedAndExecuted)
-                    } else {
-                        prop.set_state(SpendState::ApprovedButNotExecuted)
+                let new_spend_proposal =
+                    match <vote::Module<T>>::get_vote_outcome(vote_id) {
+                        // approved so try to execute and if not, still approve
+                        Ok(VoteOutcome::Approved) => Self::execute_spend(&prop),
+                        Ok(VoteOutcome::NotStarted)
+                        | Ok(VoteOutcome::Voting) => return Ok(prop.state()),
+                        // rejected or the vote was pruned
+                        _ => prop.set_state(SpendState::Rejected),
                     };
-                    let ret_state = new_spend_proposal.state();
-                    <SpendProposals<T>>::insert(
-                        prop.bank_id(),
-                        prop.spend_id(),
-                        new_spend_proposal,
-                    );
-                    Ok(ret_state)
+                let ret_state = new_spend_proposal.state();
+                <SpendProposals<T>>::insert(
+                    bank_id,
+                    spend_id,
+                    new_spend_proposal,
+                );
+                Ok(ret_state)
+            }
+            SpendState::ApprovedAndScheduled => Ok(prop.state()),
+            // still pending, see `supports_pending_spend`, until paid out or expired
+            SpendState::ApprovedButNotExecuted => {
+                if Self::transfer_spend(&prop).is_ok() {
+                    let executed =
+                        prop.set_state(SpendState::ApprovedAndExecuted);
+                    <SpendProposals<T>>::insert(bank_id, spend_id, executed);
+                    Ok(SpendState::ApprovedAndExecuted)
+                } else if Self::spend_expired(bank_id, spend_id) {
+                    Self::remove_spend(bank_id, spend_id);
+                    Self::deposit_event(RawEvent::SpendExpired(
+                        bank_id, spend_id,
+                    ));
+                    Ok(prop.state())
                 } else {
                     Ok(prop.state())
                 }
             }
-            _ => Ok(prop.state()),
+            // finished proposals are pruned the poll after they finish
+            _ => {
+                Self::remove_spend(bank_id, spend_id);
+                Self::deposit_event(RawEvent::SpendArchived(
+                    bank_id,
+                    spend_id,
+                    prop.amount(),
+                    prop.dest(),
+                    prop.state(),
+                ));
+                Ok(prop.state())
+            }
         }
     }
 }
@@ -5,7 +5,11 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
-    traits::OnFinalize,
+    traits::{
+        Currency,
+        OnFinalize,
+        OnInitialize,
+    },
     weights::Weight,
 };
 use frame_system::{self as system,};
@@ -35,7 +39,9 @@ pub type AccountId = u64;
 pub type BlockNumber = u64;
 
 impl_outer_origin! {
-    pub enum Origin for Test where system = frame_system {}
+    pub enum Origin for Test where system = frame_system {
+        org<T>,
+    }
 }
 
 mod bank {
@@ -46,6 +52,7 @@ impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
         pallet_balances<T>,
+        tokens<T>,
         org<T>,
         vote<T>,
         donate<T>,
@@ -99,43 +106,92 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const NativeCurrencyId: u32 = 0;
+}
+impl tokens::Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type CurrencyId = u32;
+    type NativeCurrency = Balances;
+    type GetNativeCurrencyId = NativeCurrencyId;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: u64 = 5;
+    pub const SupervisorTermLength: u64 = 10;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type OrgOrigin = org::EnsureOrg<Test>;
+    type ElectionId = u64;
+    type SupervisorVote = vote::Module<Test>;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const MaxDelegators: u32 = 3;
+    pub const MaxOptions: u32 = 5;
+    pub const VoteRetentionPeriod: Option<u64> = None;
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type MaxDelegators = MaxDelegators;
+    type MaxOptions = MaxOptions;
+    type VoteRetentionPeriod = VoteRetentionPeriod;
+    type WeightInfo = ();
 }
 impl donate::Trait for Test {
     type Event = TestEvent;
-    type Currency = Balances;
+    type Currency = Tokens;
+    type WeightInfo = ();
 }
 parameter_types! {
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
     pub const MaxTreasuryPerOrg: u32 = 50;
     pub const MinDeposit: u64 = 20;
+    pub const SpendExpiry: u64 = 30;
+    pub const SpendVoteDuration: u64 = 10;
+    pub const MaxBankProposals: u32 = 2;
 }
 impl Trait for Test {
     type Event = TestEvent;
-    type Currency = Balances;
+    type Currency = Tokens;
     type BigBank = BigBank;
     type BankId = u64;
     type SpendId = u64;
     type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
     type MinDeposit = MinDeposit;
+    type SpendExpiry = SpendExpiry;
+    type SpendVoteDuration = SpendVoteDuration;
+    type MaxBankProposals = MaxBankProposals;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = tokens::Module<Test>;
 pub type Org = org::Module<Test>;
 pub type Vote = vote::Module<Test>;
 pub type Bank = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64, u64, u32, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -154,6 +210,7 @@ fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64, u64> {
 fn run_to_block(n: u64) {
     while System::block_number() < n {
         Bank::on_finalize(System::block_number());
+        Vote::on_finalize(System::block_number());
         System::set_block_number(System::block_number() + 1);
     }
 }
@@ -167,6 +224,11 @@ fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    tokens::GenesisConfig::<Test> {
+        endowed_accounts: vec![(1, 1, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     org::GenesisConfig::<Test> {
         first_organization_supervisor: 1,
         first_organization_value_constitution: 1738,
@@ -209,11 +271,11 @@ fn opening_bank_account_works() {
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
         assert_noop!(
-            Bank::open(sixnine, 1, 10, None, threshold.clone()),
+            Bank::open(sixnine, 1, 0, 10, None, threshold.clone()),
             Error::<Test>::NotPermittedToOpenBankAccountForOrg
         );
         assert_noop!(
-            Bank::open(one.clone(), 1, 19, None, threshold.clone()),
+            Bank::open(one.clone(), 1, 0, 19, None, threshold.clone()),
             Error::<Test>::CannotOpenBankAccountIfDepositIsBelowModuleMinimum
         );
         let false_threshold = ThresholdInput::new(
@@ -221,15 +283,15 @@ fn opening_bank_account_works() {
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
         assert_noop!(
-            Bank::open(one.clone(), 1, 20, None, false_threshold),
+            Bank::open(one.clone(), 1, 0, 20, None, false_threshold),
             Error::<Test>::ThresholdCannotBeSetForOrg
         );
         let total_bank_count = Bank::total_bank_count();
         assert_eq!(total_bank_count, 0u32);
-        assert_ok!(Bank::open(one.clone(), 1, 20, None, threshold));
+        assert_ok!(Bank::open(one.clone(), 1, 0, 20, None, threshold));
         assert_eq!(
             get_last_event(),
-            RawEvent::AccountOpened(1, 1, 20, 1, None),
+            RawEvent::AccountOpened(1, 1, 0, 20, 1, None),
         );
         let total_bank_count = Bank::total_bank_count();
         assert_eq!(total_bank_count, 1u32);
@@ -247,7 +309,7 @@ fn spend_governance_works() {
             OrgRep::Equal(1),
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
-        assert_ok!(Bank::open(Origin::signed(1), 1, 20, Some(1), threshold));
+        assert_ok!(Bank::open(Origin::signed(1), 1, 0, 20, Some(1), threshold));
         assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 3,));
         System::set_block_number(9);
         assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1,));
@@ -270,3 +332,685 @@ fn spend_governance_works() {
         assert_eq!(Balances::total_balance(&4), 80);
     });
 }
+
+#[test]
+fn bank_holds_and_spends_non_native_asset() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_noop!(
+            Bank::open(Origin::signed(2), 1, 1, 20, Some(2), threshold.clone()),
+            Error::<Test>::InsufficientBalanceToFundBankOpen
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 1, 60, Some(1), threshold));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::AccountOpened(1, 1, 1, 60, 1, Some(1)),
+        );
+        assert_eq!(Bank::bank_currency(1), 1);
+        assert_eq!(Bank::bank_balance(1), 60);
+        assert_eq!(Tokens::free_balance(1, &1), 40);
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 12, 4,));
+        assert_ok!(Bank::sudo_approve(Origin::signed(1), 1, 1));
+        assert_eq!(Tokens::free_balance(1, &4), 12);
+        assert_eq!(Balances::total_balance(&4), 75);
+        // remaining 48 is donated to the members, 1/6 * 48 rounds down to 7
+        assert_ok!(Bank::close(Origin::signed(1), 1));
+        assert_eq!(Tokens::free_balance(1, &4), 19);
+        // 40 + 7 + 6 (remainder to closer) = 53
+        assert_eq!(Tokens::free_balance(1, &1), 53);
+        assert!(!Bank::is_bank(1));
+    });
+}
+
+#[test]
+fn passed_org_vote_approves_spend_without_controller() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 1, 60, None, threshold));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 12, 4));
+        assert_noop!(
+            Bank::sudo_approve(Origin::signed(1), 1, 1),
+            Error::<Test>::OnlyControllerCanSudoApproveSpendProposals
+        );
+        assert_noop!(
+            Bank::sudo_approve(org::RawOrigin::Org(2).into(), 1, 1),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Bank::sudo_approve(org::RawOrigin::Org(1).into(), 1, 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SudoApproved(Bank::bank_account_id(1), 1, 1)
+        );
+        assert_eq!(Tokens::free_balance(1, &4), 12);
+    });
+}
+
+#[test]
+fn org_origin_spends_without_proposal() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 0, 20, Some(1), threshold));
+        assert_noop!(
+            Bank::org_spend(Origin::signed(1), 1, 10, 3),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Bank::org_spend(org::RawOrigin::Org(2).into(), 1, 10, 3),
+            Error::<Test>::OnlyBankOrgCanSpendWithoutProposal
+        );
+        assert_ok!(Bank::org_spend(org::RawOrigin::Org(1).into(), 1, 10, 3));
+        assert_eq!(get_last_event(), RawEvent::OrgSpent(1, 1, 10, 3));
+        assert_eq!(Balances::total_balance(&3), 210);
+    });
+}
+
+#[test]
+fn passed_org_vote_closes_bank_without_controller() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 1, 60, None, threshold));
+        assert_noop!(
+            Bank::close(Origin::signed(1), 1),
+            Error::<Test>::OnlyControllerCanCloseBank
+        );
+        assert_noop!(
+            Bank::close(org::RawOrigin::Org(2).into(), 1),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Bank::close(org::RawOrigin::Org(1).into(), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::AccountClosed(Bank::bank_account_id(1), 1, 1)
+        );
+        // 60 is donated equally among the 6 members
+        assert_eq!(Tokens::free_balance(1, &4), 10);
+        assert!(!Bank::is_bank(1));
+    });
+}
+
+#[test]
+fn ragequit_withdraws_portion_of_every_org_bank() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_noop!(
+            Bank::ragequit(Origin::signed(4), 1, 1),
+            Error::<Test>::CannotRagequitIfBankDNE
+        );
+        assert_ok!(Bank::open(
+            Origin::signed(3),
+            1,
+            0,
+            65,
+            Some(3),
+            threshold.clone()
+        ));
+        assert_ok!(Bank::open(Origin::signed(1), 1, 1, 65, Some(1), threshold));
+        assert_noop!(
+            Bank::ragequit(Origin::signed(7), 1, 1),
+            Error::<Test>::MustBeMemberToRagequit
+        );
+        assert_noop!(
+            Bank::ragequit(Origin::signed(4), 1, 0),
+            Error::<Test>::CannotRagequitZeroShares
+        );
+        assert_noop!(
+            Bank::ragequit(Origin::signed(4), 1, 2),
+            Error::<Test>::NotEnoughSharesToRagequit
+        );
+        // supporters of a pending spend cannot exit before it is decided
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 3));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(4),
+            1,
+            VoterView::Against,
+            None
+        ));
+        assert_noop!(
+            Bank::ragequit(Origin::signed(2), 1, 1),
+            Error::<Test>::CannotRagequitWhileSupportedSpendIsPending
+        );
+        // 1 of 6 shares withdraws 1/6 of each bank, 65 / 6 rounds down to 10
+        assert_ok!(Bank::ragequit(Origin::signed(4), 2, 1));
+        assert_eq!(get_last_event(), RawEvent::MemberExited(4, 1, 1));
+        assert_eq!(Balances::total_balance(&4), 85);
+        assert_eq!(Tokens::free_balance(1, &4), 10);
+        assert_eq!(Bank::bank_balance(1), 55);
+        assert_eq!(Bank::bank_balance(2), 55);
+        assert!(!Org::is_member_of_group(1, &4));
+        assert_eq!(Org::total_issuance(1), 5);
+    });
+}
+
+#[test]
+fn supporters_of_approved_unpaid_spend_cannot_ragequit() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::open(Origin::signed(3), 1, 0, 65, None, threshold));
+        assert_ok!(Bank::propose_scheduled_spend(
+            Origin::signed(2),
+            1,
+            10,
+            4,
+            SpendSchedule::At(50)
+        ));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1));
+        for i in [1u64, 2, 3, 5].iter() {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(*i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(11);
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedAndScheduled
+        );
+        assert_noop!(
+            Bank::ragequit(Origin::signed(2), 1, 1),
+            Error::<Test>::CannotRagequitWhileSupportedSpendIsPending
+        );
+        assert_ok!(Bank::ragequit(Origin::signed(6), 1, 1));
+        // once paid out the supporters may exit
+        run_to_block(51);
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_ok!(Bank::ragequit(Origin::signed(2), 1, 1));
+    });
+}
+
+#[test]
+fn scheduled_spends_pay_out_once_approved_until_cancelled() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(3), 1, 0, 65, Some(3), threshold));
+        assert_noop!(
+            Bank::propose_scheduled_spend(
+                Origin::signed(2),
+                1,
+                10,
+                4,
+                SpendSchedule::Every(0, 3)
+            ),
+            Error::<Test>::SpendSchedulePeriodLengthMustBeGreaterThanZero
+        );
+        assert_noop!(
+            Bank::propose_scheduled_spend(
+                Origin::signed(2),
+                1,
+                10,
+                4,
+                SpendSchedule::Every(5, 0)
+            ),
+            Error::<Test>::SpendScheduleMustHaveAtLeastOnePayment
+        );
+        assert_noop!(
+            Bank::propose_scheduled_spend(
+                Origin::signed(2),
+                1,
+                10,
+                4,
+                SpendSchedule::At(1)
+            ),
+            Error::<Test>::CannotScheduleSpendForPastBlock
+        );
+        // 10 every 5 blocks for 3 payments
+        assert_ok!(Bank::propose_scheduled_spend(
+            Origin::signed(2),
+            1,
+            10,
+            4,
+            SpendSchedule::Every(5, 3)
+        ));
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 1));
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedAndScheduled
+        );
+        assert_eq!(Balances::total_balance(&4), 75);
+        // payments are due on multiples of the period
+        assert_eq!(Bank::scheduled_spends_due(5, (1, 1)), Some(()));
+        // the block a payment is due in is charged for it
+        assert!(Bank::on_initialize(5) > Bank::on_initialize(4));
+        run_to_block(6);
+        assert_eq!(get_last_event(), RawEvent::ScheduledSpendPaid(1, 1, 10, 4));
+        assert_eq!(Balances::total_balance(&4), 85);
+        assert_eq!(
+            Bank::spend_schedules(1, 1),
+            Some(SpendSchedule::Every(5, 2))
+        );
+        assert!(Bank::scheduled_spends_due(5, (1, 1)).is_none());
+        assert_eq!(Bank::scheduled_spends_due(10, (1, 1)), Some(()));
+        run_to_block(16);
+        assert_eq!(Balances::total_balance(&4), 105);
+        assert_eq!(Bank::spend_schedules(1, 1), None);
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_noop!(
+            Bank::cancel_scheduled_spend(Origin::signed(3), 1, 1),
+            Error::<Test>::NoScheduledSpendToCancel
+        );
+        // a one-off spend at a future block is cancelled by a follow-up org vote
+        assert_ok!(Bank::propose_scheduled_spend(
+            Origin::signed(2),
+            1,
+            5,
+            6,
+            SpendSchedule::At(30)
+        ));
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 2));
+        assert_noop!(
+            Bank::cancel_scheduled_spend(Origin::signed(2), 1, 2),
+            Error::<Test>::OnlyControllerOrOrgCanCancelScheduledSpend
+        );
+        assert_ok!(Bank::cancel_scheduled_spend(
+            org::RawOrigin::Org(1).into(),
+            1,
+            2
+        ));
+        assert_eq!(get_last_event(), RawEvent::ScheduledSpendCancelled(1, 2));
+        run_to_block(31);
+        assert_eq!(Balances::total_balance(&6), 69);
+        assert!(!Bank::is_spend(1, 2));
+        assert!(Bank::scheduled_spends_due(30, (1, 2)).is_none());
+        assert_eq!(Bank::bank_balance(1), 35);
+    });
+}
+
+#[test]
+fn spend_limits_cap_controller_and_spender_allowances_per_period() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(3), 1, 0, 65, Some(3), threshold));
+        let cap = SpendCap::new(20, 10);
+        assert_noop!(
+            Bank::set_spend_policy(Origin::signed(3), 1, Some(cap), Some(15)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Bank::set_spend_policy(
+                org::RawOrigin::Org(2).into(),
+                1,
+                Some(cap),
+                Some(15)
+            ),
+            Error::<Test>::OnlyBankOrgCanSetSpendPolicy
+        );
+        assert_noop!(
+            Bank::set_spend_policy(
+                org::RawOrigin::Org(1).into(),
+                1,
+                Some(SpendCap::new(20, 0)),
+                Some(15)
+            ),
+            Error::<Test>::SpendCapPeriodLengthMustBeGreaterThanZero
+        );
+        assert_ok!(Bank::set_spend_policy(
+            org::RawOrigin::Org(1).into(),
+            1,
+            Some(cap),
+            Some(15)
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendPolicySet(1, 1, true, Some(15))
+        );
+        // large spends need a vote even if the controller has allowance left
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 16, 4));
+        assert_noop!(
+            Bank::sudo_approve(Origin::signed(3), 1, 1),
+            Error::<Test>::SpendAboveLimitRequiresOrgVote
+        );
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 4));
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 2));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 4));
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 3));
+        assert_eq!(Balances::total_balance(&4), 95);
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 5, 4));
+        assert_noop!(
+            Bank::sudo_approve(Origin::signed(3), 1, 4),
+            Error::<Test>::ControllerSpendCapExceeded
+        );
+        // the cap resets in the next period
+        run_to_block(10);
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 4));
+        assert_eq!(Balances::total_balance(&4), 100);
+        // spenders spend without proposals up to their allowance
+        assert_noop!(
+            Bank::spend_allowance(Origin::signed(5), 1, 8, 6),
+            Error::<Test>::NoSpendAllowanceForBank
+        );
+        assert_ok!(Bank::set_spender_allowance(
+            org::RawOrigin::Org(1).into(),
+            1,
+            5,
+            Some(SpendCap::new(10, 10))
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpenderAllowanceSet(1, 1, 5, true)
+        );
+        assert_ok!(Bank::spend_allowance(Origin::signed(5), 1, 8, 6));
+        assert_eq!(get_last_event(), RawEvent::AllowanceSpent(5, 1, 8, 6));
+        assert_eq!(Balances::total_balance(&6), 77);
+        assert_noop!(
+            Bank::spend_allowance(Origin::signed(5), 1, 3, 6),
+            Error::<Test>::SpendAllowanceExceeded
+        );
+        assert_noop!(
+            Bank::spend_allowance(Origin::signed(5), 1, 16, 6),
+            Error::<Test>::SpendAboveLimitRequiresOrgVote
+        );
+        assert_eq!(Bank::bank_balance(1), 32);
+    });
+}
+
+#[test]
+fn failed_controller_spend_does_not_use_allowance() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(3), 1, 0, 65, Some(3), threshold));
+        assert_ok!(Bank::set_spend_policy(
+            org::RawOrigin::Org(1).into(),
+            1,
+            Some(SpendCap::new(100, 10)),
+            None
+        ));
+        // the bank cannot cover the spend so it is approved but not executed
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 70, 4));
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 1));
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert_eq!(Bank::controller_allowances(1).unwrap().spent_at(1), 0);
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 40, 4));
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 2));
+        assert_eq!(Bank::controller_allowances(1).unwrap().spent_at(1), 40);
+        assert_eq!(Bank::bank_balance(1), 25);
+        // the failed transfer is retried each poll until the bank can cover it
+        run_to_block(11);
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert_ok!(Balances::transfer(
+            Origin::signed(3),
+            Bank::bank_account_id(1),
+            50
+        ));
+        run_to_block(21);
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Bank::bank_balance(1), 5);
+        assert_eq!(Balances::total_balance(&4), 185);
+    });
+}
+
+#[test]
+fn undecided_spend_votes_are_rejected_and_unpaid_spends_expire() {
+    new_test_ext().execute_with(|| {
+        // no rejection threshold so only the end of the vote rejects the spend
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(3), 1, 0, 65, Some(3), threshold));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 4));
+        assert_eq!(Bank::spend_proposal_count(1), 1);
+        assert_eq!(Bank::total_spend_proposals(), 1);
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(11);
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::Voting(1)
+        );
+        // the vote closes at block 11 and the next poll rejects the spend
+        run_to_block(21);
+        assert_eq!(
+            Bank::spend_proposals(1, 1).unwrap().state(),
+            SpendState::Rejected
+        );
+        run_to_block(31);
+        assert!(!Bank::is_spend(1, 1));
+        let archived = TestEvent::bank(RawEvent::SpendArchived(
+            1,
+            1,
+            10,
+            4,
+            SpendState::Rejected,
+        ));
+        assert!(System::events().into_iter().any(|r| r.event == archived));
+        // an approved spend the bank cannot cover expires after `SpendExpiry`
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 100, 4));
+        run_to_block(41);
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 2));
+        assert_eq!(Bank::spend_proposers(1, 2), Some((2, 71)));
+        run_to_block(71);
+        assert_eq!(
+            Bank::spend_proposals(1, 2).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        run_to_block(81);
+        assert!(!Bank::is_spend(1, 2));
+        assert_eq!(Bank::spend_proposal_count(1), 0);
+        assert_eq!(Bank::total_spend_proposals(), 0);
+        let expired = TestEvent::bank(RawEvent::SpendExpired(1, 2));
+        assert!(System::events().into_iter().any(|r| r.event == expired));
+        assert_eq!(Bank::bank_balance(1), 65);
+    });
+}
+
+#[test]
+fn bank_votes_rotate_controller_and_close_bank() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 1, 60, None, threshold));
+        assert_noop!(
+            Bank::propose_close(Origin::signed(1), 2),
+            Error::<Test>::CannotProposeBankChangeIfBankDNE
+        );
+        assert_noop!(
+            Bank::propose_controller_change(Origin::signed(69), 1, Some(2)),
+            Error::<Test>::NotPermittedToProposeBankChange
+        );
+        assert_ok!(Bank::propose_controller_change(
+            Origin::signed(3),
+            1,
+            Some(2)
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ControllerChangeProposed(3, 1, 1, Some(2))
+        );
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_eq!(Bank::bank_proposal_count(1), 1);
+        run_to_block(11);
+        assert_eq!(Bank::banks(1).unwrap().controller(), Some(2));
+        assert!(Bank::bank_proposals(1, 1).is_none());
+        assert_eq!(Bank::bank_proposal_count(1), 0);
+        assert_ok!(Bank::propose_close(Origin::signed(2), 1));
+        assert_eq!(get_last_event(), RawEvent::CloseProposed(2, 1, 2));
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(21);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BankProposalExecuted(1, 2, Ok(()))
+        );
+        // 60 is donated equally among the 6 members
+        assert_eq!(Tokens::free_balance(1, &4), 10);
+        assert!(!Bank::is_bank(1));
+        assert_eq!(Bank::total_bank_count(), 0);
+    });
+}
+
+#[test]
+fn bank_proposals_are_bounded_and_rejected_when_undecided() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::open(Origin::signed(1), 1, 1, 60, None, threshold));
+        assert_ok!(Bank::propose_close(Origin::signed(2), 1));
+        assert_ok!(Bank::propose_controller_change(
+            Origin::signed(3),
+            1,
+            Some(3)
+        ));
+        assert_noop!(
+            Bank::propose_close(Origin::signed(4), 1),
+            Error::<Test>::TooManyPendingBankProposals
+        );
+        assert_eq!(Bank::bank_proposal_count(1), 2);
+        assert_eq!(Bank::total_bank_proposals(), 2);
+        // both votes close undecided at block 11 and are rejected by the next poll
+        run_to_block(11);
+        assert_eq!(Bank::bank_proposal_count(1), 2);
+        run_to_block(21);
+        for vote_id in 1u64..3u64 {
+            let rejected =
+                TestEvent::bank(RawEvent::BankProposalRejected(1, vote_id));
+            assert!(System::events().into_iter().any(|r| r.event == rejected));
+        }
+        assert_eq!(Bank::bank_proposal_count(1), 0);
+        assert_eq!(Bank::total_bank_proposals(), 0);
+        assert!(Bank::is_bank(1));
+        assert_ok!(Bank::propose_close(Origin::signed(4), 1));
+    });
+}
+
+#[test]
+fn spend_proposals_are_cancelled_rejected_expired_and_pruned() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::one(),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::open(Origin::signed(3), 1, 0, 65, Some(3), threshold));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 4));
+        assert_eq!(Bank::spend_proposers(1, 1), Some((2, 31)));
+        assert_noop!(
+            Bank::cancel_spend(Origin::signed(2), 1, 9),
+            Error::<Test>::CannotCancelSpendIfSpendProposalDNE
+        );
+        assert_noop!(
+            Bank::cancel_spend(Origin::signed(3), 1, 1),
+            Error::<Test>::OnlyProposerCanCancelSpend
+        );
+        assert_ok!(Bank::cancel_spend(Origin::signed(2), 1, 1));
+        assert_eq!(get_last_event(), RawEvent::SpendCancelled(2, 1, 1));
+        assert!(!Bank::is_spend(1, 1));
+        // half of the members against rejects the spend
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 4));
+        assert_ok!(Bank::trigger_vote(Origin::signed(2), 1, 2));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None
+            ));
+        }
+        // never voted on
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 5));
+        // executed
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 6));
+        assert_ok!(Bank::sudo_approve(Origin::signed(3), 1, 4));
+        assert_noop!(
+            Bank::cancel_spend(Origin::signed(2), 1, 4),
+            Error::<Test>::CannotCancelSpendFromCurrentSpendProposalState
+        );
+        run_to_block(11);
+        assert_eq!(
+            Bank::spend_proposals(1, 2).unwrap().state(),
+            SpendState::Rejected
+        );
+        assert!(!Bank::is_spend(1, 4));
+        let archived = TestEvent::bank(RawEvent::SpendArchived(
+            1,
+            4,
+            10,
+            6,
+            SpendState::ApprovedAndExecuted,
+        ));
+        assert!(System::events().into_iter().any(|r| r.event == archived));
+        run_to_block(21);
+        assert!(!Bank::is_spend(1, 2));
+        assert!(Bank::is_spend(1, 3));
+        run_to_block(41);
+        assert!(!Bank::is_spend(1, 3));
+        assert_eq!(Bank::spend_proposers(1, 3), None);
+        assert_eq!(Balances::total_balance(&5), 10);
+    });
+}
@@ -0,0 +1,264 @@
+//! Bounty pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_support::traits::OnFinalize;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+use util::{
+    organization::{
+        OrgRep,
+        OrganizationSource,
+    },
+    traits::{
+        OpenBankAccount,
+        RegisterOrganization,
+    },
+    vote::{
+        Threshold as VoteThreshold,
+        ThresholdInput,
+        VoterView,
+        XorThreshold,
+    },
+};
+
+const SEED: u32 = 0;
+const MAX_ISSUE_LEN: u32 = 1024;
+const MAX_MEMBERS: u32 = 100;
+const MAX_CONTRIBUTORS: u32 = 100;
+const MAX_WINNERS: u32 = 20;
+const MAX_PER_BLOCK: u32 = 100;
+
+fn native<T: Trait>() -> CurrencyIdOf<T> {
+    <T as Trait>::Currency::native_currency_id()
+}
+
+fn funded<T: Trait>(who: &T::AccountId) {
+    <T as Trait>::Currency::deposit(
+        native::<T>(),
+        who,
+        BalanceOf::<T>::max_value(),
+    )
+    .expect("native deposit works");
+}
+
+fn issue(i: u32, seed: u8) -> EncodedIssue {
+    let mut issue = vec![0u8; i as usize];
+    issue.push(seed);
+    issue
+}
+
+fn submission<T: Trait>(
+    bounty_id: T::BountyId,
+    submitter: T::AccountId,
+    index: u32,
+) -> T::SubmissionId {
+    Module::<T>::submit_for_bounty(
+        RawOrigin::Signed(submitter).into(),
+        bounty_id,
+        issue(index, 1),
+        T::IpfsReference::default(),
+        <T as Trait>::MinDeposit::get(),
+    )
+    .expect("bounty can fund submission");
+    Module::<T>::submission_nonce()
+}
+
+fn bounty<T: Trait>(depositer: T::AccountId) -> T::BountyId {
+    funded::<T>(&depositer);
+    let amount = <T as Trait>::MinDeposit::get() * 1000u32.into();
+    Module::<T>::post_bounty(
+        RawOrigin::Signed(depositer).into(),
+        issue(0, 0),
+        T::IpfsReference::default(),
+        native::<T>(),
+        amount,
+        None,
+    )
+    .expect("funded depositer can post bounty");
+    Module::<T>::bounty_nonce()
+}
+
+/// Opens a bank for an org of size `m` supervised by `caller` and funds it for bounties
+fn funded_bank<T: Trait>(caller: T::AccountId, m: u32) -> T::BankId {
+    <T as bank::Trait>::Currency::deposit(
+        native::<T>(),
+        &caller,
+        Bounded::max_value(),
+    )
+    .expect("native deposit works");
+    let mut members = vec![caller.clone()];
+    members.extend((1..m).map(|i| account("member", i, SEED)));
+    let org = <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        Some(caller.clone()),
+        T::Cid::default(),
+    )
+    .expect("org registration with supervisor works");
+    let majority = ThresholdInput::new(
+        OrgRep::Equal(org),
+        XorThreshold::Percent(VoteThreshold::new(
+            Permill::from_percent(51),
+            None,
+        )),
+    );
+    let bank_id = <bank::Module<T>>::open_bank_account(
+        caller.clone(),
+        org,
+        <T as bank::Trait>::MinDeposit::get(),
+        Some(caller),
+        majority,
+    )
+    .expect("bank opens for funded caller");
+    funded::<T>(&<bank::Module<T>>::bank_account_id(bank_id));
+    bank_id
+}
+
+benchmarks! {
+    _ { }
+
+    post_bounty {
+        let i in 0 .. MAX_ISSUE_LEN;
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+    }: _(RawOrigin::Signed(caller), issue(i, 1), T::IpfsReference::default(), native::<T>(), <T as Trait>::MinDeposit::get(), None)
+
+    post_org_bounty {
+        let i in 0 .. MAX_ISSUE_LEN;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+    }: _(RawOrigin::Signed(caller), bank_id, issue(i, 1), T::IpfsReference::default(), <T as Trait>::MinDeposit::get(), None)
+
+    contribute_to_bounty {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let bounty_id = bounty::<T>(account("depositer", 0, SEED));
+    }: _(RawOrigin::Signed(caller), bounty_id, T::MinContribution::get())
+
+    submit_for_bounty {
+        let i in 0 .. MAX_ISSUE_LEN;
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(account("depositer", 0, SEED));
+    }: _(RawOrigin::Signed(caller), bounty_id, issue(i, 1), T::IpfsReference::default(), T::MinContribution::get())
+
+    approve_bounty_submission {
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(caller.clone());
+        let submitter: T::AccountId = account("submitter", 0, SEED);
+        Module::<T>::submit_for_bounty(
+            RawOrigin::Signed(submitter).into(),
+            bounty_id,
+            issue(0, 1),
+            T::IpfsReference::default(),
+            <T as Trait>::MinDeposit::get(),
+        )?;
+        let submission_id = Module::<T>::submission_nonce();
+    }: _(RawOrigin::Signed(caller), submission_id, None)
+    verify {
+        assert!(Module::<T>::submissions(submission_id).is_none());
+    }
+
+    approve_split {
+        let s in 1 .. MAX_WINNERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(caller.clone());
+        let mut split = Vec::new();
+        for i in 0..s {
+            let submitter: T::AccountId = account("submitter", i, SEED);
+            let submission_id = submission::<T>(bounty_id, submitter, i);
+            split.push((submission_id, Permill::from_rational_approximation(1, s)));
+        }
+    }: _(RawOrigin::Signed(caller), bounty_id, split)
+
+    trigger_approval_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), m);
+        Module::<T>::post_org_bounty(
+            RawOrigin::Signed(caller.clone()).into(),
+            bank_id,
+            issue(0, 0),
+            T::IpfsReference::default(),
+            <T as Trait>::MinDeposit::get() * 1000u32.into(),
+            None,
+        )?;
+        let bounty_id = Module::<T>::bounty_nonce();
+        let submission_id = submission::<T>(bounty_id, account("submitter", 0, SEED), 0);
+    }: _(RawOrigin::Signed(caller), submission_id, None)
+    verify {
+        assert!(Module::<T>::approval_votes(submission_id).is_some());
+    }
+
+    cancel_bounty {
+        let c in 1 .. MAX_CONTRIBUTORS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(caller.clone());
+        for i in 1..c {
+            let contributor: T::AccountId = account("contributor", i, SEED);
+            funded::<T>(&contributor);
+            Module::<T>::contribute_to_bounty(
+                RawOrigin::Signed(contributor).into(),
+                bounty_id,
+                T::MinContribution::get(),
+            )?;
+        }
+    }: _(RawOrigin::Signed(caller), bounty_id)
+    verify {
+        assert!(Module::<T>::bounties(bounty_id).is_none());
+    }
+
+    reject_submission {
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(caller.clone());
+        let submission_id = submission::<T>(bounty_id, account("submitter", 0, SEED), 0);
+    }: _(RawOrigin::Signed(caller), submission_id, Some(T::IpfsReference::default()))
+    verify {
+        assert!(Module::<T>::submissions(submission_id).is_none());
+    }
+
+    withdraw_submission {
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(account("depositer", 0, SEED));
+        let submission_id = submission::<T>(bounty_id, caller.clone(), 0);
+    }: _(RawOrigin::Signed(caller), submission_id)
+    verify {
+        assert!(Module::<T>::submissions(submission_id).is_none());
+    }
+
+    update_submission {
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(account("depositer", 0, SEED));
+        let submission_id = submission::<T>(bounty_id, caller.clone(), 0);
+    }: _(RawOrigin::Signed(caller), submission_id, T::IpfsReference::default(), T::MinContribution::get())
+
+    settle_approval_votes {
+        let v in 1 .. MAX_PER_BLOCK;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        Module::<T>::post_org_bounty(
+            RawOrigin::Signed(caller.clone()).into(),
+            bank_id,
+            issue(0, 0),
+            T::IpfsReference::default(),
+            <T as Trait>::MinDeposit::get() * 1000u32.into(),
+            None,
+        )?;
+        let bounty_id = Module::<T>::bounty_nonce();
+        let ends = <frame_system::Module<T>>::block_number() + T::ApprovalVoteDuration::get();
+        for i in 0..v {
+            let submission_id = submission::<T>(bounty_id, account("submitter", i, SEED), i);
+            Module::<T>::trigger_approval_vote(RawOrigin::Signed(caller.clone()).into(), submission_id, None)?;
+            let (vote_id, _) = Module::<T>::approval_votes(submission_id).ok_or("approval vote triggered")?;
+            <vote::Module<T>>::submit_vote(RawOrigin::Signed(caller.clone()).into(), vote_id, VoterView::InFavor, None)?;
+        }
+    }: { Module::<T>::on_finalize(ends) }
+    verify {
+        assert!(Module::<T>::approval_votes(Module::<T>::submission_nonce()).is_none());
+    }
+}
@@ -0,0 +1,169 @@
+//! Default weights for the bounty pallet, to be regenerated from `benchmarking.rs`
+//! with the `benchmark` subcommand of a node built with `runtime-benchmarks`
+//!
+//! The runtime charges database access at its own `DbWeight` with `SubstrateWeight`,
+//! `()` charges `RocksDbWeight` for the mocks
+
+use frame_support::{
+    traits::Get,
+    weights::{
+        constants::RocksDbWeight as DbWeight,
+        Weight,
+    },
+};
+use sp_std::marker::PhantomData;
+
+impl crate::WeightInfo for () {
+    fn post_bounty(i: u32) -> Weight {
+        (85_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(7 as Weight))
+    }
+    fn post_org_bounty(i: u32) -> Weight {
+        (95_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(8 as Weight))
+    }
+    fn contribute_to_bounty() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn submit_for_bounty(i: u32) -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn approve_bounty_submission() -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn approve_split(s: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((65_000_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(s as Weight)))
+            .saturating_add(DbWeight::get().writes((6 as Weight).saturating_mul(s as Weight)))
+    }
+    fn trigger_approval_vote(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn cancel_bounty(c: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((45_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(DbWeight::get().writes(7 as Weight))
+            .saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+    }
+    fn reject_submission() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn withdraw_submission() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn update_submission() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn settle_approval_votes(v: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((85_000_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads(8 * v as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes(5 * v as Weight))
+    }
+}
+
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> crate::WeightInfo for SubstrateWeight<T> {
+    fn post_bounty(i: u32) -> Weight {
+        (85_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
+    }
+    fn post_org_bounty(i: u32) -> Weight {
+        (95_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(8 as Weight))
+    }
+    fn contribute_to_bounty() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn submit_for_bounty(i: u32) -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn approve_bounty_submission() -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn approve_split(s: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((65_000_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(s as Weight)))
+            .saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(s as Weight)))
+    }
+    fn trigger_approval_vote(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn cancel_bounty(c: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((45_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+    }
+    fn reject_submission() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn withdraw_submission() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn update_submission() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn settle_approval_votes(v: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((85_000_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(8 * v as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 * v as Weight))
+    }
+}
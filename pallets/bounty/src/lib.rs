@@ -3,7 +3,11 @@
 #![allow(clippy::type_complexity)]
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Minimal bounty module
+//! -> bounties are posted by individuals or by orgs from one of their bank accounts
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -14,15 +18,15 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
-    storage::IterableStorageMap,
+    storage::{
+        IterableStorageDoubleMap,
+        IterableStorageMap,
+    },
     traits::{
-        Currency,
         ExistenceRequirement,
         Get,
-        ReservableCurrency,
-        WithdrawReason,
-        WithdrawReasons,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -32,26 +36,43 @@ use sp_runtime::{
         AtLeast32Bit,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
+    DispatchError,
     DispatchResult,
     ModuleId,
+    Permill,
 };
 use sp_std::{
     fmt::Debug,
     prelude::*,
 };
-use util::bounty::{
-    BountyInformation,
-    BountySubmission,
-    Contribution,
-    SubmissionState,
+use util::{
+    bounty::{
+        BountyInformation,
+        BountySubmission,
+        Contribution,
+        SubmissionState,
+    },
+    share::WeightedVector,
+    traits::{
+        AccessGenesis,
+        ConfigureThreshold,
+        EnsureOrgOrigin,
+        GetVoteOutcome,
+        MultiCurrency,
+    },
+    vote::VoteOutcome,
 };
 
 // type aliases
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+type BalanceOf<T> = <<T as Trait>::Currency as MultiCurrency<
     <T as frame_system::Trait>::AccountId,
 >>::Balance;
+type CurrencyIdOf<T> = <<T as Trait>::Currency as MultiCurrency<
+    <T as frame_system::Trait>::AccountId,
+>>::CurrencyId;
 type Bounty<T> = BountyInformation<
     <T as Trait>::BountyId,
     <T as Trait>::IpfsReference,
@@ -73,16 +94,34 @@ type Contrib<T> = Contribution<
 >;
 type EncodedIssue = Vec<u8>;
 
-pub trait Trait: frame_system::Trait {
+pub trait WeightInfo {
+    fn post_bounty(i: u32) -> Weight;
+    fn post_org_bounty(i: u32) -> Weight;
+    fn contribute_to_bounty() -> Weight;
+    fn submit_for_bounty(i: u32) -> Weight;
+    fn approve_bounty_submission() -> Weight;
+    fn approve_split(s: u32) -> Weight;
+    fn trigger_approval_vote(m: u32) -> Weight;
+    fn cancel_bounty(c: u32) -> Weight;
+    fn reject_submission() -> Weight;
+    fn withdraw_submission() -> Weight;
+    fn update_submission() -> Weight;
+    fn settle_approval_votes(v: u32) -> Weight;
+}
+
+pub trait Trait: frame_system::Trait + bank::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
     /// Cid type
     type IpfsReference: Parameter + Member + Default;
 
-    /// The currency type
-    type Currency: Currency<Self::AccountId>
-        + ReservableCurrency<Self::AccountId>;
+    /// The multi-asset currency type, shares asset identifiers and balances with `bank`
+    type Currency: MultiCurrency<
+        Self::AccountId,
+        CurrencyId = bank::CurrencyIdOf<Self>,
+        Balance = bank::BalanceOf<Self>,
+    >;
 
     /// The bounty post identifier
     type BountyId: Parameter
@@ -118,6 +157,15 @@ pub trait Trait: frame_system::Trait {
 
     /// Minimum contribution to posted bounty
     type MinContribution: Get<BalanceOf<Self>>;
+
+    /// Number of blocks org members may vote on approving a submission
+    type ApprovalVoteDuration: Get<Self::BlockNumber>;
+
+    /// Number of blocks after which an expired bounty's failed refund is retried
+    type RefundRetryPeriod: Get<Self::BlockNumber>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -127,16 +175,45 @@ decl_event!(
         <T as Trait>::IpfsReference,
         <T as Trait>::BountyId,
         <T as Trait>::SubmissionId,
+        <T as frame_system::Trait>::BlockNumber,
+        <T as org::Trait>::OrgId,
+        <T as bank::Trait>::BankId,
+        <T as vote::Trait>::VoteId,
+        CurrencyId = CurrencyIdOf<T>,
         Balance = BalanceOf<T>,
     {
-        /// Poster, Initial Amount, Identifier, Bounty Metadata (i.e. github issue reference)
-        BountyPosted(AccountId, Balance, BountyId, IpfsReference),
+        /// Poster, Asset, Initial Amount, Identifier, Bounty Metadata (i.e. github issue reference)
+        BountyPosted(AccountId, CurrencyId, Balance, BountyId, IpfsReference),
+        /// Poster, Funding Bank Identifier, Org Identifier, Bounty Identifier
+        OrgBountyPosted(AccountId, BankId, OrgId, BountyId),
         /// Contributor, This Contribution Amount, Identifier, Full Amount After Contribution, Bounty Metadata
         BountyRaiseContribution(AccountId, Balance, BountyId, Balance, IpfsReference),
         /// Submitter, Bounty Identifier, Amount Requested, Submission Identifier, Bounty Metadata, Submission Metadata
         BountySubmissionPosted(AccountId, BountyId, Balance, SubmissionId, IpfsReference, IpfsReference),
         /// Bounty Identifier, Full Amount Left After Payment, Submission Identifier, Amount Requested, Bounty Metadata, Submission Metadata
         BountyPaymentExecuted(BountyId, Balance, SubmissionId, Balance, AccountId, IpfsReference, IpfsReference),
+        /// Trigger-er, Bounty Identifier, Submission Identifier, Amount Paid If Approved, Vote Identifier
+        SubmissionApprovalVoteTriggered(AccountId, BountyId, SubmissionId, Balance, VoteId),
+        /// Bounty Identifier, Submission Identifier, Vote Identifier
+        SubmissionRejectedByVote(BountyId, SubmissionId, VoteId),
+        /// Submission Identifier, Vote Identifier
+        SubmissionApprovedByVoteButPaymentFailed(SubmissionId, VoteId),
+        /// Depositer, Bounty Identifier, Submission Identifier, Optional Reason For Rejection
+        SubmissionRejected(AccountId, BountyId, SubmissionId, Option<IpfsReference>),
+        /// Submitter, Bounty Identifier, Submission Identifier
+        SubmissionWithdrawn(AccountId, BountyId, SubmissionId),
+        /// Submitter, Bounty Identifier, Submission Identifier, New Amount Requested, New Submission Metadata
+        SubmissionUpdated(AccountId, BountyId, SubmissionId, Balance, IpfsReference),
+        /// Bounty Identifier, Block Number At Which The Bounty Expires
+        BountyExpirySet(BountyId, BlockNumber),
+        /// Depositer, Bounty Identifier, Total Refunded To Contributors, Remainder Sent To Depositer
+        BountyCancelled(AccountId, BountyId, Balance, Balance),
+        /// Bounty Identifier, Total Refunded To Contributors, Remainder Sent To Depositer
+        BountyExpired(BountyId, Balance, Balance),
+        /// Bounty Identifier, Total Left In The Bounty, Block Number At Which The Refund Is Retried
+        BountyExpiredButRefundFailed(BountyId, Balance, BlockNumber),
+        /// Bounty Identifier, Contributor, Amount Refunded
+        ContributionRefunded(BountyId, AccountId, Balance),
     }
 );
 
@@ -153,6 +230,26 @@ decl_error! {
         CannotApproveSubmissionIfAmountExceedsTotalAvailable,
         NotAuthorizedToApproveBountySubmissions,
         IssueAlreadyClaimedForBountyOrSubmission,
+        BountyExpiryMustBeInTheFuture,
+        NotAuthorizedToCancelBounty,
+        NotAuthorizedToRejectBountySubmissions,
+        NotAuthorizedToWithdrawBountySubmission,
+        NotAuthorizedToUpdateBountySubmission,
+        SubmissionNotInValidStateToReject,
+        SubmissionNotInValidStateToWithdraw,
+        SubmissionNotInValidStateToUpdate,
+        ApprovedAmountExceedsAmountRequested,
+        PayoutMustBeNonZero,
+        SubmissionNotForBounty,
+        DuplicateSubmissionInSplit,
+        SplitExceedsBountyTotal,
+        BankMustExistToPostOrgBounty,
+        NotAuthorizedToPostBountyForBank,
+        ApprovalVoteOnlyForOrgBounties,
+        BankMustExistToTriggerApprovalVote,
+        NotAuthorizedToTriggerApprovalVote,
+        SubmissionUnderApprovalVote,
+        RefundBelowExistentialDeposit,
     }
 }
 
@@ -170,14 +267,51 @@ decl_storage! {
         /// Posted Bounties
         pub Bounties get(fn bounties): map
             hasher(blake2_128_concat) T::BountyId => Option<Bounty<T>>;
+        /// Issue claimed by each bounty, released when the bounty is removed
+        pub BountyIssue get(fn bounty_issue): map
+            hasher(blake2_128_concat) T::BountyId => Option<EncodedIssue>;
+        /// The asset each bounty is funded and paid out in
+        pub BountyCurrency get(fn bounty_currency): map
+            hasher(blake2_128_concat) T::BountyId => CurrencyIdOf<T>;
+        /// Block number at which each bounty expires, if set when posted
+        pub BountyExpiry get(fn bounty_expiry): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::BlockNumber>;
+        /// Bounties indexed by the block number at which they expire
+        pub ExpiringBounties get(fn expiring_bounties): double_map
+            hasher(twox_64_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::BountyId => Option<()>;
+        /// Bank that funded each bounty posted by an org
+        pub OrgBounties get(fn org_bounties): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::BankId>;
         /// Tips for existing Bounties
         pub Contributions get(fn contributions): double_map
             hasher(blake2_128_concat) T::BountyId,
             hasher(blake2_128_concat) T::AccountId => Option<Contrib<T>>;
+        /// Number of accounts that contributed to each bounty
+        pub ContributorCount get(fn contributor_count): map
+            hasher(blake2_128_concat) T::BountyId => u32;
+        /// Index of the bounties each account contributed to
+        pub ContributionsByAccount get(fn contributions_by_account): double_map
+            hasher(blake2_128_concat) T::AccountId,
+            hasher(blake2_128_concat) T::BountyId => Option<()>;
 
         /// Posted Submissions
         pub Submissions get(fn submissions): map
             hasher(blake2_128_concat) T::SubmissionId => Option<BountySub<T>>;
+        /// Issue claimed by each submission, released if the submission is removed before approval
+        pub SubmissionIssue get(fn submission_issue): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<EncodedIssue>;
+        /// Index of the submissions posted for each bounty
+        pub SubmissionsByBounty get(fn submissions_by_bounty): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::SubmissionId => Option<()>;
+        /// Open org votes to approve submissions, with the amount paid if approved
+        pub ApprovalVotes get(fn approval_votes): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<(T::VoteId, BalanceOf<T>)>;
+        /// Approval votes indexed by the block number at which they end
+        pub ApprovalVoteEndings get(fn approval_vote_endings): double_map
+            hasher(twox_64_concat) T::BlockNumber,
+            hasher(blake2_128_concat) T::SubmissionId => Option<()>;
     }
 }
 
@@ -186,32 +320,47 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::post_bounty(issue.len() as u32)]
         fn post_bounty(
             origin,
             issue: EncodedIssue,
             info: T::IpfsReference,
+            currency_id: CurrencyIdOf<T>,
             amount: BalanceOf<T>,
+            expiry: Option<T::BlockNumber>,
         ) -> DispatchResult {
-            ensure!(<IssueHashSet>::get(issue.clone()).is_none(), Error::<T>::IssueAlreadyClaimedForBountyOrSubmission);
-            ensure!(amount >= T::MinDeposit::get(), Error::<T>::BountyPostMustExceedMinDeposit);
             let depositer = ensure_signed(origin)?;
-            let imb = T::Currency::withdraw(
-                &depositer,
-                amount,
-                WithdrawReasons::from(WithdrawReason::Transfer),
-                ExistenceRequirement::AllowDeath,
-            )?;
-            let id = Self::bounty_generate_uid();
-            let bounty = Bounty::<T>::new(id, info.clone(), depositer.clone(), amount);
-            T::Currency::resolve_creating(&Self::bounty_account_id(id), imb);
-            <IssueHashSet>::insert(issue, ());
-            <Bounties<T>>::insert(id, bounty);
-            <Contributions<T>>::insert(id, &depositer, Contrib::<T>::new(id, depositer.clone(), amount));
-            Self::deposit_event(RawEvent::BountyPosted(depositer, amount, id, info));
+            Self::create_bounty(depositer, issue, info, currency_id, amount, expiry, ExistenceRequirement::AllowDeath)?;
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::post_org_bounty(issue.len() as u32)]
+        fn post_org_bounty(
+            origin,
+            bank_id: T::BankId,
+            issue: EncodedIssue,
+            info: T::IpfsReference,
+            amount: BalanceOf<T>,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let bank = <bank::Module<T>>::banks(bank_id).ok_or(Error::<T>::BankMustExistToPostOrgBounty)?;
+            let poster = Self::ensure_bank_sudo(origin, bank_id, Error::<T>::NotAuthorizedToPostBountyForBank)?;
+            // the bank account is the depositer so refunds return to the bank
+            let depositer = <bank::Module<T>>::bank_account_id(bank_id);
+            let currency_id = <bank::Module<T>>::bank_currency(bank_id);
+            // funding the bounty is a spend without a vote so it counts against the bank limits,
+            // unless the org itself posts it
+            let allowance = if poster == depositer {
+                None
+            } else {
+                <bank::Module<T>>::check_controller_spend(bank_id, amount)?
+            };
+            let id = Self::create_bounty(depositer, issue, info, currency_id, amount, expiry, ExistenceRequirement::KeepAlive)?;
+            <bank::Module<T>>::record_controller_spend(bank_id, allowance);
+            <OrgBounties<T>>::insert(id, bank_id);
+            Self::deposit_event(RawEvent::OrgBountyPosted(poster, bank_id, bank.org(), id));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::contribute_to_bounty()]
         fn contribute_to_bounty(
             origin,
             bounty_id: T::BountyId,
@@ -220,7 +369,8 @@ decl_module! {
             let contributor = ensure_signed(origin)?;
             ensure!(amount >= T::MinContribution::get(), Error::<T>::ContributionMustExceedModuleMin);
             let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
-            T::Currency::transfer(
+            <T as Trait>::Currency::transfer(
+                <BountyCurrency<T>>::get(bounty_id),
                 &contributor,
                 &Self::bounty_account_id(bounty_id),
                 amount,
@@ -229,16 +379,18 @@ decl_module! {
             let new_contribution = if let Some(a) = <Contributions<T>>::get(bounty_id, &contributor) {
                 a.add_total(amount)
             } else {
+                <ContributorCount<T>>::mutate(bounty_id, |count| *count += 1);
                 Contrib::<T>::new(bounty_id, contributor.clone(), amount)
             };
             let new_bounty = bounty.add_total(amount);
             let total = new_bounty.total();
             <Contributions<T>>::insert(bounty_id, &contributor, new_contribution);
+            <ContributionsByAccount<T>>::insert(&contributor, bounty_id, ());
             <Bounties<T>>::insert(bounty_id, new_bounty);
             Self::deposit_event(RawEvent::BountyRaiseContribution(contributor, amount, bounty_id, total, bounty.info()));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_for_bounty(issue.len() as u32)]
         fn submit_for_bounty(
             origin,
             bounty_id: T::BountyId,
@@ -254,37 +406,223 @@ decl_module! {
             let id = Self::submission_generate_uid();
             let submission = BountySub::<T>::new(bounty_id, id, submission_ref.clone(), submitter.clone(), amount);
             <Submissions<T>>::insert(id, submission);
+            <SubmissionsByBounty<T>>::insert(bounty_id, id, ());
+            <SubmissionIssue<T>>::insert(id, &issue);
             <IssueHashSet>::insert(issue, ());
             Self::deposit_event(RawEvent::BountySubmissionPosted(submitter, bounty_id, amount, id, bounty.info(), submission_ref));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::approve_bounty_submission()]
         fn approve_bounty_submission(
             origin,
             submission_id: T::SubmissionId,
+            amount: Option<BalanceOf<T>>,
         ) -> DispatchResult {
-            let approver = ensure_signed(origin)?;
             let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
-            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToApprove);
+            ensure!(submission.state().payable(), Error::<T>::SubmissionNotInValidStateToApprove);
             let bounty_id = submission.bounty_id();
             let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
-            ensure!(bounty.total() >= submission.amount(), Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable);
-            ensure!(bounty.depositer() == approver, Error::<T>::NotAuthorizedToApproveBountySubmissions);
-            // execute payment
-            T::Currency::transfer(
-                &Self::bounty_account_id(bounty_id),
-                &submission.submitter(),
-                submission.amount(),
-                ExistenceRequirement::KeepAlive,
-            )?;
-            let new_bounty = bounty.subtract_total(submission.amount());
-            let (bounty_info, new_total) = (new_bounty.info(), new_bounty.total());
-            // submission approved and executed => can be removed
-            <Submissions<T>>::remove(submission_id);
-            <Bounties<T>>::insert(bounty_id, new_bounty);
-            Self::deposit_event(RawEvent::BountyPaymentExecuted(bounty_id, new_total, submission_id, submission.amount(), submission.submitter(), bounty_info, submission.submission()));
+            let approver = Self::ensure_approver(origin, bounty_id, &bounty, Error::<T>::NotAuthorizedToApproveBountySubmissions)?;
+            // approve the full amount requested unless a partial amount is specified
+            let amount = amount.unwrap_or_else(|| submission.amount());
+            ensure!(!amount.is_zero(), Error::<T>::PayoutMustBeNonZero);
+            ensure!(amount <= submission.amount(), Error::<T>::ApprovedAmountExceedsAmountRequested);
+            ensure!(bounty.total() >= amount, Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable);
+            Self::ensure_below_bank_vote_limit(bounty_id, &approver, amount)?;
+            Self::pay_submission(bounty, submission_id, submission, amount)?;
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::approve_split(split.len() as u32)]
+        fn approve_split(
+            origin,
+            bounty_id: T::BountyId,
+            split: Vec<(T::SubmissionId, Permill)>,
+        ) -> DispatchResult {
+            let mut bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            let approver = Self::ensure_approver(origin, bounty_id, &bounty, Error::<T>::NotAuthorizedToApproveBountySubmissions)?;
+            let mut ids = split.iter().map(|(id, _)| *id).collect::<Vec<T::SubmissionId>>();
+            ids.sort();
+            ids.dedup();
+            ensure!(ids.len() == split.len(), Error::<T>::DuplicateSubmissionInSplit);
+            let split_total = split.iter().fold(0u32, |acc, (_, share)| acc.saturating_add(share.deconstruct()));
+            ensure!(split_total <= Permill::one().deconstruct(), Error::<T>::SplitExceedsBountyTotal);
+            // check every payout before executing any of them
+            let total = bounty.total();
+            let mut payouts = Vec::<(T::SubmissionId, BountySub<T>, BalanceOf<T>)>::new();
+            for (submission_id, share) in split {
+                let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+                ensure!(submission.bounty_id() == bounty_id, Error::<T>::SubmissionNotForBounty);
+                ensure!(submission.state().payable(), Error::<T>::SubmissionNotInValidStateToApprove);
+                let amount = share.mul_floor(total).min(submission.amount());
+                ensure!(!amount.is_zero(), Error::<T>::PayoutMustBeNonZero);
+                Self::ensure_below_bank_vote_limit(bounty_id, &approver, amount)?;
+                payouts.push((submission_id, submission, amount));
+            }
+            for (submission_id, submission, amount) in payouts {
+                bounty = Self::pay_submission(bounty, submission_id, submission, amount)?;
+            }
             Ok(())
         }
+        #[weight = <T as Trait>::WeightInfo::trigger_approval_vote(Self::submission_org_size(*submission_id))]
+        fn trigger_approval_vote(
+            origin,
+            submission_id: T::SubmissionId,
+            amount: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().payable(), Error::<T>::SubmissionNotInValidStateToApprove);
+            ensure!(<ApprovalVotes<T>>::get(submission_id).is_none(), Error::<T>::SubmissionUnderApprovalVote);
+            let bounty_id = submission.bounty_id();
+            let bank_id = <OrgBounties<T>>::get(bounty_id).ok_or(Error::<T>::ApprovalVoteOnlyForOrgBounties)?;
+            let bank = <bank::Module<T>>::banks(bank_id).ok_or(Error::<T>::BankMustExistToTriggerApprovalVote)?;
+            // members trigger the vote, the org itself triggers as the bank account
+            let trigger_er = match org::EnsureOrgMember::<T>::try_org_origin(origin, bank.org()) {
+                Ok(Some(member)) => member,
+                Ok(None) => <bank::Module<T>>::bank_account_id(bank_id),
+                Err(_) => return Err(Error::<T>::NotAuthorizedToTriggerApprovalVote.into()),
+            };
+            let amount = amount.unwrap_or_else(|| submission.amount());
+            ensure!(!amount.is_zero(), Error::<T>::PayoutMustBeNonZero);
+            ensure!(amount <= submission.amount(), Error::<T>::ApprovedAmountExceedsAmountRequested);
+            // dispatch vote with the funding bank's threshold
+            let duration = T::ApprovalVoteDuration::get();
+            let vote_id = <vote::Module<T>>::invoke_threshold(bank.threshold_id(), None, Some(duration))?;
+            let ends = <frame_system::Module<T>>::block_number() + duration;
+            <ApprovalVotes<T>>::insert(submission_id, (vote_id, amount));
+            <ApprovalVoteEndings<T>>::insert(ends, submission_id, ());
+            Self::deposit_event(RawEvent::SubmissionApprovalVoteTriggered(trigger_er, bounty_id, submission_id, amount, vote_id));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::cancel_bounty(Self::contributor_count(*bounty_id))]
+        fn cancel_bounty(
+            origin,
+            bounty_id: T::BountyId,
+        ) -> DispatchResult {
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            let canceller = Self::ensure_approver(origin, bounty_id, &bounty, Error::<T>::NotAuthorizedToCancelBounty)?;
+            let (to_contributors, remainder) = Self::execute_refund(bounty_id, &bounty.depositer())?;
+            Self::recursive_remove_bounty(bounty_id);
+            Self::deposit_event(RawEvent::BountyCancelled(canceller, bounty_id, to_contributors, remainder));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::reject_submission()]
+        fn reject_submission(
+            origin,
+            submission_id: T::SubmissionId,
+            reason: Option<T::IpfsReference>,
+        ) -> DispatchResult {
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToReject);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            let rejecter = Self::ensure_approver(origin, bounty_id, &bounty, Error::<T>::NotAuthorizedToRejectBountySubmissions)?;
+            Self::remove_submission(bounty_id, submission_id);
+            Self::deposit_event(RawEvent::SubmissionRejected(rejecter, bounty_id, submission_id, reason));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::withdraw_submission()]
+        fn withdraw_submission(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.is_submitter(&submitter), Error::<T>::NotAuthorizedToWithdrawBountySubmission);
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToWithdraw);
+            let bounty_id = submission.bounty_id();
+            Self::remove_submission(bounty_id, submission_id);
+            Self::deposit_event(RawEvent::SubmissionWithdrawn(submitter, bounty_id, submission_id));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::update_submission()]
+        fn update_submission(
+            origin,
+            submission_id: T::SubmissionId,
+            submission_ref: T::IpfsReference,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.is_submitter(&submitter), Error::<T>::NotAuthorizedToUpdateBountySubmission);
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToUpdate);
+            ensure!(<ApprovalVotes<T>>::get(submission_id).is_none(), Error::<T>::SubmissionUnderApprovalVote);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(amount <= bounty.total(), Error::<T>::BountySubmissionExceedsTotalAvailableFunding);
+            <Submissions<T>>::insert(submission_id, submission.revise(submission_ref.clone(), amount));
+            Self::deposit_event(RawEvent::SubmissionUpdated(submitter, bounty_id, submission_id, amount, submission_ref));
+            Ok(())
+        }
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            // charges the work `on_finalize` does in this block, counting it reads the same keys
+            let ending = <ApprovalVoteEndings<T>>::iter_prefix(n).count() as u32;
+            let mut weight = <T as Trait>::WeightInfo::settle_approval_votes(ending)
+                .saturating_add(T::DbWeight::get().reads(ending as Weight));
+            // each expiring bounty is refunded like a cancelled bounty
+            for (bounty_id, _) in <ExpiringBounties<T>>::iter_prefix(n) {
+                weight = weight
+                    .saturating_add(<T as Trait>::WeightInfo::cancel_bounty(Self::contributor_count(bounty_id)))
+                    .saturating_add(T::DbWeight::get().reads(2));
+            }
+            weight
+        }
+        fn on_finalize(n: T::BlockNumber) {
+            // pay submissions approved by org votes that end in this block and drop those rejected
+            let ending = <ApprovalVoteEndings<T>>::iter_prefix(n)
+                .map(|(submission_id, _)| submission_id)
+                .collect::<Vec<T::SubmissionId>>();
+            <ApprovalVoteEndings<T>>::remove_prefix(n);
+            for submission_id in ending {
+                let (vote_id, amount) = match <ApprovalVotes<T>>::get(submission_id) {
+                    Some(approval) => approval,
+                    // the submission was removed while under vote
+                    None => continue,
+                };
+                match <vote::Module<T>>::get_vote_outcome(vote_id) {
+                    Ok(VoteOutcome::Approved) => {
+                        <ApprovalVotes<T>>::remove(submission_id);
+                        if Self::execute_approval_vote(submission_id, amount).is_err() {
+                            Self::deposit_event(RawEvent::SubmissionApprovedByVoteButPaymentFailed(submission_id, vote_id));
+                        }
+                    },
+                    // the vote module closes the vote later in this block
+                    Ok(VoteOutcome::NotStarted) | Ok(VoteOutcome::Voting) => {
+                        <ApprovalVoteEndings<T>>::insert(n + 1u32.into(), submission_id, ());
+                    },
+                    // rejected or the vote was pruned
+                    _ => {
+                        <ApprovalVotes<T>>::remove(submission_id);
+                        if let Some(submission) = <Submissions<T>>::get(submission_id) {
+                            let bounty_id = submission.bounty_id();
+                            // partially paid submissions keep what they were paid
+                            if submission.state().awaiting_review() {
+                                Self::remove_submission(bounty_id, submission_id);
+                            }
+                            Self::deposit_event(RawEvent::SubmissionRejectedByVote(bounty_id, submission_id, vote_id));
+                        }
+                    },
+                }
+            }
+            // refund contributors to bounties that expire in this block
+            let expiring = <ExpiringBounties<T>>::iter_prefix(n)
+                .map(|(bounty_id, _)| bounty_id)
+                .collect::<Vec<T::BountyId>>();
+            for bounty_id in expiring {
+                if let Some(bounty) = <Bounties<T>>::get(bounty_id) {
+                    if let Ok((to_contributors, remainder)) = Self::execute_refund(bounty_id, &bounty.depositer()) {
+                        Self::recursive_remove_bounty(bounty_id);
+                        Self::deposit_event(RawEvent::BountyExpired(bounty_id, to_contributors, remainder));
+                    } else {
+                        // a failed refund changes nothing so it is retried later
+                        let retry = n + T::RefundRetryPeriod::get();
+                        <BountyExpiry<T>>::insert(bounty_id, retry);
+                        <ExpiringBounties<T>>::insert(retry, bounty_id, ());
+                        Self::deposit_event(RawEvent::BountyExpiredButRefundFailed(bounty_id, bounty.total(), retry));
+                    }
+                }
+            }
+            <ExpiringBounties<T>>::remove_prefix(n);
+        }
     }
 }
 
@@ -296,12 +634,12 @@ impl<T: Trait> Module<T> {
     fn bounty_id_is_available(id: T::BountyId) -> bool {
         <Bounties<T>>::get(id).is_none()
     }
-    fn bounty_generate_uid() -> T::BountyId {
+    /// Returns the next available bounty identifier without claiming it
+    fn next_bounty_uid() -> T::BountyId {
         let mut id_counter = <BountyNonce<T>>::get() + 1u32.into();
         while !Self::bounty_id_is_available(id_counter) {
             id_counter += 1u32.into();
         }
-        <BountyNonce<T>>::put(id_counter);
         id_counter
     }
     fn submission_id_is_available(id: T::SubmissionId) -> bool {
@@ -315,10 +653,301 @@ impl<T: Trait> Module<T> {
         <SubmissionNonce<T>>::put(id_counter);
         id_counter
     }
-    fn _recursive_remove_bounty(id: T::BountyId) {
+    /// Withdraws the bounty amount from the depositer and stores the new bounty
+    fn create_bounty(
+        depositer: T::AccountId,
+        issue: EncodedIssue,
+        info: T::IpfsReference,
+        currency_id: CurrencyIdOf<T>,
+        amount: BalanceOf<T>,
+        expiry: Option<T::BlockNumber>,
+        liveness: ExistenceRequirement,
+    ) -> Result<T::BountyId, DispatchError> {
+        ensure!(
+            <IssueHashSet>::get(issue.clone()).is_none(),
+            Error::<T>::IssueAlreadyClaimedForBountyOrSubmission
+        );
+        ensure!(
+            amount >= <T as Trait>::MinDeposit::get(),
+            Error::<T>::BountyPostMustExceedMinDeposit
+        );
+        if let Some(expiry) = expiry {
+            ensure!(
+                expiry > <frame_system::Module<T>>::block_number(),
+                Error::<T>::BountyExpiryMustBeInTheFuture
+            );
+        }
+        // the identifier is claimed only once the funds are transferred
+        let id = Self::next_bounty_uid();
+        <T as Trait>::Currency::transfer(
+            currency_id,
+            &depositer,
+            &Self::bounty_account_id(id),
+            amount,
+            liveness,
+        )?;
+        <BountyNonce<T>>::put(id);
+        let bounty =
+            Bounty::<T>::new(id, info.clone(), depositer.clone(), amount);
+        <BountyIssue<T>>::insert(id, &issue);
+        <IssueHashSet>::insert(issue, ());
+        <Bounties<T>>::insert(id, bounty);
+        <BountyCurrency<T>>::insert(id, currency_id);
+        <Contributions<T>>::insert(
+            id,
+            &depositer,
+            Contrib::<T>::new(id, depositer.clone(), amount),
+        );
+        <ContributionsByAccount<T>>::insert(&depositer, id, ());
+        <ContributorCount<T>>::insert(id, 1);
+        Self::deposit_event(RawEvent::BountyPosted(
+            depositer,
+            currency_id,
+            amount,
+            id,
+            info,
+        ));
+        if let Some(expiry) = expiry {
+            <BountyExpiry<T>>::insert(id, expiry);
+            <ExpiringBounties<T>>::insert(expiry, id, ());
+            Self::deposit_event(RawEvent::BountyExpirySet(id, expiry));
+        }
+        Ok(id)
+    }
+    /// The org itself, its supervisor and the bank controller act for the org on bounties funded by the bank
+    /// -> returns the signer, or the bank account if the org itself calls
+    fn ensure_bank_sudo(
+        origin: T::Origin,
+        bank_id: T::BankId,
+        err: Error<T>,
+    ) -> Result<T::AccountId, DispatchError> {
+        let bank = match <bank::Module<T>>::banks(bank_id) {
+            Some(bank) => bank,
+            None => return Err(err.into()),
+        };
+        match org::EnsureOrgSupervisor::<T>::try_org_origin(origin, bank.org())
+        {
+            Ok(Some(supervisor)) => Ok(supervisor),
+            Ok(None) => Ok(<bank::Module<T>>::bank_account_id(bank_id)),
+            Err(origin) => {
+                let caller = ensure_signed(origin)?;
+                ensure!(bank.is_controller(&caller), err);
+                Ok(caller)
+            }
+        }
+    }
+    /// Approves, rejects and cancels on behalf of the depositer
+    fn ensure_approver(
+        origin: T::Origin,
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        err: Error<T>,
+    ) -> Result<T::AccountId, DispatchError> {
+        if let Some(bank_id) = <OrgBounties<T>>::get(bounty_id) {
+            Self::ensure_bank_sudo(origin, bank_id, err)
+        } else {
+            let caller = ensure_signed(origin)?;
+            ensure!(bounty.depositer() == caller, err);
+            Ok(caller)
+        }
+    }
+    /// Payouts from org bounties above the bank's vote limit must go through `trigger_approval_vote`
+    /// -> the org itself approves as the bank account without the limit
+    fn ensure_below_bank_vote_limit(
+        bounty_id: T::BountyId,
+        approver: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        if let Some(bank_id) = <OrgBounties<T>>::get(bounty_id) {
+            if approver != &<bank::Module<T>>::bank_account_id(bank_id) {
+                <bank::Module<T>>::ensure_below_vote_limit(bank_id, amount)?;
+            }
+        }
+        Ok(())
+    }
+    /// The member count of the org that votes on the submission, used for weighing calls
+    fn submission_org_size(id: T::SubmissionId) -> u32 {
+        <Submissions<T>>::get(id)
+            .and_then(|sub| <OrgBounties<T>>::get(sub.bounty_id()))
+            .and_then(|bank_id| <bank::Module<T>>::banks(bank_id))
+            .map(|bank| <org::Module<T>>::member_count(bank.org()))
+            .unwrap_or_default()
+    }
+    /// Open bounties with at least `min` total funding
+    pub fn open_bounties(min: BalanceOf<T>) -> Vec<(T::BountyId, Bounty<T>)> {
+        <Bounties<T>>::iter()
+            .filter(|(_, bounty)| bounty.total() >= min)
+            .collect()
+    }
+    /// Submissions posted for the bounty
+    pub fn bounty_submissions(
+        bounty_id: T::BountyId,
+    ) -> Vec<(T::SubmissionId, BountySub<T>)> {
+        <SubmissionsByBounty<T>>::iter_prefix(bounty_id)
+            .filter_map(|(id, _)| <Submissions<T>>::get(id).map(|sub| (id, sub)))
+            .collect()
+    }
+    /// Refunds the bounty's balance to contributors in proportion to their contributions
+    /// -> remainder recipient should be the depositer
+    /// -> every transfer is checked before any is made so a failed refund changes nothing
+    fn execute_refund(
+        id: T::BountyId,
+        remainder_recipient: &T::AccountId,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        let from = Self::bounty_account_id(id);
+        let currency_id = <BountyCurrency<T>>::get(id);
+        let available =
+            <T as Trait>::Currency::free_balance(currency_id, &from);
+        let contributors: WeightedVector<T::AccountId, BalanceOf<T>> =
+            <Contributions<T>>::iter_prefix(id)
+                .map(|(account, contrib)| (account, contrib.total()))
+                .collect::<Vec<(T::AccountId, BalanceOf<T>)>>()
+                .into();
+        let den = contributors.total();
+        let min = <T as Trait>::Currency::minimum_balance(currency_id);
+        let mut remainder = available;
+        let mut total_to_contributors = BalanceOf::<T>::zero();
+        let mut payouts = Vec::<(T::AccountId, BalanceOf<T>)>::new();
+        for (acc, nom) in contributors.vec().iter() {
+            let due_amount = Permill::from_rational_approximation(*nom, den)
+                .mul_floor(available);
+            // shares that cannot create the contributor's account stay in the remainder
+            if due_amount.is_zero()
+                || <T as Trait>::Currency::total_balance(currency_id, acc)
+                    .saturating_add(due_amount)
+                    < min
+            {
+                continue
+            }
+            remainder -= due_amount;
+            total_to_contributors += due_amount;
+            payouts.push((acc.clone(), due_amount));
+        }
+        // the remainder recipient must end above the existential deposit and
+        // the bounty account must not be reaped before the last transfer
+        let remainder_payout = (remainder_recipient.clone(), remainder);
+        let mut left = available;
+        for (acc, amount) in payouts.iter().chain(Some(&remainder_payout)) {
+            ensure!(
+                amount.is_zero()
+                    || <T as Trait>::Currency::total_balance(currency_id, acc)
+                        .saturating_add(*amount)
+                        >= min,
+                Error::<T>::RefundBelowExistentialDeposit
+            );
+            left -= *amount;
+            ensure!(
+                left.is_zero() || left >= min,
+                Error::<T>::RefundBelowExistentialDeposit
+            );
+        }
+        for (acc, amount) in payouts {
+            <T as Trait>::Currency::transfer(
+                currency_id,
+                &from,
+                &acc,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            Self::deposit_event(RawEvent::ContributionRefunded(
+                id, acc, amount,
+            ));
+        }
+        // send remainder
+        <T as Trait>::Currency::transfer(
+            currency_id,
+            &from,
+            remainder_recipient,
+            remainder,
+            ExistenceRequirement::AllowDeath,
+        )?;
+        Ok((total_to_contributors, remainder))
+    }
+    /// Pays `amount` to the submitter from the bounty and returns the updated bounty
+    /// -> the submission is kept as partially paid until its full amount is paid out
+    fn pay_submission(
+        bounty: Bounty<T>,
+        submission_id: T::SubmissionId,
+        submission: BountySub<T>,
+        amount: BalanceOf<T>,
+    ) -> Result<Bounty<T>, DispatchError> {
+        let bounty_id = bounty.id();
+        <T as Trait>::Currency::transfer(
+            <BountyCurrency<T>>::get(bounty_id),
+            &Self::bounty_account_id(bounty_id),
+            &submission.submitter(),
+            amount,
+            ExistenceRequirement::AllowDeath,
+        )?;
+        let new_bounty = bounty.subtract_total(amount);
+        // direct approval supersedes any open approval vote
+        <ApprovalVotes<T>>::remove(submission_id);
+        if amount < submission.amount() {
+            let paid = submission
+                .pay_out_amount(amount)
+                .set_state(SubmissionState::PartiallyPaid);
+            <Submissions<T>>::insert(submission_id, paid);
+        } else {
+            // paid in full => can be removed, but its issue stays claimed
+            <Submissions<T>>::remove(submission_id);
+            <SubmissionsByBounty<T>>::remove(bounty_id, submission_id);
+            <SubmissionIssue<T>>::remove(submission_id);
+        }
+        <Bounties<T>>::insert(bounty_id, new_bounty.clone());
+        Self::deposit_event(RawEvent::BountyPaymentExecuted(
+            bounty_id,
+            new_bounty.total(),
+            submission_id,
+            amount,
+            submission.submitter(),
+            new_bounty.info(),
+            submission.submission(),
+        ));
+        Ok(new_bounty)
+    }
+    /// Pays a submission approved by org vote
+    /// -> the amount is capped in case the submission or bounty changed during the vote
+    fn execute_approval_vote(
+        submission_id: T::SubmissionId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let submission = <Submissions<T>>::get(submission_id)
+            .ok_or(Error::<T>::SubmissionDNE)?;
+        let bounty = <Bounties<T>>::get(submission.bounty_id())
+            .ok_or(Error::<T>::BountyDNE)?;
+        let amount = amount.min(submission.amount()).min(bounty.total());
+        ensure!(!amount.is_zero(), Error::<T>::PayoutMustBeNonZero);
+        Self::pay_submission(bounty, submission_id, submission, amount)?;
+        Ok(())
+    }
+    /// Removes a submission that was not approved and releases its issue
+    fn remove_submission(bounty_id: T::BountyId, submission_id: T::SubmissionId) {
+        <Submissions<T>>::remove(submission_id);
+        <SubmissionsByBounty<T>>::remove(bounty_id, submission_id);
+        <ApprovalVotes<T>>::remove(submission_id);
+        if let Some(issue) = <SubmissionIssue<T>>::take(submission_id) {
+            <IssueHashSet>::remove(issue);
+        }
+    }
+    fn recursive_remove_bounty(id: T::BountyId) {
         <Bounties<T>>::remove(id);
-        <Submissions<T>>::iter()
-            .filter(|(_, app)| app.bounty_id() == id)
-            .for_each(|(app_id, _)| <Submissions<T>>::remove(app_id));
+        <BountyCurrency<T>>::remove(id);
+        <OrgBounties<T>>::remove(id);
+        <ContributorCount<T>>::remove(id);
+        if let Some(issue) = <BountyIssue<T>>::take(id) {
+            <IssueHashSet>::remove(issue);
+        }
+        if let Some(expiry) = <BountyExpiry<T>>::take(id) {
+            <ExpiringBounties<T>>::remove(expiry, id);
+        }
+        <SubmissionsByBounty<T>>::iter_prefix(id)
+            .map(|(sub_id, _)| sub_id)
+            .collect::<Vec<T::SubmissionId>>()
+            .into_iter()
+            .for_each(|sub_id| Self::remove_submission(id, sub_id));
+        <Contributions<T>>::iter_prefix(id).for_each(|(account, _)| {
+            <ContributionsByAccount<T>>::remove(account, id)
+        });
+        <Contributions<T>>::remove_prefix(id);
     }
 }
@@ -5,6 +5,12 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::{
+        Currency,
+        LockableCurrency,
+        OnFinalize,
+        WithdrawReasons,
+    },
     weights::Weight,
 };
 use frame_system::{self as system,};
@@ -18,13 +24,25 @@ use sp_runtime::{
     traits::IdentityLookup,
     Perbill,
 };
+use util::{
+    bank::SpendCap,
+    organization::OrgRep,
+    vote::{
+        Threshold,
+        ThresholdInput,
+        VoterView,
+        XorThreshold,
+    },
+};
 
 // type aliases
 pub type AccountId = u64;
 pub type BlockNumber = u64;
 
 impl_outer_origin! {
-    pub enum Origin for Test where system = frame_system {}
+    pub enum Origin for Test where system = frame_system {
+        org<T>,
+    }
 }
 
 mod bounty {
@@ -35,6 +53,11 @@ impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
         pallet_balances<T>,
+        tokens<T>,
+        org<T>,
+        vote<T>,
+        donate<T>,
+        bank<T>,
         bounty<T>,
     }
 }
@@ -85,23 +108,109 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const NativeCurrencyId: u32 = 0;
+}
+impl tokens::Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type CurrencyId = u32;
+    type NativeCurrency = Balances;
+    type GetNativeCurrencyId = NativeCurrencyId;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: u64 = 5;
+    pub const SupervisorTermLength: u64 = 10;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
+impl org::Trait for Test {
+    type Event = TestEvent;
+    type Cid = u32;
+    type OrgId = u64;
+    type Shares = u64;
+    type OrgOrigin = org::EnsureOrg<Test>;
+    type ElectionId = u64;
+    type SupervisorVote = vote::Module<Test>;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const MaxDelegators: u32 = 3;
+    pub const MaxOptions: u32 = 5;
+    pub const VoteRetentionPeriod: Option<u64> = None;
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type VoteId = u64;
+    type Signal = u64;
+    type ThresholdId = u64;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type MaxDelegators = MaxDelegators;
+    type MaxOptions = MaxOptions;
+    type VoteRetentionPeriod = VoteRetentionPeriod;
+    type WeightInfo = ();
+}
+impl donate::Trait for Test {
+    type Event = TestEvent;
+    type Currency = Tokens;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const BigBank: ModuleId = ModuleId(*b"big/bank");
+    pub const MaxTreasuryPerOrg: u32 = 50;
+    pub const MinBankDeposit: u64 = 20;
+    pub const SpendExpiry: u64 = 100;
+    pub const SpendVoteDuration: u64 = 10;
+    pub const MaxBankProposals: u32 = 2;
+}
+impl bank::Trait for Test {
+    type Event = TestEvent;
+    type Currency = Tokens;
+    type BigBank = BigBank;
+    type BankId = u64;
+    type SpendId = u64;
+    type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
+    type MinDeposit = MinBankDeposit;
+    type SpendExpiry = SpendExpiry;
+    type SpendVoteDuration = SpendVoteDuration;
+    type MaxBankProposals = MaxBankProposals;
+    type WeightInfo = ();
+}
 parameter_types! {
     pub const Foundation: ModuleId = ModuleId(*b"fundacon");
     pub const MinDeposit: u64 = 10;
     pub const MinContribution: u64 = 5;
+    pub const ApprovalVoteDuration: u64 = 3;
+    pub const RefundRetryPeriod: u64 = 2;
 }
 impl Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32;
-    type Currency = Balances;
+    type Currency = Tokens;
     type BountyId = u64;
     type SubmissionId = u64;
     type Foundation = Foundation;
     type MinDeposit = MinDeposit;
     type MinContribution = MinContribution;
+    type ApprovalVoteDuration = ApprovalVoteDuration;
+    type RefundRetryPeriod = RefundRetryPeriod;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = tokens::Module<Test>;
+pub type Vote = vote::Module<Test>;
+pub type Bank = bank::Module<Test>;
 pub type Bounty = Module<Test>;
 
 fn random(output_len: usize) -> Vec<u8> {
@@ -110,7 +219,8 @@ fn random(output_len: usize) -> Vec<u8> {
     buf
 }
 
-fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64> {
+fn get_last_event(
+) -> RawEvent<u64, u32, u64, u64, u64, u64, u64, u64, u32, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -125,6 +235,15 @@ fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64> {
         .unwrap()
 }
 
+/// Auxiliary method for simulating block time passing
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Bounty::on_finalize(System::block_number());
+        Vote::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+    }
+}
+
 fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::default()
         .build_storage::<Test>()
@@ -134,6 +253,23 @@ fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    tokens::GenesisConfig::<Test> {
+        endowed_accounts: vec![(1, 1, 100), (3, 1, 50)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    org::GenesisConfig::<Test> {
+        first_organization_supervisor: 1,
+        first_organization_value_constitution: 1738,
+        first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    bank::GenesisConfig::<Test> {
+        spend_poll_frequency: 10,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     let mut ext: sp_io::TestExternalities = t.into();
     ext.execute_with(|| System::set_block_number(1));
     ext
@@ -154,7 +290,9 @@ fn post_bounty_works() {
                 Origin::signed(1),
                 random(10),
                 10u32, // cid
+                0,     // asset
                 9,     // amount
+                None,  // expiry
             ),
             Error::<Test>::BountyPostMustExceedMinDeposit,
         );
@@ -163,7 +301,9 @@ fn post_bounty_works() {
                 Origin::signed(1),
                 random(10),
                 10u32, // cid
+                0,     // asset
                 101,   // amount
+                None,  // expiry
             ),
             sp_runtime::DispatchError::Module {
                 index: 0,
@@ -176,15 +316,19 @@ fn post_bounty_works() {
             Origin::signed(1),
             issue_hash.clone(),
             10u32, // constitution
+            0,     // asset
             10,    // funding reserved
+            None,  // expiry
         ));
-        assert_eq!(RawEvent::BountyPosted(1, 10, 1, 10), get_last_event());
+        assert_eq!(RawEvent::BountyPosted(1, 0, 10, 1, 10), get_last_event());
         assert_noop!(
             Bounty::post_bounty(
                 Origin::signed(1),
                 issue_hash,
                 10u32, // constitution
+                0,     // asset
                 10,    // funding reserved
+                None,  // expiry
             ),
             Error::<Test>::IssueAlreadyClaimedForBountyOrSubmission
         );
@@ -198,7 +342,9 @@ fn contribution_works() {
             Origin::signed(1),
             random(10),
             10u32, // constitution
+            0,     // asset
             10,    // funding reserved
+            None,  // expiry
         ));
         assert_noop!(
             Bounty::contribute_to_bounty(Origin::signed(2), 2, 5),
@@ -241,7 +387,9 @@ fn submission_works() {
             Origin::signed(1),
             random(10),
             10u32, // constitution
+            0,     // asset
             21,    // funding reserved
+            None,  // expiry
         ));
         assert_noop!(
             Bounty::submit_for_bounty(
@@ -292,17 +440,19 @@ fn submission_works() {
 fn submission_approval_works() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            Bounty::approve_bounty_submission(Origin::signed(1), 1),
+            Bounty::approve_bounty_submission(Origin::signed(1), 1, None),
             Error::<Test>::SubmissionDNE
         );
         assert_ok!(Bounty::post_bounty(
             Origin::signed(1),
             random(10),
             10u32, // constitution
+            0,     // asset
             21,    // funding reserved
+            None,  // expiry
         ));
         assert_noop!(
-            Bounty::approve_bounty_submission(Origin::signed(1), 1),
+            Bounty::approve_bounty_submission(Origin::signed(1), 1, None),
             Error::<Test>::SubmissionDNE
         );
         assert_ok!(Bounty::submit_for_bounty(
@@ -313,13 +463,730 @@ fn submission_approval_works() {
             10u64,
         ));
         assert_noop!(
-            Bounty::approve_bounty_submission(Origin::signed(2), 1),
+            Bounty::approve_bounty_submission(Origin::signed(2), 1, None),
             Error::<Test>::NotAuthorizedToApproveBountySubmissions
         );
         assert_eq!(Balances::total_balance(&2), 98);
         assert_eq!(Balances::total_balance(&1), 79);
-        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1, None));
         assert_eq!(Balances::total_balance(&2), 108);
         assert_eq!(Balances::total_balance(&1), 79);
     });
 }
+
+#[test]
+fn bounty_queries_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            0,     // asset
+            10,    // funding reserved
+            None,  // expiry
+        ));
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            0,     // asset
+            30,    // funding reserved
+            None,  // expiry
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            2,
+            random(10),
+            10u32,
+            10u64,
+        ));
+        assert_eq!(Bounty::open_bounties(10).len(), 2);
+        let open = Bounty::open_bounties(20);
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].0, 2);
+        assert!(Bounty::bounty_submissions(1).is_empty());
+        let subs = Bounty::bounty_submissions(2);
+        assert_eq!(subs.len(), 1);
+        assert_eq!(subs[0].0, 1);
+        assert_eq!(subs[0].1.bounty_id(), 2);
+    });
+}
+
+#[test]
+fn indices_track_submissions_and_contributions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            0,     // asset
+            21,    // funding reserved
+            None,  // expiry
+        ));
+        assert!(Bounty::contributions_by_account(1, 1).is_some());
+        assert!(Bounty::contributions_by_account(2, 1).is_none());
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 5));
+        assert!(Bounty::contributions_by_account(2, 1).is_some());
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            random(10),
+            10u32,
+            10u64,
+        ));
+        assert!(Bounty::submissions_by_bounty(1, 1).is_some());
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1, None));
+        assert!(Bounty::submissions_by_bounty(1, 1).is_none());
+        assert!(Bounty::bounty_submissions(1).is_empty());
+    });
+}
+
+#[test]
+fn cancel_bounty_refunds_contributors() {
+    new_test_ext().execute_with(|| {
+        let issue = random(10);
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            issue.clone(),
+            10u32, // constitution
+            0,     // asset
+            20,    // funding reserved
+            None,  // expiry
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 20));
+        assert_eq!(Bounty::contributor_count(1), 2);
+        assert_noop!(
+            Bounty::cancel_bounty(Origin::signed(2), 1),
+            Error::<Test>::NotAuthorizedToCancelBounty
+        );
+        assert_ok!(Bounty::cancel_bounty(Origin::signed(1), 1));
+        assert_eq!(RawEvent::BountyCancelled(1, 1, 40, 0), get_last_event());
+        assert_eq!(Balances::total_balance(&1), 100);
+        assert_eq!(Balances::total_balance(&2), 98);
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::contributions(1, 2).is_none());
+        assert!(Bounty::contributions_by_account(2, 1).is_none());
+        assert_eq!(Bounty::contributor_count(1), 0);
+        // the issue may be posted again
+        assert!(Bounty::bounty_issue(1).is_none());
+        assert!(Bounty::issue_hash_set(issue.clone()).is_none());
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            issue,
+            10u32, // constitution
+            0,     // asset
+            20,    // funding reserved
+            None,  // expiry
+        ));
+    });
+}
+
+#[test]
+fn expired_bounty_refunds_remaining_funds_pro_rata() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                random(10),
+                10u32,   // constitution
+                0,       // asset
+                20,      // funding reserved
+                Some(1), // expiry
+            ),
+            Error::<Test>::BountyExpiryMustBeInTheFuture
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32,   // constitution
+            0,       // asset
+            20,      // funding reserved
+            Some(5), // expiry
+        ));
+        assert_eq!(RawEvent::BountyExpirySet(1, 5), get_last_event());
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 20));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            10u32,
+            20u64,
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1, None));
+        run_to_block(5);
+        assert!(Bounty::bounties(1).is_some());
+        run_to_block(6);
+        // the remaining 20 is split evenly between both contributors
+        assert_eq!(RawEvent::BountyExpired(1, 20, 0), get_last_event());
+        assert_eq!(Balances::total_balance(&1), 90);
+        assert_eq!(Balances::total_balance(&3), 190);
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::bounty_expiry(1).is_none());
+        assert!(Bounty::expiring_bounties(5, 1).is_none());
+    });
+}
+
+#[test]
+fn failed_expiry_refund_is_retried_later() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32,   // constitution
+            0,       // asset
+            20,      // funding reserved
+            Some(5), // expiry
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 20));
+        // funds that cannot leave the bounty account fail the whole refund
+        let bounty_account = Bounty::bounty_account_id(1);
+        Balances::set_lock(
+            *b"refundlk",
+            &bounty_account,
+            40,
+            WithdrawReasons::all(),
+        );
+        run_to_block(6);
+        assert_eq!(
+            RawEvent::BountyExpiredButRefundFailed(1, 40, 7),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&1), 80);
+        assert_eq!(Balances::total_balance(&3), 180);
+        assert_eq!(Bounty::bounty_expiry(1), Some(7));
+        assert!(Bounty::expiring_bounties(5, 1).is_none());
+        assert!(Bounty::expiring_bounties(7, 1).is_some());
+        Balances::remove_lock(*b"refundlk", &bounty_account);
+        run_to_block(8);
+        assert_eq!(RawEvent::BountyExpired(1, 40, 0), get_last_event());
+        assert_eq!(Balances::total_balance(&1), 100);
+        assert_eq!(Balances::total_balance(&3), 200);
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::expiring_bounties(7, 1).is_none());
+    });
+}
+
+#[test]
+fn submission_rejection_releases_issue() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            0,     // asset
+            21,    // funding reserved
+            None,  // expiry
+        ));
+        let issue_hash = random(10);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            issue_hash.clone(),
+            10u32,
+            10u64,
+        ));
+        assert_noop!(
+            Bounty::reject_submission(Origin::signed(2), 1, None),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_submission(Origin::signed(1), 1, Some(11)));
+        assert_eq!(
+            RawEvent::SubmissionRejected(1, 1, 1, Some(11)),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::submissions_by_bounty(1, 1).is_none());
+        assert!(Bounty::issue_hash_set(issue_hash.clone()).is_none());
+        // the released issue can be submitted again
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            issue_hash,
+            10u32,
+            10u64,
+        ));
+    });
+}
+
+#[test]
+fn submitter_can_withdraw_and_update_submission() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            0,     // asset
+            21,    // funding reserved
+            None,  // expiry
+        ));
+        let issue_hash = random(10);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            issue_hash.clone(),
+            10u32,
+            10u64,
+        ));
+        assert_noop!(
+            Bounty::update_submission(Origin::signed(3), 1, 12u32, 15u64),
+            Error::<Test>::NotAuthorizedToUpdateBountySubmission
+        );
+        assert_noop!(
+            Bounty::update_submission(Origin::signed(2), 1, 12u32, 22u64),
+            Error::<Test>::BountySubmissionExceedsTotalAvailableFunding
+        );
+        assert_ok!(Bounty::update_submission(
+            Origin::signed(2),
+            1,
+            12u32,
+            15u64
+        ));
+        assert_eq!(
+            RawEvent::SubmissionUpdated(2, 1, 1, 15, 12),
+            get_last_event()
+        );
+        let submission = Bounty::submissions(1).unwrap();
+        assert_eq!(submission.submission(), 12);
+        assert_eq!(submission.amount(), 15);
+        assert_noop!(
+            Bounty::withdraw_submission(Origin::signed(1), 1),
+            Error::<Test>::NotAuthorizedToWithdrawBountySubmission
+        );
+        assert_ok!(Bounty::withdraw_submission(Origin::signed(2), 1));
+        assert_eq!(RawEvent::SubmissionWithdrawn(2, 1, 1), get_last_event());
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::issue_hash_set(issue_hash).is_none());
+    });
+}
+
+#[test]
+fn partial_approval_keeps_submission_partially_paid() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            0,     // asset
+            30,    // funding reserved
+            None,  // expiry
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            10u32,
+            20u64,
+        ));
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1, Some(21)),
+            Error::<Test>::ApprovedAmountExceedsAmountRequested
+        );
+        assert_ok!(Bounty::approve_bounty_submission(
+            Origin::signed(1),
+            1,
+            Some(5)
+        ));
+        assert_eq!(
+            RawEvent::BountyPaymentExecuted(1, 25, 1, 5, 2, 10, 10),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&2), 103);
+        let submission = Bounty::submissions(1).unwrap();
+        assert_eq!(submission.amount(), 15);
+        assert_eq!(submission.state(), SubmissionState::PartiallyPaid);
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1, Some(16)),
+            Error::<Test>::ApprovedAmountExceedsAmountRequested
+        );
+        assert_ok!(Bounty::approve_bounty_submission(
+            Origin::signed(1),
+            1,
+            None
+        ));
+        assert_eq!(Balances::total_balance(&2), 118);
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 10);
+    });
+}
+
+#[test]
+fn approve_split_pays_several_submissions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            0,     // asset
+            40,    // funding reserved
+            None,  // expiry
+        ));
+        for (submitter, amount) in vec![(2, 20u64), (3, 20u64), (4, 5u64)] {
+            assert_ok!(Bounty::submit_for_bounty(
+                Origin::signed(submitter),
+                1,
+                random(10),
+                10u32,
+                amount,
+            ));
+        }
+        let (half, quarter) =
+            (Permill::from_percent(50), Permill::from_percent(25));
+        assert_noop!(
+            Bounty::approve_split(Origin::signed(2), 1, vec![(1, half)]),
+            Error::<Test>::NotAuthorizedToApproveBountySubmissions
+        );
+        assert_noop!(
+            Bounty::approve_split(
+                Origin::signed(1),
+                1,
+                vec![(1, quarter), (1, quarter)]
+            ),
+            Error::<Test>::DuplicateSubmissionInSplit
+        );
+        assert_noop!(
+            Bounty::approve_split(
+                Origin::signed(1),
+                1,
+                vec![(1, Permill::from_percent(60)), (2, half)]
+            ),
+            Error::<Test>::SplitExceedsBountyTotal
+        );
+        assert_ok!(Bounty::approve_split(
+            Origin::signed(1),
+            1,
+            vec![(1, half), (2, quarter), (3, quarter)]
+        ));
+        // the third payout is capped by the amount requested
+        assert_eq!(Balances::total_balance(&2), 118);
+        assert_eq!(Balances::total_balance(&3), 210);
+        assert_eq!(Balances::total_balance(&4), 80);
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Bounty::submissions(2).unwrap().amount(), 10);
+        assert!(Bounty::submissions(3).is_none());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 5);
+    });
+}
+
+/// Opens a bank for org 1 that approves by majority and rejects by half
+fn open_org_bank() {
+    let threshold = ThresholdInput::new(
+        OrgRep::Equal(1),
+        XorThreshold::Percent(Threshold::new(
+            Permill::from_percent(50),
+            Some(Permill::from_percent(50)),
+        )),
+    );
+    assert_ok!(Bank::open(Origin::signed(3), 1, 0, 100, None, threshold));
+}
+
+#[test]
+fn org_supervisor_posts_and_approves_org_bounty() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_org_bounty(
+                Origin::signed(1),
+                1,
+                random(10),
+                10u32, // cid
+                20,    // amount
+                None,  // expiry
+            ),
+            Error::<Test>::BankMustExistToPostOrgBounty
+        );
+        open_org_bank();
+        assert_noop!(
+            Bounty::post_org_bounty(
+                Origin::signed(2),
+                1,
+                random(10),
+                10u32, // cid
+                20,    // amount
+                None,  // expiry
+            ),
+            Error::<Test>::NotAuthorizedToPostBountyForBank
+        );
+        // 1 is the org supervisor
+        assert_ok!(Bounty::post_org_bounty(
+            Origin::signed(1),
+            1,
+            random(10),
+            10u32, // cid
+            20,    // amount
+            None,  // expiry
+        ));
+        assert_eq!(RawEvent::OrgBountyPosted(1, 1, 1, 1), get_last_event());
+        let bank_account = Bank::bank_account_id(1);
+        assert_eq!(Bounty::bounties(1).unwrap().depositer(), bank_account);
+        assert_eq!(Balances::total_balance(&bank_account), 80);
+        assert_eq!(Balances::total_balance(&1), 100);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            15u32, // cid
+            10,    // amount
+        ));
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(2), 1, None),
+            Error::<Test>::NotAuthorizedToApproveBountySubmissions
+        );
+        assert_noop!(
+            Bounty::approve_bounty_submission(
+                org::RawOrigin::Org(2).into(),
+                1,
+                None
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Bounty::approve_bounty_submission(
+            Origin::signed(1),
+            1,
+            None
+        ));
+        assert_eq!(Balances::total_balance(&2), 108);
+        // cancelling refunds the rest to the bank
+        assert_ok!(Bounty::cancel_bounty(Origin::signed(1), 1));
+        assert_eq!(Balances::total_balance(&bank_account), 90);
+        assert!(Bounty::org_bounties(1).is_none());
+    });
+}
+
+#[test]
+fn org_bounty_funding_and_approval_respect_bank_limits() {
+    new_test_ext().execute_with(|| {
+        open_org_bank();
+        assert_ok!(Bank::set_spend_policy(
+            org::RawOrigin::Org(1).into(),
+            1,
+            Some(SpendCap::new(30, 10)),
+            Some(25)
+        ));
+        assert_noop!(
+            Bounty::post_org_bounty(
+                Origin::signed(1),
+                1,
+                random(10),
+                10u32, // cid
+                30,    // amount
+                None,  // expiry
+            ),
+            bank::Error::<Test>::SpendAboveLimitRequiresOrgVote
+        );
+        assert_ok!(Bounty::post_org_bounty(
+            Origin::signed(1),
+            1,
+            random(10),
+            10u32, // cid
+            20,    // amount
+            None,  // expiry
+        ));
+        assert_noop!(
+            Bounty::post_org_bounty(
+                Origin::signed(1),
+                1,
+                random(10),
+                10u32, // cid
+                20,    // amount
+                None,  // expiry
+            ),
+            bank::Error::<Test>::ControllerSpendCapExceeded
+        );
+        // the org itself posts without the controller limits
+        assert_ok!(Bounty::post_org_bounty(
+            org::RawOrigin::Org(1).into(),
+            1,
+            random(10),
+            10u32, // cid
+            30,    // amount
+            None,  // expiry
+        ));
+        let bank_account = Bank::bank_account_id(1);
+        assert_eq!(
+            RawEvent::OrgBountyPosted(bank_account, 1, 1, 2),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&bank_account), 50);
+        // payouts above the limit need an approval vote
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(4), 1, 10));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            15u32, // cid
+            30,    // amount
+        ));
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1, None),
+            bank::Error::<Test>::SpendAboveLimitRequiresOrgVote
+        );
+        assert_noop!(
+            Bounty::approve_split(
+                Origin::signed(1),
+                1,
+                vec![(1, Permill::one())]
+            ),
+            bank::Error::<Test>::SpendAboveLimitRequiresOrgVote
+        );
+        assert_ok!(Bounty::approve_bounty_submission(
+            Origin::signed(1),
+            1,
+            Some(20)
+        ));
+        assert_eq!(Balances::total_balance(&2), 118);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            2,
+            random(10),
+            16u32, // cid
+            30,    // amount
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(
+            org::RawOrigin::Org(1).into(),
+            2,
+            None
+        ));
+        assert_eq!(Balances::total_balance(&4), 95);
+    });
+}
+
+#[test]
+fn org_vote_approves_or_rejects_org_bounty_submissions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // cid
+            0,     // asset
+            10,    // amount
+            None,  // expiry
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            15u32, // cid
+            10,    // amount
+        ));
+        assert_noop!(
+            Bounty::trigger_approval_vote(Origin::signed(3), 1, None),
+            Error::<Test>::ApprovalVoteOnlyForOrgBounties
+        );
+        open_org_bank();
+        assert_ok!(Bounty::post_org_bounty(
+            Origin::signed(1),
+            1,
+            random(10),
+            10u32, // cid
+            40,    // amount
+            None,  // expiry
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            2,
+            random(10),
+            15u32, // cid
+            30,    // amount
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            2,
+            random(10),
+            16u32, // cid
+            10,    // amount
+        ));
+        assert_noop!(
+            Bounty::trigger_approval_vote(Origin::signed(7), 2, None),
+            Error::<Test>::NotAuthorizedToTriggerApprovalVote
+        );
+        // org members vote to pay 20 of the 30 requested
+        assert_ok!(Bounty::trigger_approval_vote(Origin::signed(3), 2, Some(20)));
+        assert_eq!(
+            RawEvent::SubmissionApprovalVoteTriggered(3, 2, 2, 20, 1),
+            get_last_event()
+        );
+        assert_noop!(
+            Bounty::trigger_approval_vote(Origin::signed(3), 2, None),
+            Error::<Test>::SubmissionUnderApprovalVote
+        );
+        assert_noop!(
+            Bounty::update_submission(Origin::signed(2), 2, 17u32, 25),
+            Error::<Test>::SubmissionUnderApprovalVote
+        );
+        assert_ok!(Bounty::trigger_approval_vote(Origin::signed(3), 3, None));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None
+            ));
+        }
+        // the votes are settled once they end
+        run_to_block(4);
+        assert!(Bounty::approval_votes(2).is_some());
+        assert_eq!(Bounty::approval_vote_endings(4, 2), Some(()));
+        run_to_block(5);
+        assert_eq!(Balances::total_balance(&2), 118);
+        assert_eq!(
+            Bounty::submissions(2).unwrap().state(),
+            SubmissionState::PartiallyPaid
+        );
+        assert!(Bounty::approval_votes(2).is_none());
+        assert!(System::events().into_iter().any(|r| r.event
+            == TestEvent::bounty(RawEvent::SubmissionRejectedByVote(2, 3, 2))));
+        assert!(Bounty::submissions(3).is_none());
+        assert!(Bounty::approval_votes(3).is_none());
+        assert!(Bounty::approval_vote_endings(4, 2).is_none());
+        assert_eq!(Balances::total_balance(&4), 75);
+    });
+}
+
+#[test]
+fn bounty_funded_and_paid_in_non_native_asset() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            random(10),
+            10u32, // constitution
+            1,     // asset
+            40,    // funding reserved
+            None,  // expiry
+        ));
+        assert_eq!(RawEvent::BountyPosted(1, 1, 40, 1, 10), get_last_event());
+        assert_eq!(Bounty::bounty_currency(1), 1);
+        assert_eq!(Tokens::free_balance(1, &1), 60);
+        assert_eq!(Balances::total_balance(&1), 100);
+        // contributions are made in the bounty's asset
+        assert_noop!(
+            Bounty::contribute_to_bounty(Origin::signed(2), 1, 10),
+            tokens::Error::<Test>::BalanceTooLow
+        );
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 20));
+        assert_eq!(Tokens::free_balance(1, &3), 30);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            random(10),
+            10u32,
+            50u64,
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(
+            Origin::signed(1),
+            1,
+            None
+        ));
+        assert_eq!(Tokens::free_balance(1, &2), 50);
+        assert_eq!(Balances::total_balance(&2), 98);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 10);
+        assert_eq!(
+            Tokens::free_balance(1, &Bounty::bounty_account_id(1)),
+            10
+        );
+    });
+}
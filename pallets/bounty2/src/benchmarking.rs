@@ -0,0 +1,116 @@
+//! Bounty2 pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Decode;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{
+    Bounded,
+    TrailingZeroInput,
+};
+
+const SEED: u32 = 0;
+const MAX_CONTRIBUTORS: u32 = 100;
+
+fn funded<T: Trait>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value());
+}
+
+fn cid<T: Trait>() -> T::Cid {
+    T::Cid::decode(&mut TrailingZeroInput::new(&[][..]))
+        .expect("infinite length input; no invalid inputs for type; qed")
+}
+
+/// Posts a bounty with `c` contributors, the depositer included
+fn bounty<T: Trait>(depositer: T::AccountId, c: u32) -> T::BountyId {
+    funded::<T>(&depositer);
+    let half = Permill::from_percent(50);
+    let amount = T::MinDeposit::get() * 1000u32.into();
+    Module::<T>::post_bounty(
+        RawOrigin::Signed(depositer).into(),
+        cid::<T>(),
+        amount,
+        half.max(T::MinVetoThreshold::get()),
+        half.max(T::MinRefundThreshold::get()),
+    )
+    .expect("funded depositer can post bounty");
+    let bounty_id = Module::<T>::bounty_nonce();
+    for i in 1..c {
+        let contributor: T::AccountId = account("contributor", i, SEED);
+        funded::<T>(&contributor);
+        Module::<T>::contribute_to_bounty(
+            RawOrigin::Signed(contributor).into(),
+            bounty_id,
+            T::MinContribution::get(),
+        )
+        .expect("funded contributor can contribute to bounty");
+    }
+    bounty_id
+}
+
+fn submission<T: Trait>(bounty_id: T::BountyId) -> T::SubmissionId {
+    let submitter: T::AccountId = account("submitter", 0, SEED);
+    Module::<T>::submit_for_bounty(
+        RawOrigin::Signed(submitter).into(),
+        bounty_id,
+        cid::<T>(),
+        T::MinDeposit::get(),
+    )
+    .expect("submission does not exceed bounty total");
+    Module::<T>::submission_nonce()
+}
+
+benchmarks! {
+    _ { }
+
+    post_bounty {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+    }: _(
+        RawOrigin::Signed(caller),
+        cid::<T>(),
+        T::MinDeposit::get(),
+        T::MinVetoThreshold::get(),
+        T::MinRefundThreshold::get()
+    )
+
+    contribute_to_bounty {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let bounty_id = bounty::<T>(account("depositer", 0, SEED), 1);
+    }: _(RawOrigin::Signed(caller), bounty_id, T::MinContribution::get())
+    verify {
+        assert_eq!(Module::<T>::contributor_count(bounty_id), 2);
+    }
+
+    submit_for_bounty {
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(account("depositer", 0, SEED), 1);
+    }: _(RawOrigin::Signed(caller), bounty_id, cid::<T>(), T::MinDeposit::get())
+
+    approve_bounty_submission {
+        let caller: T::AccountId = whitelisted_caller();
+        let bounty_id = bounty::<T>(caller.clone(), 1);
+        let submission_id = submission::<T>(bounty_id);
+    }: _(RawOrigin::Signed(caller), submission_id)
+
+    reject_bounty_submission {
+        let c in 2 .. MAX_CONTRIBUTORS;
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let bounty_id = bounty::<T>(depositer.clone(), c);
+        let submission_id = submission::<T>(bounty_id);
+        Module::<T>::approve_bounty_submission(RawOrigin::Signed(depositer).into(), submission_id)?;
+        let caller: T::AccountId = account("contributor", 1, SEED);
+    }: _(RawOrigin::Signed(caller), submission_id)
+
+    trigger_refund_vote {
+        let c in 2 .. MAX_CONTRIBUTORS;
+        let bounty_id = bounty::<T>(account("depositer", 0, SEED), c);
+        let caller: T::AccountId = account("contributor", 1, SEED);
+    }: _(RawOrigin::Signed(caller), bounty_id)
+}
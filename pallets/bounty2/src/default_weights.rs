@@ -0,0 +1,89 @@
+//! Default weights for the bounty2 pallet, to be regenerated from `benchmarking.rs`
+//! with the `benchmark` subcommand of a node built with `runtime-benchmarks`
+//!
+//! The runtime charges database access at its own `DbWeight` with `SubstrateWeight`,
+//! `()` charges `RocksDbWeight` for the mocks
+
+use frame_support::{
+    traits::Get,
+    weights::{
+        constants::RocksDbWeight as DbWeight,
+        Weight,
+    },
+};
+use sp_std::marker::PhantomData;
+
+impl crate::WeightInfo for () {
+    fn post_bounty() -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn contribute_to_bounty() -> Weight {
+        (85_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn submit_for_bounty() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn approve_bounty_submission() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn reject_bounty_submission(c: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((6_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn trigger_refund_vote(c: u32) -> Weight {
+        (65_000_000 as Weight)
+            .saturating_add((6_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+}
+
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> crate::WeightInfo for SubstrateWeight<T> {
+    fn post_bounty() -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn contribute_to_bounty() -> Weight {
+        (85_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn submit_for_bounty() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn approve_bounty_submission() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn reject_bounty_submission(c: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((6_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn trigger_refund_vote(c: u32) -> Weight {
+        (65_000_000 as Weight)
+            .saturating_add((6_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+}
@@ -4,6 +4,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Bounty pallet with refundable contributions and more contributor voting rights
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
+#[cfg(test)]
+mod tests;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -23,6 +29,7 @@ use frame_support::{
         WithdrawReason,
         WithdrawReasons,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -32,6 +39,8 @@ use sp_runtime::{
         AtLeast32Bit,
         MaybeSerializeDeserialize,
         Member,
+        SaturatedConversion,
+        Saturating,
         Zero,
     },
     DispatchError,
@@ -54,9 +63,14 @@ use util::{
     share::WeightedVector,
     traits::{
         AccessGenesis,
+        CheckVoteStatus,
         GetVoteOutcome,
+        OpenVote,
+    },
+    vote::{
+        Threshold,
+        VoteOutcome,
     },
-    vote::VoteOutcome,
 };
 
 // type aliases
@@ -81,6 +95,15 @@ type BountySub<T> = BountySubmission<
     >,
 >;
 
+pub trait WeightInfo {
+    fn post_bounty() -> Weight;
+    fn contribute_to_bounty() -> Weight;
+    fn submit_for_bounty() -> Weight;
+    fn approve_bounty_submission() -> Weight;
+    fn reject_bounty_submission(c: u32) -> Weight;
+    fn trigger_refund_vote(c: u32) -> Weight;
+}
+
 pub trait Trait: frame_system::Trait + vote::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -132,12 +155,16 @@ pub trait Trait: frame_system::Trait + vote::Trait {
 
     /// Minimum refund threshold
     type MinRefundThreshold: Get<Permill>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
     pub enum Event<T>
     where
         <T as frame_system::Trait>::AccountId,
+        <T as frame_system::Trait>::BlockNumber,
         <T as vote::Trait>::Cid,
         <T as vote::Trait>::VoteId,
         <T as Trait>::BountyId,
@@ -153,8 +180,18 @@ decl_event!(
         BountyRefunded(BountyId, Balance, Balance),
         /// Submitter, Bounty Identifier, Amount Requested, Submission Identifier, Bounty Metadata, Submission Metadata
         BountySubmissionPosted(AccountId, BountyId, Balance, SubmissionId, Cid, Cid),
+        /// Submission Identifier, Bounty Identifier, Requested Amount, Block Number at which Payment is Scheduled
+        SubmissionApprovedAndScheduled(SubmissionId, BountyId, Balance, BlockNumber),
+        /// Depositer, Submission Identifier, Bounty Identifier
+        SubmissionRejected(AccountId, SubmissionId, BountyId),
+        /// Contributor, Submission Identifier, Bounty Identifier, Vote Identifier for Veto Challenge
+        SubmissionApprovalChallenged(AccountId, SubmissionId, BountyId, VoteId),
+        /// Contributor, Bounty Identifier, Vote Identifier for Refund Challenge
+        BountyRefundVoteTriggered(AccountId, BountyId, VoteId),
         /// Submission Identifier, Bounty Identifier, Requested Amount
         SubmissionApprovedButPaymentFailed(SubmissionId, BountyId, Balance),
+        /// Vote identifier for Challenge Results, Bounty Identifier, Amount Posted
+        BountyRefundApprovedButFailed(VoteId, BountyId, Balance),
         /// Submission Identifier, Bounty Identifier, Requested Amount
         SpendChallengePassedAndSubmissionRejected(SubmissionId, BountyId, Balance),
         /// Vote identifier for Challenge Results, Bounty Identifier, Amount Posted
@@ -170,17 +207,27 @@ decl_error! {
         BountyDNE,
         SubmissionDNE,
         BountyPostMustExceedMinDeposit,
+        VetoThresholdMustExceedModuleMin,
+        RefundThresholdMustExceedModuleMin,
         ContributionMustExceedModuleMin,
         DepositerCannotSubmitForBounty,
         BountySubmissionExceedsTotalAvailableFunding,
         SubmissionNotInValidStateToApprove,
         CannotApproveSubmissionIfAmountExceedsTotalAvailable,
+        CannotApproveSubmissionIfBountyUnderRefundChallenge,
         NotAuthorizedToApproveBountySubmissions,
+        NotAuthorizedToRejectBountySubmissions,
+        MustBeContributorToChallengeSubmissionApproval,
+        ChallengePeriodExpiredSoApprovalCannotBeChallenged,
+        SubmissionNotInValidStateToReject,
+        MustBeContributorToTriggerRefundVote,
+        BountyAlreadyUnderRefundChallenge,
+        RefundBelowExistentialDeposit,
     }
 }
 
 decl_storage! {
-    trait Store for Module<T: Trait> as Bounty {
+    trait Store for Module<T: Trait> as Bounty2 {
         /// Uid generation helper for BountyId
         BountyNonce get(fn bounty_nonce): T::BountyId;
 
@@ -194,6 +241,9 @@ decl_storage! {
         pub BountyTips get(fn bounty_tips): double_map
             hasher(blake2_128_concat) T::BountyId,
             hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+        /// Number of contributors for existing Bounties
+        pub ContributorCount get(fn contributor_count): map
+            hasher(blake2_128_concat) T::BountyId => u32;
 
         /// Posted Submissions
         pub Submissions get(fn submissions): map
@@ -211,7 +261,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::post_bounty()]
         fn post_bounty(
             origin,
             info: T::Cid,
@@ -221,6 +271,8 @@ decl_module! {
         ) -> DispatchResult {
             let depositer = ensure_signed(origin)?;
             ensure!(amount >= T::MinDeposit::get(), Error::<T>::BountyPostMustExceedMinDeposit);
+            ensure!(veto_threshold >= T::MinVetoThreshold::get(), Error::<T>::VetoThresholdMustExceedModuleMin);
+            ensure!(refund_threshold >= T::MinRefundThreshold::get(), Error::<T>::RefundThresholdMustExceedModuleMin);
             let imb = T::Currency::withdraw(
                 &depositer,
                 amount,
@@ -232,10 +284,11 @@ decl_module! {
             T::Currency::resolve_creating(&Self::bounty_account_id(id), imb);
             <Bounties<T>>::insert(id, bounty);
             <BountyTips<T>>::insert(id, &depositer, amount);
+            <ContributorCount<T>>::insert(id, 1);
             Self::deposit_event(RawEvent::BountyPosted(depositer, amount, id, info));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::contribute_to_bounty()]
         fn contribute_to_bounty(
             origin,
             bounty_id: T::BountyId,
@@ -253,6 +306,7 @@ decl_module! {
             let new_amount = if let Some(a) = <BountyTips<T>>::get(bounty_id, &contributor) {
                 amount + a
             } else {
+                <ContributorCount<T>>::mutate(bounty_id, |c| *c += 1);
                 amount
             };
             let new_bounty = bounty.add_funds(amount);
@@ -262,7 +316,7 @@ decl_module! {
             Self::deposit_event(RawEvent::BountyRaiseContribution(contributor, amount, bounty_id, total, bounty.info()));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_for_bounty()]
         fn submit_for_bounty(
             origin,
             bounty_id: T::BountyId,
@@ -279,28 +333,96 @@ decl_module! {
             Self::deposit_event(RawEvent::BountySubmissionPosted(submitter, bounty_id, amount, id, bounty.info(), submission_ref));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::approve_bounty_submission()]
         fn approve_bounty_submission(
             origin,
             submission_id: T::SubmissionId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let approver = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToApprove);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(bounty.gov().leader() == approver, Error::<T>::NotAuthorizedToApproveBountySubmissions);
+            ensure!(
+                bounty.state() == BountyState::NoPendingChallenges,
+                Error::<T>::CannotApproveSubmissionIfBountyUnderRefundChallenge
+            );
+            ensure!(
+                bounty.total() >= submission.amount(),
+                Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable
+            );
+            // payment is scheduled for after the challenge period so that contributors may veto
+            let scheduled = <frame_system::Module<T>>::block_number() + T::ChallengePeriod::get();
+            let amount = submission.amount();
+            <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState2::ApprovedAndScheduled(scheduled)));
+            Self::deposit_event(RawEvent::SubmissionApprovedAndScheduled(submission_id, bounty_id, amount, scheduled));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::reject_bounty_submission(Self::submission_contributor_count(*submission_id))]
         fn reject_bounty_submission(
             origin,
             submission_id: T::SubmissionId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
-            Ok(())
+            let caller = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            match submission.state() {
+                SubmissionState2::SubmittedAwaitingResponse => {
+                    // => the depositer rejects a submission it has not yet approved
+                    ensure!(bounty.gov().leader() == caller, Error::<T>::NotAuthorizedToRejectBountySubmissions);
+                    <Submissions<T>>::remove(submission_id);
+                    Self::deposit_event(RawEvent::SubmissionRejected(caller, submission_id, bounty_id));
+                    Ok(())
+                }
+                SubmissionState2::ApprovedAndScheduled(n) => {
+                    // => a contributor challenges the depositer's approval with a veto vote
+                    ensure!(
+                        <BountyTips<T>>::get(bounty_id, &caller).is_some(),
+                        Error::<T>::MustBeContributorToChallengeSubmissionApproval
+                    );
+                    ensure!(
+                        <frame_system::Module<T>>::block_number() < n,
+                        Error::<T>::ChallengePeriodExpiredSoApprovalCannotBeChallenged
+                    );
+                    let vote_id = <vote::Module<T>>::open_percent_vote(
+                        Some(submission.submission()),
+                        Self::contributor_signal(bounty_id),
+                        Threshold::new(bounty.gov().veto_threshold(), None),
+                        Some(T::ChallengePeriod::get()),
+                    )?;
+                    <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState2::ChallengedAndUnderReview(vote_id)));
+                    Self::deposit_event(RawEvent::SubmissionApprovalChallenged(caller, submission_id, bounty_id, vote_id));
+                    Ok(())
+                }
+                _ => Err(Error::<T>::SubmissionNotInValidStateToReject.into()),
+            }
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::trigger_refund_vote(Self::contributor_count(*bounty_id))]
         fn trigger_refund_vote(
             origin,
             bounty_id: T::BountyId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let caller = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(
+                <BountyTips<T>>::get(bounty_id, &caller).is_some(),
+                Error::<T>::MustBeContributorToTriggerRefundVote
+            );
+            // a failed refund may be retried with another vote
+            ensure!(
+                matches!(bounty.state(), BountyState::NoPendingChallenges | BountyState::RefundFailed(_)),
+                Error::<T>::BountyAlreadyUnderRefundChallenge
+            );
+            let vote_id = <vote::Module<T>>::open_percent_vote(
+                Some(bounty.info()),
+                Self::contributor_signal(bounty_id),
+                Threshold::new(bounty.gov().refund_threshold(), None),
+                Some(T::ChallengePeriod::get()),
+            )?;
+            <Bounties<T>>::insert(bounty_id, bounty.set_state(BountyState::ChallengedToClose(vote_id)));
+            Self::deposit_event(RawEvent::BountyRefundVoteTriggered(caller, bounty_id, vote_id));
             Ok(())
         }
         fn on_finalize(_n: T::BlockNumber) {
@@ -311,11 +433,8 @@ decl_module! {
                     match sub.state() {
                         SubmissionState2::ApprovedAndScheduled(n) => {
                             if now >= n {
-                                // TODO: make this path infallible
                                 // approve and execute submission payment
-                                if !Self::approve_and_execute_payment(sub_id).is_ok() {
-                                    Self::deposit_event(RawEvent::SubmissionApprovedButPaymentFailed(sub_id, sub.bounty_id(), sub.amount()));
-                                }
+                                Self::execute_payment_or_fail(sub_id, sub);
                             }
                         }
                         SubmissionState2::ChallengedAndUnderReview(v) => {
@@ -327,13 +446,15 @@ decl_module! {
                                     Self::deposit_event(RawEvent::SpendChallengePassedAndSubmissionRejected(sub_id, sub.bounty_id(), sub.amount()));
                                 },
                                 VoteOutcome::Rejected => {
-                                    // TODO: make this path infallible
                                     // => the submission is approved because this vote was a challenge to an approval by the depositer
-                                    if !Self::approve_and_execute_payment(sub_id).is_ok() {
-                                        Self::deposit_event(RawEvent::SubmissionApprovedButPaymentFailed(sub_id, sub.bounty_id(), sub.amount()));
+                                    Self::execute_payment_or_fail(sub_id, sub);
+                                },
+                                _ => {
+                                    // => the veto did not reach threshold support before the challenge expired
+                                    if Self::challenge_expired(v) {
+                                        Self::execute_payment_or_fail(sub_id, sub);
                                     }
                                 },
-                                _ => (),
                             }
                         }
                         _ => (),
@@ -347,9 +468,15 @@ decl_module! {
                         let status = <vote::Module<T>>::get_vote_outcome(v).expect("dispatched votes are never cleared by default, qed");
                         match status {
                             VoteOutcome::Approved => {
-                                // => the refund is executed
+                                // => the refund is executed, a refund that cannot be paid out is no longer polled
                                 if let Ok((amt_to_contributors, amt_to_depositer)) = Self::execute_refund(bid, &bty.gov().leader()) {
+                                    Self::recursive_remove_bounty(bid);
                                     Self::deposit_event(RawEvent::BountyRefunded(bid, amt_to_contributors, amt_to_depositer));
+                                } else {
+                                    let new_bty = bty.set_state(BountyState::RefundFailed(v));
+                                    let total = new_bty.total();
+                                    <Bounties<T>>::insert(bid, new_bty);
+                                    Self::deposit_event(RawEvent::BountyRefundApprovedButFailed(v, bid, total));
                                 }
                             },
                             VoteOutcome::Rejected => {
@@ -359,7 +486,15 @@ decl_module! {
                                 <Bounties<T>>::insert(bid, new_bty);
                                 Self::deposit_event(RawEvent::BountyRefundChallengeRejected(v, bid, total));
                             },
-                            _ => (),
+                            _ => {
+                                // => the refund did not reach threshold support before the challenge expired
+                                if Self::challenge_expired(v) {
+                                    let new_bty = bty.set_state(BountyState::NoPendingChallenges);
+                                    let total = new_bty.total();
+                                    <Bounties<T>>::insert(bid, new_bty);
+                                    Self::deposit_event(RawEvent::BountyRefundChallengeRejected(v, bid, total));
+                                }
+                            },
                         }
                     }
                 }
@@ -399,11 +534,37 @@ impl<T: Trait> Module<T> {
 
 // Runtime helpers
 impl<T: Trait> Module<T> {
+    /// Number of contributors to the bounty for which the submission was made
+    fn submission_contributor_count(id: T::SubmissionId) -> u32 {
+        <Submissions<T>>::get(id)
+            .map(|s| Self::contributor_count(s.bounty_id()))
+            .unwrap_or_default()
+    }
+    /// Contributors vote in proportion to their contributions to the bounty
+    fn contributor_signal(
+        id: T::BountyId,
+    ) -> WeightedVector<T::AccountId, T::Signal> {
+        <BountyTips<T>>::iter_prefix(id)
+            .map(|(ac, amt)| {
+                let amt: u128 = amt.saturated_into();
+                (ac, amt.saturated_into())
+            })
+            .collect::<Vec<(T::AccountId, T::Signal)>>()
+            .into()
+    }
+    /// Returns true if the challenge vote expired (or DNE) without reaching threshold support
+    fn challenge_expired(vote_id: T::VoteId) -> bool {
+        if let Some(state) = <vote::Module<T>>::vote_states(vote_id) {
+            <vote::Module<T>>::check_vote_expired(&state)
+        } else {
+            true
+        }
+    }
     fn approve_and_execute_payment(id: T::SubmissionId) -> DispatchResult {
         let submission =
             <Submissions<T>>::get(id).ok_or(Error::<T>::SubmissionDNE)?;
         ensure!(
-            submission.state().awaiting_review(),
+            !submission.state().awaiting_review(),
             Error::<T>::SubmissionNotInValidStateToApprove
         );
         let bounty_id = submission.bounty_id();
@@ -436,59 +597,83 @@ impl<T: Trait> Module<T> {
         ));
         Ok(())
     }
-    // remainder recipient should be the depositer, aka bounty.gov().leader()
+    /// Pays an approved submission or moves it to `PaymentFailed` so it is no longer polled
+    fn execute_payment_or_fail(id: T::SubmissionId, submission: BountySub<T>) {
+        if Self::approve_and_execute_payment(id).is_err() {
+            let (bounty_id, amount) =
+                (submission.bounty_id(), submission.amount());
+            <Submissions<T>>::insert(
+                id,
+                submission.set_state(SubmissionState2::PaymentFailed),
+            );
+            Self::deposit_event(RawEvent::SubmissionApprovedButPaymentFailed(
+                id, bounty_id, amount,
+            ));
+        }
+    }
+    /// Refunds the bounty's balance to contributors in proportion to their contributions
+    /// -> remainder recipient should be the depositer, aka bounty.gov().leader()
+    /// -> every transfer is checked before any is made so a failed refund changes nothing
     fn execute_refund(
         id: T::BountyId,
-        _remainder_recipient: &T::AccountId,
+        remainder_recipient: &T::AccountId,
     ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
         let from = Self::bounty_account_id(id);
-        let total = T::Currency::total_balance(&from);
+        let available = T::Currency::free_balance(&from);
         let contributors: WeightedVector<T::AccountId, BalanceOf<T>> =
-            <BountyTips<T>>::iter()
-                .filter(|(i, _, _)| i == &id)
-                .map(|(_, ac, amt)| (ac, amt))
+            <BountyTips<T>>::iter_prefix(id)
                 .collect::<Vec<(T::AccountId, BalanceOf<T>)>>()
                 .into();
-        let num_of_accounts: u32 = contributors.vec().len() as u32;
-        if num_of_accounts == 1 {
+        let den = contributors.total();
+        let min = T::Currency::minimum_balance();
+        let mut remainder = available;
+        let mut total_to_contributors = BalanceOf::<T>::zero();
+        let mut payouts = Vec::<(T::AccountId, BalanceOf<T>)>::new();
+        for (acc, nom) in contributors.vec().iter() {
+            let due_amount = Permill::from_rational_approximation(*nom, den)
+                .mul_floor(available);
+            // shares that cannot create the contributor's account stay in the remainder
+            if due_amount.is_zero()
+                || T::Currency::total_balance(acc).saturating_add(due_amount)
+                    < min
+            {
+                continue
+            }
+            remainder -= due_amount;
+            total_to_contributors += due_amount;
+            payouts.push((acc.clone(), due_amount));
+        }
+        if !remainder.is_zero() {
+            payouts.push((remainder_recipient.clone(), remainder));
+        }
+        // the remainder recipient must end above the existential deposit and
+        // the bounty account must not be reaped before the last transfer
+        let mut left = available;
+        for (acc, amount) in payouts.iter() {
+            ensure!(
+                T::Currency::total_balance(acc).saturating_add(*amount) >= min,
+                Error::<T>::RefundBelowExistentialDeposit
+            );
+            left -= *amount;
+            ensure!(
+                left.is_zero() || left >= min,
+                Error::<T>::RefundBelowExistentialDeposit
+            );
+        }
+        for (acc, amount) in payouts {
             T::Currency::transfer(
                 &from,
-                &contributors.vec()[0].0,
-                total,
+                &acc,
+                amount,
                 ExistenceRequirement::AllowDeath,
             )?;
-            // Self::recursive_remove_bounty(id);
-            Ok((total, BalanceOf::<T>::zero()))
-        } else {
-            todo!()
-            // let mut total_to_contributors = BalanceOf::<T>::zero();
-            // let den: T::Signal = contributors.total();
-            // for (acc, nom) in contributors.vec().iter() {
-            //     let due_proportion =
-            //         Permill::from_rational_approximation(nom, &den);
-            //     let due_amount: BalanceOf<T> = due_proportion * total;
-            //     T::Currency::transfer(
-            //         &from,
-            //         &acc,
-            //         due_amount,
-            //         ExistenceRequirement::AllowDeath,
-            //     )?;
-            //     total -= due_amount;
-            //     total_to_contributors += due_amount;
-            // }
-            // // send remainder
-            // T::Currency::transfer(
-            //     &from,
-            //     remainder_recipient,
-            //     total,
-            //     ExistenceRequirement::AllowDeath,
-            // )?;
-            // // Self::recursive_remove_bounty(id);
-            // Ok((total_to_contributors, total))
         }
+        Ok((total_to_contributors, remainder))
     }
-    fn _recursive_remove_bounty(id: T::BountyId) {
+    fn recursive_remove_bounty(id: T::BountyId) {
         <Bounties<T>>::remove(id);
+        <BountyTips<T>>::remove_prefix(id);
+        <ContributorCount<T>>::remove(id);
         <Submissions<T>>::iter()
             .filter(|(_, app)| app.bounty_id() == id)
             .for_each(|(app_id, _)| <Submissions<T>>::remove(app_id));
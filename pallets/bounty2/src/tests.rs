@@ -0,0 +1,427 @@
+use super::*;
+use frame_support::{
+    assert_noop,
+    assert_ok,
+    impl_outer_event,
+    impl_outer_origin,
+    parameter_types,
+    traits::{
+        LockableCurrency,
+        OnFinalize,
+        WithdrawReasons,
+    },
+    weights::Weight,
+};
+use frame_system::{self as system,};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::IdentityLookup,
+    Perbill,
+};
+use util::vote::VoterView;
+
+// type aliases
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+mod bounty2 {
+    pub use super::super::*;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        system<T>,
+        pallet_balances<T>,
+        vote<T>,
+        bounty2<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+    type Origin = Origin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Call = ();
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type MaximumBlockLength = MaximumBlockLength;
+    type Version = ();
+    type ModuleToIndex = ();
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type BaseCallFilter = ();
+    type SystemWeightInfo = ();
+}
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type Cid = u32;
+    type VoteId = u64;
+    type Signal = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ChallengePeriod: u64 = 5;
+    pub const Foundation: ModuleId = ModuleId(*b"fundacon");
+    pub const MinDeposit: u64 = 10;
+    pub const MinContribution: u64 = 5;
+    pub const MinVetoThreshold: Permill = Permill::from_percent(20);
+    pub const MinRefundThreshold: Permill = Permill::from_percent(20);
+}
+impl Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+    type BountyId = u64;
+    type SubmissionId = u64;
+    type ChallengePeriod = ChallengePeriod;
+    type Foundation = Foundation;
+    type MinDeposit = MinDeposit;
+    type MinContribution = MinContribution;
+    type MinVetoThreshold = MinVetoThreshold;
+    type MinRefundThreshold = MinRefundThreshold;
+    type WeightInfo = ();
+}
+pub type System = system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type Vote = vote::Module<Test>;
+pub type Bounty = Module<Test>;
+
+fn get_last_event() -> RawEvent<u64, u64, u32, u64, u64, u64, u64> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let TestEvent::bounty2(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .last()
+        .unwrap()
+}
+
+/// Auxiliary method for simulating block time passing
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Bounty::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+    }
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100), (3, 100), (4, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    GenesisConfig::<Test> {
+        bounty_poll_frequency: 1,
+        submission_poll_frequency: 1,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn post_bounty_works() {
+    new_test_ext().execute_with(|| {
+        let half = Permill::from_percent(50);
+        assert_noop!(
+            Bounty::post_bounty(Origin::signed(1), 10, 9, half, half),
+            Error::<Test>::BountyPostMustExceedMinDeposit
+        );
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10,
+                20,
+                Permill::from_percent(10),
+                half
+            ),
+            Error::<Test>::VetoThresholdMustExceedModuleMin
+        );
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10,
+                20,
+                half,
+                Permill::from_percent(10)
+            ),
+            Error::<Test>::RefundThresholdMustExceedModuleMin
+        );
+        assert_ok!(Bounty::post_bounty(Origin::signed(1), 10, 20, half, half));
+        assert_eq!(get_last_event(), RawEvent::BountyPosted(1, 20, 1, 10));
+        assert_eq!(Balances::total_balance(&1), 80);
+        assert_eq!(Balances::total_balance(&Bounty::bounty_account_id(1)), 20);
+    });
+}
+
+#[test]
+fn approved_submission_is_paid_after_challenge_period() {
+    new_test_ext().execute_with(|| {
+        let half = Permill::from_percent(50);
+        assert_ok!(Bounty::post_bounty(Origin::signed(1), 10, 20, half, half));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11, 10));
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(3), 1),
+            Error::<Test>::NotAuthorizedToApproveBountySubmissions
+        );
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SubmissionApprovedAndScheduled(1, 1, 10, 6)
+        );
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1),
+            Error::<Test>::SubmissionNotInValidStateToApprove
+        );
+        run_to_block(6);
+        // not yet paid out
+        assert_eq!(Balances::total_balance(&2), 100);
+        run_to_block(7);
+        assert_eq!(Balances::total_balance(&2), 110);
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 10);
+    });
+}
+
+#[test]
+fn depositer_can_reject_submission() {
+    new_test_ext().execute_with(|| {
+        let half = Permill::from_percent(50);
+        assert_ok!(Bounty::post_bounty(Origin::signed(1), 10, 20, half, half));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11, 10));
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(3), 1),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(get_last_event(), RawEvent::SubmissionRejected(1, 1, 1));
+        assert!(Bounty::submissions(1).is_none());
+    });
+}
+
+#[test]
+fn contributor_veto_rejects_approved_submission() {
+    new_test_ext().execute_with(|| {
+        let half = Permill::from_percent(50);
+        assert_ok!(Bounty::post_bounty(Origin::signed(1), 10, 20, half, half));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 20));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11, 10));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(4), 1),
+            Error::<Test>::MustBeContributorToChallengeSubmissionApproval
+        );
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(3), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SubmissionApprovalChallenged(3, 1, 1, 1)
+        );
+        // 3 contributed half of the funds so their vote meets the veto threshold
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(3);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendChallengePassedAndSubmissionRejected(1, 1, 10)
+        );
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Balances::total_balance(&2), 100);
+    });
+}
+
+#[test]
+fn expired_veto_challenge_executes_payment() {
+    new_test_ext().execute_with(|| {
+        let half = Permill::from_percent(50);
+        assert_ok!(Bounty::post_bounty(Origin::signed(1), 10, 20, half, half));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 20));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11, 10));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(3), 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::Against,
+            None
+        ));
+        run_to_block(7);
+        assert_eq!(Balances::total_balance(&2), 100);
+        // veto vote expires without threshold support
+        run_to_block(8);
+        assert_eq!(Balances::total_balance(&2), 110);
+        assert!(Bounty::submissions(1).is_none());
+    });
+}
+
+#[test]
+fn refund_is_proportional_to_contributions() {
+    new_test_ext().execute_with(|| {
+        let half = Permill::from_percent(50);
+        assert_ok!(Bounty::post_bounty(Origin::signed(1), 10, 30, half, half));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_eq!(Bounty::contributor_count(1), 2);
+        assert_eq!(Balances::total_balance(&1), 70);
+        assert_eq!(Balances::total_balance(&2), 90);
+        assert_noop!(
+            Bounty::trigger_refund_vote(Origin::signed(3), 1),
+            Error::<Test>::MustBeContributorToTriggerRefundVote
+        );
+        assert_ok!(Bounty::trigger_refund_vote(Origin::signed(2), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BountyRefundVoteTriggered(2, 1, 1)
+        );
+        assert_noop!(
+            Bounty::trigger_refund_vote(Origin::signed(1), 1),
+            Error::<Test>::BountyAlreadyUnderRefundChallenge
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(3);
+        assert_eq!(get_last_event(), RawEvent::BountyRefunded(1, 40, 0));
+        assert_eq!(Balances::total_balance(&1), 100);
+        assert_eq!(Balances::total_balance(&2), 100);
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::bounty_tips(1, 2).is_none());
+        assert_eq!(Bounty::contributor_count(1), 0);
+    });
+}
+
+#[test]
+fn failed_payment_is_not_polled_again() {
+    new_test_ext().execute_with(|| {
+        let half = Permill::from_percent(50);
+        assert_ok!(Bounty::post_bounty(Origin::signed(1), 10, 20, half, half));
+        // paying out the whole bounty would reap the bounty account
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11, 20));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        run_to_block(10);
+        assert_eq!(
+            Bounty::submissions(1).unwrap().state(),
+            SubmissionState2::PaymentFailed
+        );
+        let failed = TestEvent::bounty2(
+            RawEvent::SubmissionApprovedButPaymentFailed(1, 1, 20),
+        );
+        assert_eq!(
+            System::events()
+                .into_iter()
+                .filter(|r| r.event == failed)
+                .count(),
+            1
+        );
+        assert_eq!(Balances::total_balance(&2), 100);
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1),
+            Error::<Test>::SubmissionNotInValidStateToApprove
+        );
+    });
+}
+
+#[test]
+fn failed_refund_is_not_polled_again_until_another_vote() {
+    new_test_ext().execute_with(|| {
+        let half = Permill::from_percent(50);
+        assert_ok!(Bounty::post_bounty(Origin::signed(1), 10, 30, half, half));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_ok!(Bounty::trigger_refund_vote(Origin::signed(2), 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        // funds that cannot leave the bounty account fail the whole refund
+        let bounty_account = Bounty::bounty_account_id(1);
+        Balances::set_lock(
+            *b"refundlk",
+            &bounty_account,
+            40,
+            WithdrawReasons::all(),
+        );
+        run_to_block(5);
+        assert_eq!(
+            Bounty::bounties(1).unwrap().state(),
+            BountyState::RefundFailed(1)
+        );
+        let failed = TestEvent::bounty2(
+            RawEvent::BountyRefundApprovedButFailed(1, 1, 40),
+        );
+        assert_eq!(
+            System::events()
+                .into_iter()
+                .filter(|r| r.event == failed)
+                .count(),
+            1
+        );
+        assert_eq!(Balances::total_balance(&1), 70);
+        assert_eq!(Balances::total_balance(&2), 90);
+        // contributors may retry the refund with another vote
+        Balances::remove_lock(*b"refundlk", &bounty_account);
+        assert_ok!(Bounty::trigger_refund_vote(Origin::signed(2), 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            2,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(6);
+        assert_eq!(get_last_event(), RawEvent::BountyRefunded(1, 40, 0));
+        assert_eq!(Balances::total_balance(&1), 100);
+        assert_eq!(Balances::total_balance(&2), 100);
+        assert!(Bounty::bounties(1).is_none());
+    });
+}
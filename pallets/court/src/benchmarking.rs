@@ -0,0 +1,97 @@
+//! Court pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+use util::{
+    meta::VoteCall,
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+    vote::{
+        Threshold,
+        VoterView,
+    },
+};
+
+const SEED: u32 = 0;
+const MAX_MEMBERS: u32 = 100;
+
+/// Registers an org with `first` as supervisor and the first member
+fn org_with_members<T: Trait>(first: T::AccountId, m: u32) -> T::OrgId {
+    let mut members = vec![first.clone()];
+    members.extend((1..m).map(|i| account("member", i, SEED)));
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        Some(first),
+        T::Cid::default(),
+    )
+    .expect("org registration with supervisor works")
+}
+
+/// Registers a dispute resolved by a unanimous vote of the `m` member court
+fn dispute<T: Trait>(
+    locker: T::AccountId,
+    raiser: T::AccountId,
+    m: u32,
+) -> T::DisputeId {
+    T::Currency::make_free_balance_be(&locker, BalanceOf::<T>::max_value());
+    let court = org_with_members::<T>(locker.clone(), m);
+    <Module<T> as RegisterDisputeType<_, _, _, _>>::register_dispute_type(
+        locker,
+        T::MinimumDisputeAmount::get(),
+        raiser,
+        VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(court),
+            Threshold::new(m.into(), None),
+            None,
+        )),
+        None,
+    )
+    .expect("funded locker can register dispute")
+}
+
+benchmarks! {
+    _ { }
+
+    register_dispute_type_with_resolution_path {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        let court = org_with_members::<T>(caller.clone(), 1);
+        let resolution = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(court),
+            Threshold::new(1u32.into(), None),
+            None,
+        ));
+    }: _(
+        RawOrigin::Signed(caller),
+        T::MinimumDisputeAmount::get(),
+        account("raiser", 0, SEED),
+        resolution,
+        None
+    )
+
+    raise_dispute_to_trigger_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let dispute_id = dispute::<T>(account("locker", 0, SEED), caller.clone(), m);
+    }: _(RawOrigin::Signed(caller), dispute_id)
+
+    poll_dispute_to_execute_outcome {
+        let caller: T::AccountId = whitelisted_caller();
+        let locker: T::AccountId = account("locker", 0, SEED);
+        let dispute_id = dispute::<T>(locker.clone(), caller.clone(), 1);
+        Module::<T>::raise_dispute_to_trigger_vote(RawOrigin::Signed(caller.clone()).into(), dispute_id)?;
+        let vote_id = match Module::<T>::dispute_states(dispute_id).map(|d| d.state()) {
+            Some(DisputeState::DisputeRaisedAndVoteDispatched(v)) => v,
+            _ => return Err("dispute vote not dispatched"),
+        };
+        <vote::Module<T>>::submit_vote(RawOrigin::Signed(locker).into(), vote_id, VoterView::InFavor, None)?;
+    }: _(RawOrigin::Signed(caller), dispute_id)
+}
@@ -0,0 +1,57 @@
+//! Default weights for the court pallet, to be regenerated from `benchmarking.rs`
+//! with the `benchmark` subcommand of a node built with `runtime-benchmarks`
+//!
+//! The runtime charges database access at its own `DbWeight` with `SubstrateWeight`,
+//! `()` charges `RocksDbWeight` for the mocks
+
+use frame_support::{
+    traits::Get,
+    weights::{
+        constants::RocksDbWeight as DbWeight,
+        Weight,
+    },
+};
+use sp_std::marker::PhantomData;
+
+impl crate::WeightInfo for () {
+    fn register_dispute_type_with_resolution_path() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn raise_dispute_to_trigger_vote(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn poll_dispute_to_execute_outcome() -> Weight {
+        (75_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+}
+
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> crate::WeightInfo for SubstrateWeight<T> {
+    fn register_dispute_type_with_resolution_path() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn raise_dispute_to_trigger_vote(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn poll_dispute_to_execute_outcome() -> Weight {
+        (75_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+}
@@ -5,6 +5,9 @@
 //! This court module is for dispute resolution
 //! - stores vote metadata to schedule and dispatch votes to resolve disputes
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -21,6 +24,7 @@ use frame_support::{
         Get,
         ReservableCurrency,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -67,6 +71,12 @@ type GovernanceOf<T> = VoteMetadata<
     <T as frame_system::Trait>::BlockNumber,
 >;
 
+pub trait WeightInfo {
+    fn register_dispute_type_with_resolution_path() -> Weight;
+    fn raise_dispute_to_trigger_vote(m: u32) -> Weight;
+    fn poll_dispute_to_execute_outcome() -> Weight;
+}
+
 pub trait Trait: frame_system::Trait + org::Trait + vote::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -90,6 +100,9 @@ pub trait Trait: frame_system::Trait + org::Trait + vote::Trait {
 
     /// The minimum amount for any dispute registered in this module
     type MinimumDisputeAmount: Get<BalanceOf<Self>>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -149,7 +162,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::register_dispute_type_with_resolution_path()]
         fn register_dispute_type_with_resolution_path(
             origin,
             amount_to_lock: BalanceOf<T>,
@@ -171,7 +184,7 @@ decl_module! {
             Self::deposit_event(RawEvent::RegisteredDisputeWithResolutionPath(new_dispute_id, locker, amount_to_lock, dispute_raiser, court_org));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::raise_dispute_to_trigger_vote(Self::dispute_org_size(*dispute_id))]
         fn raise_dispute_to_trigger_vote(
             origin,
             dispute_id: T::DisputeId,
@@ -207,7 +220,7 @@ decl_module! {
             Self::deposit_event(RawEvent::DisputeRaisedAndVoteTriggered(dispute_id, locker, amt_locked, trigger, court_org, dispatched_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::poll_dispute_to_execute_outcome()]
         fn poll_dispute_to_execute_outcome(
             origin,
             dispute_id: T::DisputeId,
@@ -249,6 +262,19 @@ decl_module! {
     }
 }
 
+impl<T: Trait> Module<T> {
+    /// Number of members in the org that votes to resolve the dispute
+    fn dispute_org_size(id: T::DisputeId) -> u32 {
+        <DisputeStates<T>>::get(id)
+            .map(|d| {
+                <org::Module<T>>::member_count(
+                    d.resolution_metadata().org().org(),
+                )
+            })
+            .unwrap_or_default()
+    }
+}
+
 impl<T: Trait> IDIsAvailable<T::DisputeId> for Module<T> {
     fn id_is_available(id: T::DisputeId) -> bool {
         <DisputeStates<T>>::get(id).is_none()
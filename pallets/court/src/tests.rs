@@ -29,7 +29,9 @@ pub type AccountId = u64;
 pub type BlockNumber = u64;
 
 impl_outer_origin! {
-    pub enum Origin for Test where system = frame_system {}
+    pub enum Origin for Test where system = frame_system {
+        org<T>,
+    }
 }
 
 mod court {
@@ -92,16 +94,45 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: u64 = 5;
+    pub const SupervisorTermLength: u64 = 10;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32; // TODO: replace with utils_identity::Cid
     type OrgId = u64;
     type Shares = u64;
+    type OrgOrigin = org::EnsureOrg<Test>;
+    type ElectionId = u64;
+    type SupervisorVote = vote::Module<Test>;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const MaxDelegators: u32 = 3;
+    pub const MaxOptions: u32 = 5;
+    pub const VoteRetentionPeriod: Option<u64> = None;
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type MaxDelegators = MaxDelegators;
+    type MaxOptions = MaxOptions;
+    type VoteRetentionPeriod = VoteRetentionPeriod;
+    type WeightInfo = ();
 }
 parameter_types! {
     pub const MinimumDisputeAmount: u64 = 10;
@@ -111,6 +142,7 @@ impl Trait for Test {
     type Currency = Balances;
     type DisputeId = u64;
     type MinimumDisputeAmount = MinimumDisputeAmount;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
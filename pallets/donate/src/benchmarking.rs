@@ -0,0 +1,53 @@
+//! Donate pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::prelude::*;
+use util::{
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+};
+
+const SEED: u32 = 0;
+const MAX_MEMBERS: u32 = 100;
+
+fn org_with_members<T: Trait>(m: u32) -> T::OrgId {
+    let members = (0..m)
+        .map(|i| account("member", i, SEED))
+        .collect::<Vec<T::AccountId>>();
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        None,
+        T::Cid::default(),
+    )
+    .expect("org registration works")
+}
+
+benchmarks! {
+    _ { }
+
+    make_prop_donation {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let currency_id = T::Currency::native_currency_id();
+        T::Currency::deposit(currency_id, &caller, BalanceOf::<T>::max_value())?;
+        let org = org_with_members::<T>(m);
+        let amt: BalanceOf<T> = T::Currency::minimum_balance(currency_id) * (1000 * m).into();
+    }: _(RawOrigin::Signed(caller.clone()), org, caller.clone(), currency_id, amt)
+
+    make_equal_donation {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let currency_id = T::Currency::native_currency_id();
+        T::Currency::deposit(currency_id, &caller, BalanceOf::<T>::max_value())?;
+        let org = org_with_members::<T>(m);
+        let amt: BalanceOf<T> = T::Currency::minimum_balance(currency_id) * (1000 * m).into();
+    }: _(RawOrigin::Signed(caller.clone()), org, caller.clone(), currency_id, amt)
+}
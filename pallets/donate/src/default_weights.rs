@@ -0,0 +1,53 @@
+//! Default weights for the donate pallet, to be regenerated from `benchmarking.rs`
+//! with the `benchmark` subcommand of a node built with `runtime-benchmarks`
+//!
+//! The runtime charges database access at its own `DbWeight` with `SubstrateWeight`,
+//! `()` charges `RocksDbWeight` for the mocks
+
+use frame_support::{
+    traits::Get,
+    weights::{
+        constants::RocksDbWeight as DbWeight,
+        Weight,
+    },
+};
+use sp_std::marker::PhantomData;
+
+impl crate::WeightInfo for () {
+    fn make_prop_donation(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((62_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads(2 * m as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn make_equal_donation(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((58_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+}
+
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> crate::WeightInfo for SubstrateWeight<T> {
+    fn make_prop_donation(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((62_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 * m as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn make_equal_donation(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((58_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+}
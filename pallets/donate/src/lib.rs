@@ -1,5 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -7,11 +10,8 @@ use frame_support::{
     decl_error,
     decl_event,
     decl_module,
-    traits::{
-        Currency,
-        ExistenceRequirement,
-        ReservableCurrency,
-    },
+    traits::ExistenceRequirement,
+    weights::Weight,
 };
 use frame_system::{
     self as system,
@@ -28,29 +28,45 @@ use sp_runtime::{
 };
 use util::{
     organization::OrgRep,
-    traits::GetGroup,
+    traits::{
+        GetGroup,
+        MultiCurrency,
+    },
 };
 
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+type BalanceOf<T> = <<T as Trait>::Currency as MultiCurrency<
     <T as system::Trait>::AccountId,
 >>::Balance;
+pub type CurrencyIdOf<T> = <<T as Trait>::Currency as MultiCurrency<
+    <T as system::Trait>::AccountId,
+>>::CurrencyId;
+
+pub trait WeightInfo {
+    fn make_prop_donation(m: u32) -> Weight;
+    fn make_equal_donation(m: u32) -> Weight;
+}
 
 pub trait Trait: system::Trait + org::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
-    /// The currency type
-    type Currency: Currency<Self::AccountId>
-        + ReservableCurrency<Self::AccountId>;
+    /// The multi-asset currency type
+    type Currency: MultiCurrency<Self::AccountId>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
     pub enum Event<T> where
         <T as system::Trait>::AccountId,
         <T as org::Trait>::OrgId,
+        CurrencyId = CurrencyIdOf<T>,
         Balance = BalanceOf<T>,
     {
-        PropDonationExecuted(AccountId, Balance, OrgId, Balance, AccountId),
-        EqualDonationExecuted(AccountId, Balance, OrgId, Balance, AccountId),
+        /// Donor, Asset, Amount To Org, Org Identifier, Remainder, Remainder Recipient
+        PropDonationExecuted(AccountId, CurrencyId, Balance, OrgId, Balance, AccountId),
+        /// Donor, Asset, Amount To Org, Org Identifier, Remainder, Remainder Recipient
+        EqualDonationExecuted(AccountId, CurrencyId, Balance, OrgId, Balance, AccountId),
     }
 );
 
@@ -59,6 +75,7 @@ decl_error! {
         AccountHasNoOwnershipInOrg,
         NotEnoughFundsInFreeToMakeTransfer,
         CannotDonateToOrgThatDNE,
+        QuadraticDonationsNotSupported,
     }
 }
 
@@ -66,21 +83,23 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::make_prop_donation(<org::Module<T>>::member_count(org))]
         fn make_prop_donation(
             origin,
             org: T::OrgId,
             remainder_recipient: T::AccountId,
+            currency_id: CurrencyIdOf<T>,
             amt: BalanceOf<T>
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let (
                 amt_transferred_to_org,
                 remainder_transferred_to_acc
-            ) = Self::donate(&sender, OrgRep::Weighted(org), &remainder_recipient, amt)?;
+            ) = Self::donate(&sender, OrgRep::Weighted(org), &remainder_recipient, currency_id, amt)?;
             Self::deposit_event(
                 RawEvent::PropDonationExecuted(
                     sender,
+                    currency_id,
                     amt_transferred_to_org,
                     org,
                     remainder_transferred_to_acc,
@@ -89,21 +108,23 @@ decl_module! {
             );
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::make_equal_donation(<org::Module<T>>::member_count(org))]
         fn make_equal_donation(
             origin,
             org: T::OrgId,
             remainder_recipient: T::AccountId,
+            currency_id: CurrencyIdOf<T>,
             amt: BalanceOf<T>,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let (
                 amt_transferred_to_org,
                 remainder_transferred_to_acc
-            ) = Self::donate(&sender, OrgRep::Equal(org), &remainder_recipient, amt)?;
+            ) = Self::donate(&sender, OrgRep::Equal(org), &remainder_recipient, currency_id, amt)?;
             Self::deposit_event(
                 RawEvent::EqualDonationExecuted(
                     sender,
+                    currency_id,
                     amt_transferred_to_org,
                     org,
                     remainder_transferred_to_acc,
@@ -121,9 +142,10 @@ impl<T: Trait> Module<T> {
         sender: &T::AccountId,
         recipient: OrgRep<T::OrgId>,
         remainder_recipient: &T::AccountId,
+        currency_id: CurrencyIdOf<T>,
         amt: BalanceOf<T>,
     ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
-        let free = T::Currency::free_balance(sender);
+        let free = T::Currency::free_balance(currency_id, sender);
         let _ = free
             .checked_sub(&amt)
             .ok_or(Error::<T>::NotEnoughFundsInFreeToMakeTransfer)?;
@@ -146,6 +168,7 @@ impl<T: Trait> Module<T> {
                             org_id,
                         )?;
                         T::Currency::transfer(
+                            currency_id,
                             sender,
                             &acc,
                             amt_due,
@@ -171,6 +194,7 @@ impl<T: Trait> Module<T> {
                     .into_iter()
                     .map(|acc: T::AccountId| -> DispatchResult {
                         T::Currency::transfer(
+                            currency_id,
                             sender,
                             &acc,
                             equal_payment,
@@ -182,9 +206,13 @@ impl<T: Trait> Module<T> {
                     .collect::<DispatchResult>()?;
                 amt - transferred_amt
             }
+            OrgRep::Quadratic(_) => {
+                return Err(Error::<T>::QuadraticDonationsNotSupported.into())
+            }
         };
         // transfer remainder to remainder recipient
         T::Currency::transfer(
+            currency_id,
             sender,
             remainder_recipient,
             remainder,
@@ -5,6 +5,7 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::Currency,
     weights::Weight,
 };
 use sp_core::H256;
@@ -23,7 +24,9 @@ pub type AccountId = u64;
 pub type BlockNumber = u64;
 
 impl_outer_origin! {
-    pub enum Origin for Test where system = frame_system {}
+    pub enum Origin for Test where system = frame_system {
+        org<T>,
+    }
 }
 
 mod donate {
@@ -34,7 +37,9 @@ impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
         pallet_balances<T>,
+        tokens<T>,
         org<T>,
+        vote<T>,
         donate<T>,
     }
 }
@@ -85,22 +90,70 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const NativeCurrencyId: u32 = 0;
+}
+impl tokens::Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type CurrencyId = u32;
+    type NativeCurrency = Balances;
+    type GetNativeCurrencyId = NativeCurrencyId;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: u64 = 5;
+    pub const SupervisorTermLength: u64 = 10;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32; // TODO: replace with utils_identity::Cid
     type OrgId = u64;
     type Shares = u64;
+    type OrgOrigin = org::EnsureOrg<Test>;
+    type ElectionId = u64;
+    type SupervisorVote = vote::Module<Test>;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const MaxDelegators: u32 = 3;
+    pub const MaxOptions: u32 = 5;
+    pub const VoteRetentionPeriod: Option<u64> = None;
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type VoteId = u64;
+    type Signal = u64;
+    type ThresholdId = u64;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type MaxDelegators = MaxDelegators;
+    type MaxOptions = MaxOptions;
+    type VoteRetentionPeriod = VoteRetentionPeriod;
+    type WeightInfo = ();
 }
 impl Trait for Test {
     type Event = TestEvent;
-    type Currency = Balances;
+    type Currency = Tokens;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = tokens::Module<Test>;
 pub type Org = org::Module<Test>;
 pub type Donate = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u64, u32, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -124,6 +177,11 @@ fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    tokens::GenesisConfig::<Test> {
+        endowed_accounts: vec![(1, 1, 600)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     org::GenesisConfig::<Test> {
         first_organization_supervisor: 1,
         first_organization_value_constitution: 1738,
@@ -159,14 +217,14 @@ fn check_donation_accuracy() {
         let two = Origin::signed(2);
         assert_eq!(Balances::total_balance(&2), 98);
         // only 54 actually transferred because it rounds down 1/6 * 60 = 9
-        assert_ok!(Donate::make_prop_donation(one, 1, 3, 60));
+        assert_ok!(Donate::make_prop_donation(one, 1, 3, 0, 60));
         // 98 + 9 = 107
         assert_eq!(Balances::total_balance(&2), 107);
         // 200 + 9 + 6 = 215
         assert_eq!(Balances::total_balance(&3), 215);
         // 100 - 60 + 9 = 49
         assert_eq!(Balances::total_balance(&1), 49);
-        assert_ok!(Donate::make_prop_donation(two, 1, 3, 20));
+        assert_ok!(Donate::make_prop_donation(two, 1, 3, 0, 20));
         // 49 + (20/6 ~= 3) = 52
         assert_eq!(Balances::total_balance(&1), 52);
         // 107 - 20 + 3 = 90
@@ -175,3 +233,26 @@ fn check_donation_accuracy() {
         assert_eq!(Balances::total_balance(&3), 220);
     });
 }
+
+#[test]
+fn donation_in_non_native_asset() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Donate::make_equal_donation(Origin::signed(2), 1, 2, 1, 60),
+            Error::<Test>::NotEnoughFundsInFreeToMakeTransfer
+        );
+        assert_ok!(Donate::make_equal_donation(Origin::signed(1), 1, 1, 1, 60));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::EqualDonationExecuted(1, 1, 54, 1, 6, 1)
+        );
+        // 1/6 * 60 rounds down to 9
+        for i in 2u64..7u64 {
+            assert_eq!(Tokens::free_balance(1, &i), 9);
+        }
+        // 600 - 60 + 9 + 6 (remainder) = 555
+        assert_eq!(Tokens::free_balance(1, &1), 555);
+        // native balances are untouched
+        assert_eq!(Balances::total_balance(&1), 100);
+    });
+}
@@ -0,0 +1,38 @@
+//! Drip pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn rate<T: Trait>() -> DripRate<T::BlockNumber, BalanceOf<T>> {
+    DripRate::new(10u32.into(), 10u32.into())
+}
+
+benchmarks! {
+    _ { }
+
+    start_drip {
+        let caller: T::AccountId = whitelisted_caller();
+        let destination: T::AccountId = account("destination", 0, SEED);
+    }: _(RawOrigin::Signed(caller), destination, T::Currency::native_currency_id(), rate::<T>())
+    verify {
+        assert_eq!(Module::<T>::open_drip_counter(), 1);
+    }
+
+    cancel_drip {
+        let caller: T::AccountId = whitelisted_caller();
+        let destination: T::AccountId = account("destination", 0, SEED);
+        Module::<T>::start_drip(RawOrigin::Signed(caller.clone()).into(), destination, T::Currency::native_currency_id(), rate::<T>())?;
+        let id = Module::<T>::drip_id_counter();
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(Module::<T>::drips(id).is_none());
+    }
+}
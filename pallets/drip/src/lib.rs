@@ -5,6 +5,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Drip fund stream
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -16,10 +19,8 @@ use frame_support::{
     decl_storage,
     ensure,
     storage::IterableStorageMap,
-    traits::{
-        Currency,
-        ExistenceRequirement,
-    },
+    traits::ExistenceRequirement,
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -48,12 +49,21 @@ use util::{
     traits::{
         GenerateUniqueID,
         IDIsAvailable,
+        MultiCurrency,
     },
 };
 
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+type BalanceOf<T> = <<T as Trait>::Currency as MultiCurrency<
     <T as system::Trait>::AccountId,
 >>::Balance;
+type CurrencyIdOf<T> = <<T as Trait>::Currency as MultiCurrency<
+    <T as system::Trait>::AccountId,
+>>::CurrencyId;
+
+pub trait WeightInfo {
+    fn start_drip() -> Weight;
+    fn cancel_drip() -> Weight;
+}
 
 pub trait Trait: system::Trait {
     /// Overarching event type
@@ -72,8 +82,11 @@ pub trait Trait: system::Trait {
         + PartialEq
         + Zero;
 
-    /// Currency type
-    type Currency: Currency<Self::AccountId>;
+    /// Multi-asset currency type
+    type Currency: MultiCurrency<Self::AccountId>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -82,10 +95,11 @@ decl_event!(
         <T as Trait>::DripId,
         <T as frame_system::Trait>::AccountId,
         <T as frame_system::Trait>::BlockNumber,
+        CurrencyId = CurrencyIdOf<T>,
         Balance = BalanceOf<T>,
     {
-        /// Drip identifier, First payment block, Source, Destination, Amount Per Period, Period Length
-        DripStarted(DripId, BlockNumber, AccountId, AccountId, Balance, BlockNumber),
+        /// Drip identifier, First payment block, Source, Destination, Asset, Amount Per Period, Period Length
+        DripStarted(DripId, BlockNumber, AccountId, AccountId, CurrencyId, Balance, BlockNumber),
         /// Drip from Source to Destination of Amount
         Dripped(AccountId, AccountId, Balance),
         /// Drip identifier at this BlockNumber with this drip info
@@ -122,6 +136,10 @@ decl_storage! {
                         >,
                     >
                 >;
+
+        /// The asset each drip pays out in
+        pub DripCurrency get(fn drip_currency): map
+            hasher(blake2_128_concat) T::DripId => CurrencyIdOf<T>;
     }
 }
 
@@ -130,10 +148,11 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::start_drip()]
         fn start_drip(
             origin,
             destination: T::AccountId,
+            currency_id: CurrencyIdOf<T>,
             rate: DripRate<T::BlockNumber, BalanceOf<T>>,
         ) -> DispatchResult {
             let source = ensure_signed(origin)?;
@@ -144,6 +163,7 @@ decl_module! {
             let drip = Drip::new(source.clone(), destination.clone(), rate);
             let id = Self::generate_unique_id();
             <Drips<T>>::insert(id, drip);
+            <DripCurrency<T>>::insert(id, currency_id);
             OpenDripCounter::mutate(|n| *n += 1u32);
             Self::deposit_event(
                 RawEvent::DripStarted(
@@ -151,6 +171,7 @@ decl_module! {
                     first_payment_block,
                     source,
                     destination,
+                    currency_id,
                     rate.amount(),
                     rate.period_length()
                 )
@@ -158,7 +179,7 @@ decl_module! {
             Ok(())
         }
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::cancel_drip()]
         fn cancel_drip(
             origin,
             id: T::DripId
@@ -167,6 +188,7 @@ decl_module! {
             let drip = <Drips<T>>::get(id).ok_or(Error::<T>::DripDNE)?;
             ensure!(drip.source() == caller, Error::<T>::NotAuthorizedToCancelDrip);
             <Drips<T>>::remove(id);
+            <DripCurrency<T>>::remove(id);
             OpenDripCounter::mutate(|n| *n -= 1u32);
             let now = <system::Module<T>>::block_number();
             Self::deposit_event(
@@ -187,7 +209,7 @@ decl_module! {
            // TODO: sweep periodically instead of scanning after every block (which is what it does now)
             <Drips<T>>::iter()
                 .filter(|(_, drip)| current_block % drip.rate().period_length() == 0u32.into())
-                .for_each(|(_, drip)| Self::pay(drip));
+                .for_each(|(id, drip)| Self::pay(id, drip));
         }
     }
 }
@@ -209,10 +231,14 @@ impl<T: Trait> Module<T> {
             None
         }
     }
-    fn pay(drip: Drip<T::AccountId, DripRate<T::BlockNumber, BalanceOf<T>>>) {
+    fn pay(
+        id: T::DripId,
+        drip: Drip<T::AccountId, DripRate<T::BlockNumber, BalanceOf<T>>>,
+    ) {
         let (src, dest, amt) =
             (&drip.source(), &drip.destination(), drip.rate().amount());
         if T::Currency::transfer(
+            <DripCurrency<T>>::get(id),
             src,
             dest,
             amt,
@@ -5,7 +5,10 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
-    traits::OnFinalize,
+    traits::{
+        Currency,
+        OnFinalize,
+    },
     weights::Weight,
 };
 use sp_core::H256;
@@ -31,6 +34,7 @@ impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
         pallet_balances<T>,
+        tokens<T>,
         drip<T>,
     }
 }
@@ -81,16 +85,29 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const NativeCurrencyId: u32 = 0;
+}
+impl tokens::Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type CurrencyId = u32;
+    type NativeCurrency = Balances;
+    type GetNativeCurrencyId = NativeCurrencyId;
+    type WeightInfo = ();
+}
 impl Trait for Test {
     type Event = TestEvent;
     type DripId = u64;
-    type Currency = Balances;
+    type Currency = Tokens;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = tokens::Module<Test>;
 pub type Drip = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u32, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -129,6 +146,11 @@ fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    tokens::GenesisConfig::<Test> {
+        endowed_accounts: vec![(1, 1, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     let mut ext: sp_io::TestExternalities = t.into();
     ext.execute_with(|| System::set_block_number(1));
     ext
@@ -149,19 +171,19 @@ fn drip_started() {
         let ten_ten = DripRate::new(10, 10);
         let zero_ten = DripRate::new(10, 0);
         assert_noop!(
-            Drip::start_drip(Origin::signed(1), 2, zero_ten),
+            Drip::start_drip(Origin::signed(1), 2, 0, zero_ten),
             Error::<Test>::RatePeriodLengthMustBeGreaterThanZero
         );
         assert_noop!(
-            Drip::start_drip(Origin::signed(1), 1, ten_ten),
+            Drip::start_drip(Origin::signed(1), 1, 0, ten_ten),
             Error::<Test>::DoNotDripToSelf
         );
         assert_noop!(
-            Drip::start_drip(Origin::signed(1), 1, ten_ten),
+            Drip::start_drip(Origin::signed(1), 1, 0, ten_ten),
             Error::<Test>::DoNotDripToSelf
         );
         System::set_block_number(8);
-        assert_ok!(Drip::start_drip(Origin::signed(1), 2, ten_ten));
+        assert_ok!(Drip::start_drip(Origin::signed(1), 2, 0, ten_ten));
         run_to_block(14);
         assert_eq!(Balances::total_balance(&1), 990);
         assert_eq!(Balances::total_balance(&2), 110);
@@ -181,7 +203,7 @@ fn drip_cancelled() {
         assert_eq!(Balances::total_balance(&2), 100);
         let ten_ten = DripRate::new(10, 10);
         System::set_block_number(8);
-        assert_ok!(Drip::start_drip(Origin::signed(1), 2, ten_ten));
+        assert_ok!(Drip::start_drip(Origin::signed(1), 2, 0, ten_ten));
         run_to_block(14);
         assert_eq!(Balances::total_balance(&1), 990);
         assert_eq!(Balances::total_balance(&2), 110);
@@ -202,3 +224,23 @@ fn drip_cancelled() {
         assert_eq!(Balances::total_balance(&2), 120);
     });
 }
+
+#[test]
+fn drip_in_non_native_asset() {
+    new_test_ext().execute_with(|| {
+        let ten_ten = DripRate::new(10, 10);
+        System::set_block_number(8);
+        assert_ok!(Drip::start_drip(Origin::signed(1), 2, 1, ten_ten));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DripStarted(1, 18, 1, 2, 1, 10, 10)
+        );
+        assert_eq!(Drip::drip_currency(1), 1);
+        run_to_block(21);
+        assert_eq!(Tokens::free_balance(1, &1), 80);
+        assert_eq!(Tokens::free_balance(1, &2), 20);
+        assert_eq!(Balances::total_balance(&1), 1000);
+        assert_ok!(Drip::cancel_drip(Origin::signed(1), 1));
+        assert!(!DripCurrency::<Test>::contains_key(1));
+    });
+}
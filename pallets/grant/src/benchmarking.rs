@@ -0,0 +1,185 @@
+//! Grant pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+use util::{
+    meta::VoteCall,
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+    vote::Threshold,
+};
+
+const SEED: u32 = 0;
+const MAX_MEMBERS: u32 = 100;
+
+fn native<T: Trait>() -> CurrencyIdOf<T> {
+    <T as donate::Trait>::Currency::native_currency_id()
+}
+
+fn funded<T: Trait>(who: &T::AccountId) {
+    <T as donate::Trait>::Currency::deposit(
+        native::<T>(),
+        who,
+        BalanceOf::<T>::max_value(),
+    )
+    .expect("native deposit works");
+}
+
+/// Registers an org with `first` as supervisor and the first member
+fn org_with_members<T: Trait>(first: T::AccountId, m: u32) -> T::OrgId {
+    let mut members = vec![first.clone()];
+    members.extend((1..m).map(|i| account("member", i, SEED)));
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        Some(first),
+        T::Cid::default(),
+    )
+    .expect("org registration with supervisor works")
+}
+
+/// Creates a foundation with `sudo` as sudo and the first member of its `m` member review committee
+fn foundation<T: Trait>(sudo: T::AccountId, m: u32) -> T::FoundationId {
+    funded::<T>(&sudo);
+    let committee = org_with_members::<T>(sudo.clone(), m);
+    let governance = ResolutionMetadata::new(
+        Some(sudo.clone()),
+        Some(VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(committee),
+            Threshold::new(m.into(), None),
+            None,
+        ))),
+    )
+    .expect("sudo and vote are set");
+    Module::<T>::create_foundation(
+        RawOrigin::Signed(sudo).into(),
+        T::Cid::default(),
+        native::<T>(),
+        T::MinDeposit::get() * 1000u32.into(),
+        governance,
+    )
+    .expect("funded sudo can create foundation");
+    Module::<T>::foundation_nonce()
+}
+
+fn application<T: Trait>(foundation_id: T::FoundationId) -> T::ApplicationId {
+    let applicant: T::AccountId = account("applicant", 0, SEED);
+    Module::<T>::submit_application(
+        RawOrigin::Signed(applicant.clone()).into(),
+        foundation_id,
+        T::Cid::default(),
+        Recipient::new(applicant, None),
+        T::MinContribution::get(),
+    )
+    .expect("foundation exists");
+    Module::<T>::application_nonce()
+}
+
+fn milestone<T: Trait>(sudo: T::AccountId, m: u32) -> (T::ApplicationId, T::MilestoneId) {
+    let foundation_id = foundation::<T>(sudo.clone(), m);
+    let application_id = application::<T>(foundation_id);
+    Module::<T>::approve_application(RawOrigin::Signed(sudo).into(), application_id)
+        .expect("sudo can approve application");
+    let applicant: T::AccountId = account("applicant", 0, SEED);
+    Module::<T>::submit_milestone(
+        RawOrigin::Signed(applicant.clone()).into(),
+        foundation_id,
+        application_id,
+        T::Cid::default(),
+        Recipient::new(applicant, None),
+        T::MinContribution::get(),
+    )
+    .expect("application is approved");
+    (application_id, Module::<T>::milestone_nonce(application_id))
+}
+
+benchmarks! {
+    _ { }
+
+    create_foundation {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let governance = ResolutionMetadata::new(Some(caller.clone()), None)
+            .expect("sudo is set");
+    }: _(RawOrigin::Signed(caller), T::Cid::default(), native::<T>(), T::MinDeposit::get(), governance)
+
+    donate_to_foundation {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let foundation_id = foundation::<T>(account("sudo", 0, SEED), 1);
+    }: _(RawOrigin::Signed(caller), foundation_id, T::MinContribution::get())
+
+    submit_application {
+        let caller: T::AccountId = whitelisted_caller();
+        let foundation_id = foundation::<T>(account("sudo", 0, SEED), 1);
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        foundation_id,
+        T::Cid::default(),
+        Recipient::new(caller, None),
+        T::MinContribution::get()
+    )
+
+    trigger_application_review {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let foundation_id = foundation::<T>(caller.clone(), m);
+        let application_id = application::<T>(foundation_id);
+    }: _(RawOrigin::Signed(caller), application_id)
+
+    approve_application {
+        let caller: T::AccountId = whitelisted_caller();
+        let foundation_id = foundation::<T>(caller.clone(), 1);
+        let application_id = application::<T>(foundation_id);
+    }: _(RawOrigin::Signed(caller), application_id)
+
+    reject_application {
+        let caller: T::AccountId = whitelisted_caller();
+        let foundation_id = foundation::<T>(caller.clone(), 1);
+        let application_id = application::<T>(foundation_id);
+    }: _(RawOrigin::Signed(caller), application_id)
+    verify {
+        assert!(Module::<T>::applications(application_id).is_none());
+    }
+
+    submit_milestone {
+        let caller: T::AccountId = whitelisted_caller();
+        let sudo: T::AccountId = account("sudo", 0, SEED);
+        let foundation_id = foundation::<T>(sudo.clone(), 1);
+        let application_id = application::<T>(foundation_id);
+        Module::<T>::approve_application(RawOrigin::Signed(sudo).into(), application_id)?;
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        foundation_id,
+        application_id,
+        T::Cid::default(),
+        Recipient::new(caller, None),
+        T::MinContribution::get()
+    )
+
+    trigger_milestone_review {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let (application_id, milestone_id) = milestone::<T>(caller.clone(), m);
+    }: _(RawOrigin::Signed(caller), application_id, milestone_id)
+
+    approve_milestone {
+        let caller: T::AccountId = whitelisted_caller();
+        let (application_id, milestone_id) = milestone::<T>(caller.clone(), 1);
+    }: _(RawOrigin::Signed(caller), application_id, milestone_id)
+
+    reject_milestone {
+        let caller: T::AccountId = whitelisted_caller();
+        let (application_id, milestone_id) = milestone::<T>(caller.clone(), 1);
+    }: _(RawOrigin::Signed(caller), application_id, milestone_id)
+    verify {
+        assert!(Module::<T>::milestones(application_id, milestone_id).is_none());
+    }
+}
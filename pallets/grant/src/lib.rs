@@ -4,6 +4,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Grants module for short to long-term developer contracts
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -19,12 +22,10 @@ use frame_support::{
         IterableStorageMap,
     },
     traits::{
-        Currency,
         ExistenceRequirement,
         Get,
-        WithdrawReason,
-        WithdrawReasons,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -59,17 +60,19 @@ use util::{
     },
     organization::OrgRep,
     traits::{
+        EnsureOrgOrigin,
         GetVoteOutcome,
-        GroupMembership,
+        MultiCurrency,
         OpenVote,
     },
     vote::VoteOutcome,
 };
 
 // type aliases
-type BalanceOf<T> = <<T as donate::Trait>::Currency as Currency<
+type BalanceOf<T> = <<T as donate::Trait>::Currency as MultiCurrency<
     <T as frame_system::Trait>::AccountId,
 >>::Balance;
+type CurrencyIdOf<T> = donate::CurrencyIdOf<T>;
 type GovernanceOf<T> = ResolutionMetadata<
     <T as frame_system::Trait>::AccountId,
     VoteMetadata<
@@ -101,6 +104,19 @@ type Milestone<T> = MilestoneSubmission<
     MilestoneStatus<<T as vote::Trait>::VoteId>,
 >;
 
+pub trait WeightInfo {
+    fn create_foundation() -> Weight;
+    fn donate_to_foundation() -> Weight;
+    fn submit_application() -> Weight;
+    fn trigger_application_review(m: u32) -> Weight;
+    fn approve_application() -> Weight;
+    fn reject_application() -> Weight;
+    fn submit_milestone() -> Weight;
+    fn trigger_milestone_review(m: u32) -> Weight;
+    fn approve_milestone() -> Weight;
+    fn reject_milestone() -> Weight;
+}
+
 pub trait Trait:
     frame_system::Trait + org::Trait + vote::Trait + donate::Trait
 {
@@ -154,6 +170,9 @@ pub trait Trait:
 
     /// Minimum contribution to open foundation
     type MinContribution: Get<BalanceOf<Self>>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -165,10 +184,11 @@ decl_event!(
         <T as Trait>::FoundationId,
         <T as Trait>::ApplicationId,
         <T as Trait>::MilestoneId,
+        CurrencyId = CurrencyIdOf<T>,
         Balance = BalanceOf<T>,
         Recipient = RecipientOf<T>,
     {
-        FoundationCreated(FoundationId, Balance, Cid),
+        FoundationCreated(FoundationId, CurrencyId, Balance, Cid),
         FoundationDonation(AccountId, Balance, FoundationId, Balance),
         ApplicationSubmitted(FoundationId, ApplicationId, Recipient, Balance, Cid),
         ApplicationReviewTriggered(FoundationId, ApplicationId, VoteId),
@@ -220,6 +240,9 @@ decl_storage! {
         /// Foundations
         pub Foundations get(fn foundations): map
             hasher(blake2_128_concat) T::FoundationId => Option<FoundationOf<T>>;
+        /// The asset each foundation holds and pays grants in
+        pub FoundationCurrency get(fn foundation_currency): map
+            hasher(blake2_128_concat) T::FoundationId => CurrencyIdOf<T>;
         /// History of Foundation Inflows
         pub FoundationDonations get(fn bounty_tips): double_map
             hasher(blake2_128_concat) T::FoundationId,
@@ -245,31 +268,35 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_foundation()]
         fn create_foundation(
             origin,
             info: T::Cid,
+            currency_id: CurrencyIdOf<T>,
             amount: BalanceOf<T>,
             governance: GovernanceOf<T>,
         ) -> DispatchResult {
             let depositer = ensure_signed(origin)?;
             ensure!(amount >= T::MinDeposit::get(), Error::<T>::DepositBelowMinDeposit);
-            let imb = <T as donate::Trait>::Currency::withdraw(
+            // TODO: screen validity of `governance` input in context of registered orgs
+            let foundation = FoundationOf::<T>::new(info.clone(), amount, governance);
+            // the identifier is claimed only once the funds are transferred
+            let id = Self::next_foundation_uid();
+            <T as donate::Trait>::Currency::transfer(
+                currency_id,
                 &depositer,
+                &Self::foundation_account_id(id),
                 amount,
-                WithdrawReasons::from(WithdrawReason::Transfer),
                 ExistenceRequirement::AllowDeath,
             )?;
-            // TODO: screen validity of `governance` input in context of registered orgs
-            let foundation = FoundationOf::<T>::new(info.clone(), amount, governance);
-            let id = Self::foundation_generate_uid();
-            <T as donate::Trait>::Currency::resolve_creating(&Self::foundation_account_id(id), imb);
+            <FoundationNonce<T>>::put(id);
             <Foundations<T>>::insert(id, foundation);
+            <FoundationCurrency<T>>::insert(id, currency_id);
             <FoundationDonations<T>>::insert(id, &depositer, amount);
-            Self::deposit_event(RawEvent::FoundationCreated(id, amount, info));
+            Self::deposit_event(RawEvent::FoundationCreated(id, currency_id, amount, info));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::donate_to_foundation()]
         fn donate_to_foundation(
             origin,
             id: T::FoundationId,
@@ -279,6 +306,7 @@ decl_module! {
             ensure!(amount >= T::MinContribution::get(), Error::<T>::ContributionBelowMinContribution);
             let foundation = <Foundations<T>>::get(id).ok_or(Error::<T>::FoundationDNE)?;
             <T as donate::Trait>::Currency::transfer(
+                <FoundationCurrency<T>>::get(id),
                 &giver,
                 &Self::foundation_account_id(id),
                 amount,
@@ -294,7 +322,7 @@ decl_module! {
             Self::deposit_event(RawEvent::FoundationDonation(giver, new_amount, id, total));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_application()]
         fn submit_application(
             origin,
             foundation_id: T::FoundationId,
@@ -310,19 +338,15 @@ decl_module! {
             Self::deposit_event(RawEvent::ApplicationSubmitted(foundation_id, id, recipient, amount_requested, submission_ref));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::trigger_application_review(Self::application_org_size(*application_id))]
         fn trigger_application_review(
             origin,
             application_id: T::ApplicationId,
         ) -> DispatchResult {
-            let trigger_er = ensure_signed(origin)?;
             let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
             ensure!(app.awaiting_review(), Error::<T>::ApplicationNotInValidStateToTriggerReview);
             let foundation = <Foundations<T>>::get(app.foundation_id()).ok_or(Error::<T>::FoundationDNE)?;
-            let auth = if let Some(gov) = foundation.gov().vote() {
-                <org::Module<T>>::is_member_of_group(gov.org().org(), &trigger_er) || foundation.gov().is_sudo(&trigger_er)
-            } else { false };
-            ensure!(auth, Error::<T>::NotAuthorizedToTriggerApplicationReview);
+            Self::ensure_review_trigger(origin, &foundation.gov(), Error::<T>::NotAuthorizedToTriggerApplicationReview)?;
             let new_vote_id = match foundation.gov().vote().ok_or(Error::<T>::NotAuthorizedToTriggerApplicationReview)? {
                 VoteMetadata::Signal(v) => <vote::Module<T>>::open_vote(Some(app.submission_ref()), v.org, v.threshold, v.duration)?,
                 VoteMetadata::Percentage(v) => <vote::Module<T>>::open_percent_vote(Some(app.submission_ref()), v.org, v.threshold, v.duration)?,
@@ -332,36 +356,34 @@ decl_module! {
             Self::deposit_event(RawEvent::ApplicationReviewTriggered(app.foundation_id(), application_id, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::approve_application()]
         fn approve_application(
             origin,
             application_id: T::ApplicationId,
         ) -> DispatchResult {
-            let purported_sudo = ensure_signed(origin)?;
             let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
             ensure!(app.awaiting_review(), Error::<T>::ApplicationNotInValidStateToApprove);
             let foundation = <Foundations<T>>::get(app.foundation_id()).ok_or(Error::<T>::FoundationDNE)?;
-            ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToApproveApplication);
+            Self::ensure_foundation_sudo(origin, &foundation.gov(), Error::<T>::NotAuthorizedToApproveApplication)?;
             let new_app = app.set_state(ApplicationState::ApprovedAndLive);
             <Applications<T>>::insert(application_id, new_app);
             Self::deposit_event(RawEvent::ApplicationApproved(app.foundation_id(), application_id, app.submission_ref()));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::reject_application()]
         fn reject_application(
             origin,
             application_id: T::ApplicationId,
         ) -> DispatchResult {
-            let purported_sudo = ensure_signed(origin)?;
             let app = <Applications<T>>::get(application_id).ok_or(Error::<T>::ApplicationDNE)?;
             ensure!(app.awaiting_review(), Error::<T>::ApplicationNotInValidStateToReject);
             let foundation = <Foundations<T>>::get(app.foundation_id()).ok_or(Error::<T>::FoundationDNE)?;
-            ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToRejectApplication);
+            Self::ensure_foundation_sudo(origin, &foundation.gov(), Error::<T>::NotAuthorizedToRejectApplication)?;
             <Applications<T>>::remove(application_id);
             Self::deposit_event(RawEvent::ApplicationRejected(app.foundation_id(), application_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_milestone()]
         fn submit_milestone(
             origin,
             foundation_id: T::FoundationId,
@@ -380,20 +402,16 @@ decl_module! {
             Self::deposit_event(RawEvent::MilestoneSubmitted(foundation_id, application_id, id, recipient, amount_requested, submission_ref));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::trigger_milestone_review(Self::milestone_org_size(*application_id, *milestone_id))]
         fn trigger_milestone_review(
             origin,
             application_id: T::ApplicationId,
             milestone_id: T::MilestoneId,
         ) -> DispatchResult {
-            let trigger_er = ensure_signed(origin)?;
             let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
             ensure!(mile.awaiting_review(), Error::<T>::MilestoneNotInValidStateToTriggerReview);
             let foundation = <Foundations<T>>::get(mile.base_foundation()).ok_or(Error::<T>::FoundationDNE)?;
-            let auth = if let Some(gov) = foundation.gov().vote() {
-                <org::Module<T>>::is_member_of_group(gov.org().org(), &trigger_er) || foundation.gov().is_sudo(&trigger_er)
-            } else { false };
-            ensure!(auth, Error::<T>::NotAuthorizedToTriggerMilestoneReview);
+            Self::ensure_review_trigger(origin, &foundation.gov(), Error::<T>::NotAuthorizedToTriggerMilestoneReview)?;
             let new_vote_id = match foundation.gov().vote().ok_or(Error::<T>::NotAuthorizedToTriggerMilestoneReview)? {
                 VoteMetadata::Signal(v) => <vote::Module<T>>::open_vote(Some(mile.submission()), v.org, v.threshold, v.duration)?,
                 VoteMetadata::Percentage(v) => <vote::Module<T>>::open_percent_vote(Some(mile.submission()), v.org, v.threshold, v.duration)?,
@@ -403,33 +421,31 @@ decl_module! {
             Self::deposit_event(RawEvent::MilestoneReviewTriggered(mile.base_foundation(), application_id, milestone_id, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::approve_milestone()]
         fn approve_milestone(
             origin,
             application_id: T::ApplicationId,
             milestone_id: T::MilestoneId,
         ) -> DispatchResult {
-            let purported_sudo = ensure_signed(origin)?;
             let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
             ensure!(mile.awaiting_review(), Error::<T>::MilestoneNotInValidStateToApprove);
             let foundation = <Foundations<T>>::get(mile.base_foundation()).ok_or(Error::<T>::FoundationDNE)?;
-            ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToApproveMilestone);
+            Self::ensure_foundation_sudo(origin, &foundation.gov(), Error::<T>::NotAuthorizedToApproveMilestone)?;
             let new_mile = Self::approve_milestone_and_try_transfer(&mile);
             <Milestones<T>>::insert(application_id, milestone_id, new_mile);
             Self::deposit_event(RawEvent::MilestoneApproved(mile.base_foundation(), application_id, milestone_id, mile.submission()));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::reject_milestone()]
         fn reject_milestone(
             origin,
             application_id: T::ApplicationId,
             milestone_id: T::MilestoneId,
         ) -> DispatchResult {
-            let purported_sudo = ensure_signed(origin)?;
             let mile = <Milestones<T>>::get(application_id, milestone_id).ok_or(Error::<T>::MilestoneDNE)?;
             ensure!(mile.awaiting_review(), Error::<T>::MilestoneNotInValidStateToReject);
             let foundation = <Foundations<T>>::get(mile.base_foundation()).ok_or(Error::<T>::FoundationDNE)?;
-            ensure!(foundation.gov().is_sudo(&purported_sudo), Error::<T>::NotAuthorizedToRejectMilestone);
+            Self::ensure_foundation_sudo(origin, &foundation.gov(), Error::<T>::NotAuthorizedToRejectMilestone)?;
             <Milestones<T>>::remove(application_id, milestone_id);
             Self::deposit_event(RawEvent::MilestoneRejected(mile.base_foundation(), application_id, milestone_id));
             Ok(())
@@ -491,12 +507,12 @@ impl<T: Trait> Module<T> {
     fn foundation_id_is_available(id: T::FoundationId) -> bool {
         <Foundations<T>>::get(id).is_none()
     }
-    fn foundation_generate_uid() -> T::FoundationId {
+    /// Returns the next available foundation identifier without claiming it
+    fn next_foundation_uid() -> T::FoundationId {
         let mut id_counter = <FoundationNonce<T>>::get() + 1u32.into();
         while !Self::foundation_id_is_available(id_counter) {
             id_counter += 1u32.into();
         }
-        <FoundationNonce<T>>::put(id_counter);
         id_counter
     }
     fn application_id_is_available(id: T::ApplicationId) -> bool {
@@ -526,15 +542,84 @@ impl<T: Trait> Module<T> {
     }
 }
 
+// Permission helpers
+impl<T: Trait> Module<T> {
+    /// The foundation's sudo or its reviewing org acting through a passed vote
+    fn ensure_foundation_sudo(
+        origin: T::Origin,
+        gov: &GovernanceOf<T>,
+        err: Error<T>,
+    ) -> DispatchResult {
+        let origin = if let Some(vote) = gov.vote() {
+            match org::EnsureOrgVotePassed::<T>::try_org_origin(
+                origin,
+                vote.org().org(),
+            ) {
+                Ok(()) => return Ok(()),
+                Err(origin) => origin,
+            }
+        } else {
+            origin
+        };
+        let caller = ensure_signed(origin)?;
+        ensure!(gov.is_sudo(&caller), err);
+        Ok(())
+    }
+    /// Members of the reviewing org, the org itself or the foundation's sudo trigger review votes
+    fn ensure_review_trigger(
+        origin: T::Origin,
+        gov: &GovernanceOf<T>,
+        err: Error<T>,
+    ) -> DispatchResult {
+        let vote = match gov.vote() {
+            Some(vote) => vote,
+            None => return Err(err.into()),
+        };
+        match org::EnsureOrgMember::<T>::try_org_origin(
+            origin,
+            vote.org().org(),
+        ) {
+            Ok(_) => Ok(()),
+            Err(origin) => {
+                let caller = ensure_signed(origin)?;
+                ensure!(gov.is_sudo(&caller), err);
+                Ok(())
+            }
+        }
+    }
+}
+
 // Storage helpers
 impl<T: Trait> Module<T> {
+    /// Number of members in the org that votes on the foundation's reviews
+    fn foundation_org_size(id: T::FoundationId) -> u32 {
+        <Foundations<T>>::get(id)
+            .and_then(|f| f.gov().vote())
+            .map(|v| <org::Module<T>>::member_count(v.org().org()))
+            .unwrap_or_default()
+    }
+    fn application_org_size(id: T::ApplicationId) -> u32 {
+        <Applications<T>>::get(id)
+            .map(|app| Self::foundation_org_size(app.foundation_id()))
+            .unwrap_or_default()
+    }
+    fn milestone_org_size(
+        application_id: T::ApplicationId,
+        milestone_id: T::MilestoneId,
+    ) -> u32 {
+        <Milestones<T>>::get(application_id, milestone_id)
+            .map(|m| Self::foundation_org_size(m.base_foundation()))
+            .unwrap_or_default()
+    }
     /// Approve milestone and TRY to execute transfer
     fn approve_milestone_and_try_transfer(m: &Milestone<T>) -> Milestone<T> {
+        let currency_id = <FoundationCurrency<T>>::get(m.base_foundation());
         if let Some(o) = m.recipient().org() {
             if <donate::Module<T>>::donate(
                 &Self::foundation_account_id(m.base_foundation()),
                 o,
                 &m.recipient().account(),
+                currency_id,
                 m.payment(),
             )
             .is_ok()
@@ -544,6 +629,7 @@ impl<T: Trait> Module<T> {
                 m.set_state(MilestoneStatus::ApprovedButNotTransferred)
             }
         } else if <T as donate::Trait>::Currency::transfer(
+            currency_id,
             &Self::foundation_account_id(m.base_foundation()),
             &m.recipient().account(),
             m.payment(),
@@ -558,6 +644,7 @@ impl<T: Trait> Module<T> {
     }
     fn _recursive_remove_foundation(id: T::FoundationId) {
         <Foundations<T>>::remove(id);
+        <FoundationCurrency<T>>::remove(id);
         <Applications<T>>::iter()
             .filter(|(_, app)| app.foundation_id() == id)
             .for_each(|(app_id, _)| {
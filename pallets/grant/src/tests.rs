@@ -37,7 +37,9 @@ pub type AccountId = u64;
 pub type BlockNumber = u64;
 
 impl_outer_origin! {
-    pub enum Origin for Test where system = frame_system {}
+    pub enum Origin for Test where system = frame_system {
+        org<T>,
+    }
 }
 
 mod grant {
@@ -48,6 +50,7 @@ impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
         pallet_balances<T>,
+        tokens<T>,
         org<T>,
         vote<T>,
         donate<T>,
@@ -101,20 +104,61 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const NativeCurrencyId: u32 = 0;
+}
+impl tokens::Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type CurrencyId = u32;
+    type NativeCurrency = Balances;
+    type GetNativeCurrencyId = NativeCurrencyId;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: u64 = 5;
+    pub const SupervisorTermLength: u64 = 10;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type OrgOrigin = org::EnsureOrg<Test>;
+    type ElectionId = u64;
+    type SupervisorVote = vote::Module<Test>;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const MaxDelegators: u32 = 3;
+    pub const MaxOptions: u32 = 5;
+    pub const VoteRetentionPeriod: Option<u64> = None;
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type MaxDelegators = MaxDelegators;
+    type MaxOptions = MaxOptions;
+    type VoteRetentionPeriod = VoteRetentionPeriod;
+    type WeightInfo = ();
 }
 impl donate::Trait for Test {
     type Event = TestEvent;
-    type Currency = Balances;
+    type Currency = Tokens;
+    type WeightInfo = ();
 }
 parameter_types! {
     pub const BigFoundation: ModuleId = ModuleId(*b"big/fund");
@@ -129,15 +173,27 @@ impl Trait for Test {
     type BigFoundation = BigFoundation;
     type MinDeposit = MinDeposit;
     type MinContribution = MinContribution;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = tokens::Module<Test>;
 pub type Org = org::Module<Test>;
 pub type Vote = vote::Module<Test>;
 pub type Grant = Module<Test>;
 
 fn get_last_event(
-) -> RawEvent<u64, u32, u64, u64, u64, u64, u64, Recipient<u64, OrgRep<u64>>> {
+) -> RawEvent<
+    u64,
+    u32,
+    u64,
+    u64,
+    u64,
+    u64,
+    u32,
+    u64,
+    Recipient<u64, OrgRep<u64>>,
+> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -229,6 +285,7 @@ fn create_foundation_test() {
             Grant::create_foundation(
                 Origin::signed(1),
                 10u32,
+                0,
                 101u64,
                 sudo_threshold_no_vote()
             ),
@@ -242,6 +299,7 @@ fn create_foundation_test() {
             Grant::create_foundation(
                 Origin::signed(1),
                 10u32,
+                0,
                 19u64,
                 sudo_threshold_no_vote()
             ),
@@ -251,32 +309,35 @@ fn create_foundation_test() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             sudo_threshold_no_vote()
         ));
         assert_eq!(
             get_last_event(),
-            RawEvent::FoundationCreated(1u64, 20u64, 10u32)
+            RawEvent::FoundationCreated(1u64, 0, 20u64, 10u32)
         );
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             new_min_threshold_and_sudo()
         ));
         assert_eq!(
             get_last_event(),
-            RawEvent::FoundationCreated(2u64, 20u64, 10u32)
+            RawEvent::FoundationCreated(2u64, 0, 20u64, 10u32)
         );
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             new_min_threshold_no_sudo()
         ));
         assert_eq!(
             get_last_event(),
-            RawEvent::FoundationCreated(3u64, 20u64, 10u32)
+            RawEvent::FoundationCreated(3u64, 0, 20u64, 10u32)
         );
     });
 }
@@ -291,6 +352,7 @@ fn donate_2_foundation_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             sudo_threshold_no_vote()
         ));
@@ -308,7 +370,7 @@ fn donate_2_foundation_works() {
         );
         assert_eq!(
             get_last_event(),
-            RawEvent::FoundationCreated(1u64, 20u64, 10u32)
+            RawEvent::FoundationCreated(1u64, 0, 20u64, 10u32)
         );
         assert_ok!(Grant::donate_to_foundation(Origin::signed(2), 1, 10,));
         assert_eq!(
@@ -335,6 +397,7 @@ fn submit_application_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             sudo_threshold_no_vote()
         ));
@@ -369,6 +432,7 @@ fn trigger_app_review_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             sudo_threshold_no_vote()
         ));
@@ -391,6 +455,7 @@ fn trigger_app_review_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             new_min_threshold_and_sudo()
         ));
@@ -439,6 +504,7 @@ fn approve_reject_application_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             sudo_threshold_no_vote()
         ));
@@ -486,6 +552,7 @@ fn approve_reject_application_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             new_min_threshold_and_sudo(),
         ));
@@ -500,6 +567,7 @@ fn approve_reject_application_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             new_min_threshold_no_sudo(),
         ));
@@ -515,6 +583,19 @@ fn approve_reject_application_works() {
             Grant::reject_application(Origin::signed(1), 4,),
             Error::<Test>::NotAuthorizedToRejectApplication
         );
+        // but the reviewing org approves through a passed vote
+        assert_noop!(
+            Grant::approve_application(org::RawOrigin::Org(2).into(), 4,),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Grant::approve_application(
+            org::RawOrigin::Org(1).into(),
+            4,
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ApplicationApproved(3, 4, 14u32)
+        );
     });
 }
 
@@ -524,6 +605,7 @@ fn submit_milestone_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             sudo_threshold_no_vote()
         ));
@@ -600,6 +682,7 @@ fn trigger_milestone_review_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             sudo_threshold_no_vote()
         ));
@@ -626,6 +709,7 @@ fn trigger_milestone_review_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             new_min_threshold_and_sudo()
         ));
@@ -671,6 +755,7 @@ fn approve_reject_milestone_works() {
         assert_ok!(Grant::create_foundation(
             Origin::signed(1),
             10u32,
+            0,
             20u64,
             sudo_threshold_no_vote()
         ));
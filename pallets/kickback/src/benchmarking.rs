@@ -0,0 +1,67 @@
+//! Kickback pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+fn funded<T: Trait>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value());
+}
+
+fn kickback_event<T: Trait>(supervisor: T::AccountId) -> T::KickbackEventId {
+    Module::<T>::post_kickback_event(
+        RawOrigin::Signed(supervisor).into(),
+        T::IpfsReference::default(),
+        T::MinReservationReq::get(),
+        T::MaxAttendance::get(),
+    )
+    .expect("event within module limits");
+    Module::<T>::kickback_event_nonce()
+}
+
+benchmarks! {
+    _ { }
+
+    post_kickback_event {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(
+        RawOrigin::Signed(caller),
+        T::IpfsReference::default(),
+        T::MinReservationReq::get(),
+        T::MaxAttendance::get()
+    )
+
+    reserve_seat {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let id = kickback_event::<T>(account("supervisor", 0, SEED));
+    }: _(RawOrigin::Signed(caller.clone()), id)
+    verify {
+        assert!(Module::<T>::kickback_reservations(id, &caller).is_some());
+    }
+
+    publish_attendance_and_execute_redistribution {
+        let r in 1 .. T::MaxAttendance::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let id = kickback_event::<T>(caller.clone());
+        let mut present: Vec<T::AccountId> = Vec::new();
+        for i in 0..r {
+            let reserver: T::AccountId = account("reserver", i, SEED);
+            funded::<T>(&reserver);
+            Module::<T>::reserve_seat(RawOrigin::Signed(reserver.clone()).into(), id)?;
+            present.push(reserver);
+        }
+        present.sort();
+    }: _(RawOrigin::Signed(caller), id, present)
+    verify {
+        assert!(Module::<T>::kickback_events(id).is_none());
+    }
+}
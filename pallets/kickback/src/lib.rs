@@ -4,6 +4,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Kickback pallet for event management with incentives
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -20,6 +23,7 @@ use frame_support::{
         ExistenceRequirement,
         Get,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -52,6 +56,12 @@ type KickbackEventFor<T> = KickbackEvent<
     BalanceOf<T>,
 >;
 
+pub trait WeightInfo {
+    fn post_kickback_event() -> Weight;
+    fn reserve_seat() -> Weight;
+    fn publish_attendance_and_execute_redistribution(r: u32) -> Weight;
+}
+
 pub trait Trait: frame_system::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -83,6 +93,9 @@ pub trait Trait: frame_system::Trait {
 
     /// Maximum attendance limit for posted events
     type MaxAttendance: Get<u32>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -135,7 +148,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::post_kickback_event()]
         fn post_kickback_event(
             origin,
             info: T::IpfsReference,
@@ -151,7 +164,7 @@ decl_module! {
             Self::deposit_event(RawEvent::EventPosted(supervisor, reservation_req, id, info));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::reserve_seat()]
         fn reserve_seat(
             origin,
             event_id: T::KickbackEventId,
@@ -171,7 +184,7 @@ decl_module! {
             Self::deposit_event(RawEvent::EventSeatReserved(event_id, reserver));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::publish_attendance_and_execute_redistribution(Self::expected_attendance(*id))]
         pub fn publish_attendance_and_execute_redistribution(
             origin,
             id: T::KickbackEventId,
@@ -181,9 +194,9 @@ decl_module! {
             let publisher = ensure_signed(origin)?;
             let k = <KickbackEvents<T>>::get(id).ok_or(Error::<T>::KickbackEventDNE)?;
             ensure!(k.supervisor() == publisher, Error::<T>::NotAuthorizedToPublishAttendance);
-            let present_members = <KickbackReservations<T>>::iter()
-                .filter(|(i, ac, _)| i == &id && present.binary_search(&ac).is_ok())
-                .map(|(_, a, _)| a)
+            let present_members = <KickbackReservations<T>>::iter_prefix(id)
+                .filter(|(ac, _)| present.binary_search(&ac).is_ok())
+                .map(|(a, _)| a)
                 .collect::<Vec<T::AccountId>>();
             ensure!(!present_members.is_empty(), Error::<T>::AttendanceMustBeGreaterThanZero);
             // drain to present members
@@ -214,6 +227,12 @@ impl<T: Trait> Module<T> {
     pub fn event_account_id(index: T::KickbackEventId) -> T::AccountId {
         T::EventPool::get().into_sub_account(index)
     }
+    /// Number of seats reserved for the event
+    fn expected_attendance(id: T::KickbackEventId) -> u32 {
+        <KickbackEvents<T>>::get(id)
+            .map(|k| k.expected_attendance())
+            .unwrap_or_default()
+    }
     pub fn kickback_event_id_is_available(id: T::KickbackEventId) -> bool {
         <KickbackEvents<T>>::get(id).is_none()
     }
@@ -0,0 +1,141 @@
+//! Moloch pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+use util::{
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+    vote::Threshold as VoteThreshold,
+};
+
+const SEED: u32 = 0;
+const MAX_MEMBERS: u32 = 100;
+
+fn funded<T: Trait>(who: &T::AccountId) {
+    <T as Trait>::Currency::make_free_balance_be(
+        who,
+        BalanceOf::<T>::max_value(),
+    );
+}
+
+fn org_with_members<T: Trait>(caller: T::AccountId, m: u32) -> T::OrgId {
+    let mut members = vec![caller.clone()];
+    members.extend((1..m).map(|i| account("member", i, SEED)));
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        Some(caller),
+        T::Cid::default(),
+    )
+    .expect("org registration with supervisor works")
+}
+
+fn majority<T: Trait>(org: T::OrgId) -> Threshold<T> {
+    ThresholdInput::new(
+        OrgRep::Equal(org),
+        XorThreshold::Percent(VoteThreshold::new(
+            Permill::from_percent(51),
+            None,
+        )),
+    )
+}
+
+/// Summons a funded moloch for an org of size `m` controlled by `caller`
+fn funded_bank<T: Trait>(caller: T::AccountId, m: u32) -> T::BankId {
+    funded::<T>(&caller);
+    let org = org_with_members::<T>(caller.clone(), m);
+    let deposit = T::MinDeposit::get() * 1000u32.into();
+    Module::<T>::summon(
+        RawOrigin::Signed(caller.clone()).into(),
+        org,
+        deposit,
+        Some(caller),
+        majority::<T>(org),
+    )
+    .expect("moloch summoned by funded member");
+    Module::<T>::get_org_bank(org).expect("moloch summoned above")
+}
+
+benchmarks! {
+    _ { }
+
+    summon {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let org = org_with_members::<T>(caller.clone(), 1);
+        let count = Module::<T>::total_bank_count();
+    }: _(RawOrigin::Signed(caller.clone()), org, T::MinDeposit::get(), Some(caller), majority::<T>(org))
+    verify {
+        assert_eq!(Module::<T>::total_bank_count(), count + 1);
+    }
+
+    propose_spend {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+    }: _(RawOrigin::Signed(caller.clone()), bank_id, T::MinDeposit::get(), caller)
+
+    propose_member {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+    }: _(
+        RawOrigin::Signed(caller),
+        bank_id,
+        T::MinDeposit::get(),
+        1u32.into(),
+        account("applicant", 0, SEED)
+    )
+
+    spend_trigger_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), m);
+        let spend_id = Module::<T>::_propose_spend(&caller, bank_id, T::MinDeposit::get(), caller.clone())?;
+    }: _(RawOrigin::Signed(caller), bank_id, spend_id)
+
+    member_trigger_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), m);
+        let proposal_id = Module::<T>::_propose_member(
+            &caller,
+            bank_id,
+            T::MinDeposit::get(),
+            1u32.into(),
+            account("applicant", 0, SEED),
+        )?;
+    }: _(RawOrigin::Signed(caller), bank_id, proposal_id)
+
+    sudo_approve_spend_proposal {
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), 1);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let spend_id = Module::<T>::_propose_spend(&caller, bank_id, T::MinDeposit::get(), dest)?;
+    }: _(RawOrigin::Signed(caller), bank_id, spend_id)
+    verify {
+        assert_eq!(
+            Module::<T>::spend_props(bank_id, spend_id).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+    }
+
+    burn_shares {
+        let bank_id = funded_bank::<T>(account("summoner", 0, SEED), 2);
+        let member: T::AccountId = account("member", 1, SEED);
+    }: _(RawOrigin::Signed(member), bank_id)
+
+    close_org_bank_account {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let bank_id = funded_bank::<T>(caller.clone(), m);
+    }: _(RawOrigin::Signed(caller), bank_id)
+    verify {
+        assert!(!Module::<T>::is_bank(bank_id));
+    }
+}
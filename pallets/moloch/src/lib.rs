@@ -4,6 +4,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Moloch impl
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -24,6 +27,7 @@ use frame_support::{
         Get,
         ReservableCurrency,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -57,9 +61,11 @@ use util::{
     organization::OrgRep,
     traits::{
         ConfigureThreshold,
+        EnsureOrgOrigin,
         GetVoteOutcome,
         GroupMembership,
         MolochMembership,
+        MultiCurrency,
         OpenBankAccount,
         ShareIssuance,
         SpendGovernance,
@@ -101,6 +107,17 @@ type MemberProp<T> = MembershipProposal<
     ProposalState<<T as vote::Trait>::VoteId>,
 >;
 
+pub trait WeightInfo {
+    fn summon() -> Weight;
+    fn propose_spend() -> Weight;
+    fn propose_member() -> Weight;
+    fn spend_trigger_vote(m: u32) -> Weight;
+    fn member_trigger_vote(m: u32) -> Weight;
+    fn sudo_approve_spend_proposal() -> Weight;
+    fn burn_shares() -> Weight;
+    fn close_org_bank_account(m: u32) -> Weight;
+}
+
 pub trait Trait:
     frame_system::Trait + org::Trait + donate::Trait + vote::Trait
 {
@@ -155,6 +172,9 @@ pub trait Trait:
 
     /// The minimum amount to open an organizational bank account and keep it open
     type MinDeposit: Get<BalanceOf<Self>>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -221,7 +241,7 @@ decl_error! {
 }
 
 decl_storage! {
-    trait Store for Module<T: Trait> as Bank {
+    trait Store for Module<T: Trait> as Moloch {
         /// Counter for generating unique bank identifiers
         BankIdNonce get(fn bank_id_nonce): T::BankId;
 
@@ -265,7 +285,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::summon()]
         fn summon(
             origin,
             org: T::OrgId,
@@ -284,7 +304,7 @@ decl_module! {
             Self::deposit_event(RawEvent::AccountOpened(opener, bank_id, deposit, org, controller));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::propose_spend()]
         fn propose_spend(
             origin,
             bank_id: T::BankId,
@@ -296,7 +316,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SpendProposed(caller, bank_id, new_spend_id, amount, dest));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::propose_member()]
         fn propose_member(
             origin,
             bank_id: T::BankId,
@@ -309,7 +329,7 @@ decl_module! {
             Self::deposit_event(RawEvent::MemberProposed(caller, bank_id, proposal_id, tribute, shares_requested, applicant));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::spend_trigger_vote(Self::bank_org_size(*bank_id))]
         fn spend_trigger_vote(
             origin,
             bank_id: T::BankId,
@@ -320,7 +340,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SpendVoteTriggered(caller, bank_id, spend_id, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::member_trigger_vote(Self::bank_org_size(*bank_id))]
         fn member_trigger_vote(
             origin,
             bank_id: T::BankId,
@@ -331,7 +351,7 @@ decl_module! {
             Self::deposit_event(RawEvent::MemberVoteTriggered(caller, bank_id, proposal_id, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::sudo_approve_spend_proposal()]
         fn sudo_approve_spend_proposal(
             origin,
             bank_id: T::BankId,
@@ -342,7 +362,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SpendSudoApproved(caller, bank_id, spend_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::burn_shares()]
         fn burn_shares(
             origin,
             bank_id: T::BankId,
@@ -351,24 +371,34 @@ decl_module! {
             Self::_burn_shares(caller, bank_id)?;
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::close_org_bank_account(Self::bank_org_size(*bank_id))]
         fn close_org_bank_account(
             origin,
             bank_id: T::BankId,
         ) -> DispatchResult {
-            let closer = ensure_signed(origin)?;
             let bank = <BankStores<T>>::get(bank_id).ok_or(Error::<T>::CannotCloseBankThatDNE)?;
-            ensure!(
-                bank.is_controller(&closer),
-                Error::<T>::MustBeOrgSupervisorToCloseBankAccount
-            );
+            // the controller or a passed org vote may close the bank
+            let closer = match org::EnsureOrgVotePassed::<T>::try_org_origin(origin, bank.org()) {
+                Ok(()) => Self::bank_account_id(bank_id),
+                Err(origin) => {
+                    let closer = ensure_signed(origin)?;
+                    ensure!(
+                        bank.is_controller(&closer),
+                        Error::<T>::MustBeOrgSupervisorToCloseBankAccount
+                    );
+                    closer
+                }
+            };
             let bank_account_id = Self::bank_account_id(bank_id);
-            let remaining_funds = <T as donate::Trait>::Currency::total_balance(&bank_account_id);
+            // the bank holds the native token
+            let currency_id = <T as donate::Trait>::Currency::native_currency_id();
+            let remaining_funds = <T as donate::Trait>::Currency::total_balance(currency_id, &bank_account_id);
             // distributes remaining funds equally among members in proportion to ownership (PropDonation)
             let _ = <donate::Module<T>>::donate(
                 &bank_account_id,
                 OrgRep::Weighted(bank.org()),
                 &closer,
+                currency_id,
                 remaining_funds,
             )?;
             <BankStores<T>>::remove(bank_id);
@@ -439,6 +469,12 @@ impl<T: Trait> Module<T> {
         <ProposalNonceMap<T>>::insert(seed, id_nonce);
         id_nonce
     }
+    /// The member count of the org that owns the bank, used for weighing calls
+    fn bank_org_size(id: T::BankId) -> u32 {
+        <BankStores<T>>::get(id)
+            .map(|bank| <org::Module<T>>::member_count(bank.org()))
+            .unwrap_or_default()
+    }
     pub fn get_org_bank(org: T::OrgId) -> Result<T::BankId, DispatchError> {
         let mut ret = <BankStores<T>>::iter()
             .filter(|(_, bank_state)| bank_state.org() == org)
@@ -0,0 +1,257 @@
+//! Org pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_support::traits::OnFinalize;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+const MAX_MEMBERS: u32 = 100;
+const MAX_PER_BLOCK: u32 = 100;
+
+fn members<T: Trait>(m: u32) -> Vec<T::AccountId> {
+    (0..m).map(|i| account("member", i, SEED)).collect()
+}
+
+fn weighted_members<T: Trait>(m: u32) -> Vec<(T::AccountId, T::Shares)> {
+    (0..m)
+        .map(|i| (account("member", i, SEED), 10u32.into()))
+        .collect()
+}
+
+fn org_with_supervisor<T: Trait>(
+    supervisor: T::AccountId,
+    m: u32,
+) -> T::OrgId {
+    Module::<T>::register_organization(
+        OrganizationSource::Accounts(members::<T>(m)),
+        Some(supervisor),
+        T::Cid::default(),
+    )
+    .expect("org registration with supervisor works")
+}
+
+benchmarks! {
+    _ { }
+
+    register_flat_org {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let count = Module::<T>::organization_counter();
+    }: _(RawOrigin::Signed(caller.clone()), Some(caller), None, T::Cid::default(), members::<T>(m))
+    verify {
+        assert_eq!(Module::<T>::organization_counter(), count + 1);
+    }
+
+    register_weighted_org {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let count = Module::<T>::organization_counter();
+    }: _(RawOrigin::Signed(caller.clone()), Some(caller), None, T::Cid::default(), weighted_members::<T>(m))
+    verify {
+        assert_eq!(Module::<T>::organization_counter(), count + 1);
+    }
+
+    issue_shares {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+        let who: T::AccountId = account("new", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who.clone(), 10u32.into())
+    verify {
+        assert!(Module::<T>::is_member_of_group(org, &who));
+    }
+
+    burn_shares {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 2);
+        let who: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who.clone(), 1u32.into())
+    verify {
+        assert!(!Module::<T>::is_member_of_group(org, &who));
+    }
+
+    batch_issue_shares {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+        let new_accounts = (0..m)
+            .map(|i| (account("new", i, SEED), 10u32.into()))
+            .collect::<Vec<(T::AccountId, T::Shares)>>();
+    }: _(RawOrigin::Signed(caller), org, new_accounts)
+    verify {
+        assert_eq!(Module::<T>::member_count(org), m + 1);
+    }
+
+    batch_burn_shares {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), m + 1);
+        let old_accounts = (0..m)
+            .map(|i| (account("member", i, SEED), 1u32.into()))
+            .collect::<Vec<(T::AccountId, T::Shares)>>();
+    }: _(RawOrigin::Signed(caller), org, old_accounts)
+    verify {
+        assert_eq!(Module::<T>::member_count(org), 1);
+    }
+
+    lock_shares {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+        let who: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who.clone())
+    verify {
+        assert!(!Module::<T>::members(org, &who).unwrap().is_unlocked());
+    }
+
+    unlock_shares {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+        let who: T::AccountId = account("member", 0, SEED);
+        Module::<T>::lock_profile(org, &who)?;
+    }: _(RawOrigin::Signed(caller), org, who.clone())
+    verify {
+        assert!(Module::<T>::members(org, &who).unwrap().is_unlocked());
+    }
+
+    put_supervisor {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+        let who: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who.clone())
+    verify {
+        assert!(Module::<T>::is_organization_supervisor(org, &who));
+    }
+
+    propose_supervisor {
+        let org = org_with_supervisor::<T>(whitelisted_caller(), 2);
+        let caller: T::AccountId = account("member", 0, SEED);
+        let candidate: T::AccountId = account("member", 1, SEED);
+    }: _(RawOrigin::Signed(caller), org, candidate)
+    verify {
+        assert!(Module::<T>::supervisor_elections(org).is_some());
+    }
+
+    recall_supervisor {
+        let org = org_with_supervisor::<T>(whitelisted_caller(), 1);
+        let caller: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org)
+    verify {
+        assert!(Module::<T>::supervisor_elections(org).is_some());
+    }
+
+    set_transfer_policy {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+    }: _(RawOrigin::Signed(caller), org, TransferPolicy::ApprovalRequired)
+    verify {
+        assert_eq!(Module::<T>::transfer_policies(org), TransferPolicy::ApprovalRequired);
+    }
+
+    approve_transfer {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 2);
+        let from: T::AccountId = account("member", 0, SEED);
+        let to: T::AccountId = account("member", 1, SEED);
+    }: _(RawOrigin::Signed(caller), org, from.clone(), to.clone(), 1u32.into())
+    verify {
+        assert!(Module::<T>::transfer_approvals(org, (from, to)).is_some());
+    }
+
+    transfer_shares {
+        let org = org_with_supervisor::<T>(whitelisted_caller(), 1);
+        let caller: T::AccountId = account("member", 0, SEED);
+        let to: T::AccountId = account("recipient", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, to.clone(), 1u32.into())
+    verify {
+        assert!(Module::<T>::is_member_of_group(org, &to));
+    }
+
+    list_shares {
+        let org = org_with_supervisor::<T>(whitelisted_caller(), 1);
+        let caller: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, 1u32.into(), 1u32.into())
+    verify {
+        assert!(Module::<T>::sell_orders(Module::<T>::sell_order_nonce()).is_some());
+    }
+
+    cancel_sell_order {
+        let org = org_with_supervisor::<T>(whitelisted_caller(), 1);
+        let caller: T::AccountId = account("member", 0, SEED);
+        Module::<T>::list_shares(RawOrigin::Signed(caller.clone()).into(), org, 1u32.into(), 1u32.into())?;
+        let order_id = Module::<T>::sell_order_nonce();
+    }: _(RawOrigin::Signed(caller), order_id)
+    verify {
+        assert!(Module::<T>::sell_orders(order_id).is_none());
+    }
+
+    buy_shares {
+        let org = org_with_supervisor::<T>(whitelisted_caller(), 1);
+        let seller: T::AccountId = account("member", 0, SEED);
+        Module::<T>::list_shares(RawOrigin::Signed(seller).into(), org, 1u32.into(), 1u32.into())?;
+        let order_id = Module::<T>::sell_order_nonce();
+        let caller: T::AccountId = account("buyer", 0, SEED);
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+    }: _(RawOrigin::Signed(caller.clone()), order_id, 1u32.into())
+    verify {
+        assert!(Module::<T>::is_member_of_group(org, &caller));
+    }
+
+    issue_vested_shares {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+        let who: T::AccountId = account("hire", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who.clone(), 10u32.into(), 5u32.into(), 10u32.into())
+    verify {
+        assert!(Module::<T>::vesting_schedules(org, &who).is_some());
+    }
+
+    revoke_vesting {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+        let who: T::AccountId = account("hire", 0, SEED);
+        Module::<T>::issue_vested_shares(RawOrigin::Signed(caller.clone()).into(), org, who.clone(), 10u32.into(), 5u32.into(), 10u32.into())?;
+    }: _(RawOrigin::Signed(caller), org, who.clone())
+    verify {
+        assert!(Module::<T>::vesting_schedules(org, &who).is_none());
+    }
+
+    set_vesting_vote_policy {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_supervisor::<T>(caller.clone(), 1);
+    }: _(RawOrigin::Signed(caller), org, VestingVotePolicy::AllShares)
+    verify {
+        assert_eq!(Module::<T>::vesting_vote_policies(org), VestingVotePolicy::AllShares);
+    }
+
+    poll_supervisor_elections {
+        let e in 1 .. MAX_PER_BLOCK;
+        let proposer: T::AccountId = account("member", 0, SEED);
+        let candidate: T::AccountId = account("member", 1, SEED);
+        for _ in 0..e {
+            let org = org_with_supervisor::<T>(whitelisted_caller(), 2);
+            Module::<T>::propose_supervisor(RawOrigin::Signed(proposer.clone()).into(), org, candidate.clone())?;
+        }
+        let now = <frame_system::Module<T>>::block_number();
+    }: { Module::<T>::on_finalize(now) }
+
+    end_supervisor_terms {
+        let t in 1 .. MAX_PER_BLOCK;
+        let now = <frame_system::Module<T>>::block_number();
+        let mut ending = Vec::new();
+        for _ in 0..t {
+            let org = org_with_supervisor::<T>(whitelisted_caller(), 1);
+            <SupervisorTerms<T>>::insert(org, now);
+            ending.push(org);
+        }
+        <TermEndings<T>>::insert(now, ending);
+    }: { Module::<T>::on_finalize(now) }
+    verify {
+        assert!(Module::<T>::term_endings(now).is_empty());
+    }
+}
@@ -5,27 +5,39 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! This module expresses a framework for configurable group governance
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
 use util::{
     organization::{
+        OrgRep,
         Organization,
         OrganizationSource,
+        SupervisorElection,
     },
     share::{
         ProfileState,
+        SellOrder,
         SharePortion,
         ShareProfile,
+        TransferPolicy,
+        VestingSchedule,
+        VestingVotePolicy,
         WeightedVector,
     },
     traits::{
         AccessGenesis,
+        EnsureOrgOrigin,
         GenerateUniqueID,
         GetGroup,
+        GetVoteOutcome,
         GroupMembership,
         IDIsAvailable,
         LockProfile,
+        OpenVote,
         OrganizationSupervisorPermissions,
         RegisterOrganization,
         RemoveOrganization,
@@ -33,16 +45,34 @@ use util::{
         ShareIssuance,
         VerifyShape,
     },
+    vote::{
+        Threshold,
+        VoteOutcome,
+    },
 };
 
-use codec::Codec;
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use frame_support::{
     decl_error,
     decl_event,
     decl_module,
     decl_storage,
     ensure,
-    storage::IterableStorageDoubleMap,
+    storage::{
+        IterableStorageDoubleMap,
+        IterableStorageMap,
+    },
+    traits::{
+        Currency,
+        EnsureOrigin,
+        ExistenceRequirement,
+        Get,
+    },
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -55,17 +85,21 @@ use sp_runtime::{
         AtLeast32Bit,
         AtLeast32BitUnsigned,
         CheckedAdd,
+        CheckedMul,
         CheckedSub,
         MaybeSerializeDeserialize,
         Member,
+        SaturatedConversion,
         Zero,
     },
     DispatchError,
     DispatchResult,
     Permill,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
+    marker::PhantomData,
     prelude::*,
 };
 
@@ -75,6 +109,49 @@ type Org<T> = Organization<
     <T as Trait>::Cid,
 >;
 type Profile<T> = ShareProfile<<T as Trait>::Shares, ProfileState>;
+type Election<T> = SupervisorElection<
+    <T as frame_system::Trait>::AccountId,
+    <T as Trait>::ElectionId,
+>;
+type Vesting<T> = VestingSchedule<
+    <T as frame_system::Trait>::AccountId,
+    <T as Trait>::Shares,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+    <T as frame_system::Trait>::AccountId,
+>>::Balance;
+type Order<T> = SellOrder<
+    <T as Trait>::OrgId,
+    <T as frame_system::Trait>::AccountId,
+    <T as Trait>::Shares,
+    BalanceOf<T>,
+>;
+
+pub trait WeightInfo {
+    fn register_flat_org(m: u32) -> Weight;
+    fn register_weighted_org(m: u32) -> Weight;
+    fn issue_shares() -> Weight;
+    fn burn_shares() -> Weight;
+    fn batch_issue_shares(m: u32) -> Weight;
+    fn batch_burn_shares(m: u32) -> Weight;
+    fn lock_shares() -> Weight;
+    fn unlock_shares() -> Weight;
+    fn put_supervisor() -> Weight;
+    fn propose_supervisor() -> Weight;
+    fn recall_supervisor() -> Weight;
+    fn set_transfer_policy() -> Weight;
+    fn approve_transfer() -> Weight;
+    fn transfer_shares() -> Weight;
+    fn list_shares() -> Weight;
+    fn cancel_sell_order() -> Weight;
+    fn buy_shares() -> Weight;
+    fn issue_vested_shares() -> Weight;
+    fn revoke_vesting() -> Weight;
+    fn set_vesting_vote_policy() -> Weight;
+    fn poll_supervisor_elections(e: u32) -> Weight;
+    fn end_supervisor_terms(t: u32) -> Weight;
+}
 
 pub trait Trait: system::Trait {
     /// Overarching event type
@@ -109,6 +186,131 @@ pub trait Trait: system::Trait {
         + CheckedSub
         + Zero
         + AtLeast32BitUnsigned;
+
+    /// Origin for calls dispatched by an organization itself
+    type OrgOrigin: EnsureOrigin<Self::Origin, Success = Self::OrgId>;
+
+    /// Identifier for the votes electing and recalling supervisors
+    type ElectionId: Parameter
+        + Member
+        + Copy
+        + Default
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// Opens and tallies supervisor elections and recalls, signal is counted in `Shares`
+    type SupervisorVote: OpenVote<
+            OrgRep<Self::OrgId>,
+            Threshold<Self::Shares>,
+            Threshold<Permill>,
+            Self::BlockNumber,
+            Self::Cid,
+            VoteIdentifier = Self::ElectionId,
+        > + GetVoteOutcome<Self::ElectionId, Outcome = VoteOutcome>;
+
+    /// Support required from the weighted org to elect or recall a supervisor
+    type ElectionThreshold: Get<Permill>;
+
+    /// Number of blocks members may vote on electing or recalling a supervisor
+    type ElectionDuration: Get<Self::BlockNumber>;
+
+    /// Number of blocks an elected supervisor serves before the seat is vacated
+    type SupervisorTermLength: Get<Self::BlockNumber>;
+
+    /// Number of consecutive terms the same supervisor may be elected to
+    type MaxConsecutiveTerms: Get<u32>;
+
+    /// The native currency, used to pay for shares bought from sell orders
+    type Currency: Currency<Self::AccountId>;
+
+    /// Identifier for sell orders
+    type OrderId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode)]
+/// Origin for calls dispatched on behalf of an organization, i.e. by its governance
+pub enum RawOrigin<OrgId> {
+    /// The organization itself
+    Org(OrgId),
+}
+
+/// Origin for the org module
+pub type Origin<T> = RawOrigin<<T as Trait>::OrgId>;
+
+/// Ensures the call is dispatched by an organization, returns its identifier
+pub struct EnsureOrg<T>(PhantomData<T>);
+impl<
+        T: Trait,
+        O: Into<Result<RawOrigin<T::OrgId>, O>> + From<RawOrigin<T::OrgId>>,
+    > EnsureOrigin<O> for EnsureOrg<T>
+{
+    type Success = T::OrgId;
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().map(|RawOrigin::Org(id)| id)
+    }
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> O {
+        O::from(RawOrigin::Org(T::OrgId::default()))
+    }
+}
+
+/// Passes for the org itself or its supervisor, returns the signer if signed
+pub struct EnsureOrgSupervisor<T>(PhantomData<T>);
+impl<T: Trait> EnsureOrgOrigin<T::Origin, T::OrgId> for EnsureOrgSupervisor<T> {
+    type Success = Option<T::AccountId>;
+    fn try_org_origin(
+        o: T::Origin,
+        org: T::OrgId,
+    ) -> Result<Self::Success, T::Origin> {
+        let caller = Module::<T>::org_or_signer(o.clone(), org)?;
+        match &caller {
+            Some(who) if !Module::<T>::is_organization_supervisor(org, who) => {
+                Err(o)
+            }
+            _ => Ok(caller),
+        }
+    }
+}
+
+/// Passes for the org itself or any of its members, returns the signer if signed
+pub struct EnsureOrgMember<T>(PhantomData<T>);
+impl<T: Trait> EnsureOrgOrigin<T::Origin, T::OrgId> for EnsureOrgMember<T> {
+    type Success = Option<T::AccountId>;
+    fn try_org_origin(
+        o: T::Origin,
+        org: T::OrgId,
+    ) -> Result<Self::Success, T::Origin> {
+        let caller = Module::<T>::org_or_signer(o.clone(), org)?;
+        match &caller {
+            Some(who) if !Module::<T>::is_member_of_group(org, who) => Err(o),
+            _ => Ok(caller),
+        }
+    }
+}
+
+/// Passes only for the org itself, i.e. a call dispatched because its vote passed
+pub struct EnsureOrgVotePassed<T>(PhantomData<T>);
+impl<T: Trait> EnsureOrgOrigin<T::Origin, T::OrgId> for EnsureOrgVotePassed<T> {
+    type Success = ();
+    fn try_org_origin(
+        o: T::Origin,
+        org: T::OrgId,
+    ) -> Result<Self::Success, T::Origin> {
+        match T::OrgOrigin::try_origin(o.clone()) {
+            Ok(caller_org) if caller_org == org => Ok(()),
+            _ => Err(o),
+        }
+    }
 }
 
 decl_event!(
@@ -118,6 +320,10 @@ decl_event!(
         OrgId = <T as Trait>::OrgId,
         <T as Trait>::Shares,
         <T as Trait>::Cid,
+        <T as Trait>::ElectionId,
+        <T as frame_system::Trait>::BlockNumber,
+        <T as Trait>::OrderId,
+        Balance = BalanceOf<T>,
     {
         /// No shares issued but an organization was registered with flat membership with the last `u32` as the number of members
         NewFlatOrganizationRegistered(AccountId, OrgId, Cid, u32),
@@ -145,6 +351,38 @@ decl_event!(
         SharesBatchBurned(OrgId, Shares),
         /// Organization ID Removed
         OrganizationRemoved(OrgId),
+        /// Organization ID, New Supervisor
+        SupervisorPut(OrgId, AccountId),
+        /// Proposer, Organization ID, Candidate, Election Vote ID
+        SupervisorProposed(AccountId, OrgId, AccountId, ElectionId),
+        /// Proposer, Organization ID, Recall Vote ID
+        SupervisorRecallProposed(AccountId, OrgId, ElectionId),
+        /// Organization ID, Elected Supervisor, Block at which the term ends
+        SupervisorElected(OrgId, AccountId, BlockNumber),
+        /// Organization ID, Recalled Supervisor
+        SupervisorRecalled(OrgId, AccountId),
+        /// Organization ID, Election Vote ID
+        SupervisorElectionFailed(OrgId, ElectionId),
+        /// Organization ID, Supervisor whose term ended
+        SupervisorTermEnded(OrgId, AccountId),
+        /// Organization ID, New Transfer Policy
+        TransferPolicySet(OrgId, TransferPolicy),
+        /// Organization ID, Sender, Recipient, Approved Amount
+        TransferApproved(OrgId, AccountId, AccountId, Shares),
+        /// Organization ID, Sender, Recipient, Transferred Amount
+        SharesTransferred(OrgId, AccountId, AccountId, Shares),
+        /// Seller, Organization ID, Sell Order ID, Amount For Sale, Price Per Share
+        SharesListed(AccountId, OrgId, OrderId, Shares, Balance),
+        /// Sell Order ID
+        SellOrderCancelled(OrderId),
+        /// Buyer, Sell Order ID, Amount Bought, Total Paid
+        SharesBought(AccountId, OrderId, Shares, Balance),
+        /// Organization ID, Recipient, Vested Amount, Block at which the cliff ends, Block at which every share is vested
+        VestedSharesIssued(OrgId, AccountId, Shares, BlockNumber, BlockNumber),
+        /// Organization ID, Account whose vesting was revoked, Unvested Amount returned to the issuer
+        VestingRevoked(OrgId, AccountId, Shares),
+        /// Organization ID, New Vesting Vote Policy
+        VestingVotePolicySet(OrgId, VestingVotePolicy),
     }
 );
 
@@ -173,8 +411,37 @@ decl_error! {
         NotAuthorizedToUnLockShares,
         NotAuthorizedToIssueShares,
         NotAuthorizedToBurnShares,
+        NotAuthorizedToPutSupervisor,
         OrganizationCannotBeRemovedIfInputIdIsAvailable,
         AccountHasNoOwnershipInOrg,
+        NotAuthorizedToProposeSupervisor,
+        NotAuthorizedToRecallSupervisor,
+        SupervisorCandidateMustBeMember,
+        NoSupervisorToRecall,
+        SupervisorElectionAlreadyPending,
+        SupervisorTermLimitReached,
+        OrganizationMustBeRegisteredToTransferShares,
+        NotAuthorizedToSetTransferPolicy,
+        NotAuthorizedToApproveTransfer,
+        CannotTransferZeroShares,
+        CannotTransferSharesToSelf,
+        CannotTransferLockedShares,
+        NotEnoughSharesToTransfer,
+        RecipientMustBeMemberToReceiveShares,
+        TransferNotApproved,
+        SellOrderDNE,
+        NotAuthorizedToCancelSellOrder,
+        CannotBuyMoreSharesThanListed,
+        SellOrderPriceOverflow,
+        CannotTransferUnvestedShares,
+        CannotVestZeroShares,
+        VestingDurationMustBeNonZero,
+        VestingCliffCannotExceedDuration,
+        VestingScheduleAlreadyExists,
+        NoVestingScheduleToRevoke,
+        BatchBurnAccountsMustBeUnique,
+        NotAuthorizedToRevokeVesting,
+        NotAuthorizedToSetVestingVotePolicy,
     }
 }
 
@@ -203,6 +470,57 @@ decl_storage! {
         /// Total number of outstanding shares that express relative ownership in group
         pub TotalIssuance get(fn total_issuance): map
             hasher(opaque_blake2_256) T::OrgId => T::Shares;
+
+        /// The number of members in each organization, used to weigh calls that iterate the group
+        pub MemberCount get(fn member_count): map
+            hasher(opaque_blake2_256) T::OrgId => u32;
+
+        /// The pending supervisor election or recall for each org, one at a time
+        pub SupervisorElections get(fn supervisor_elections): map
+            hasher(blake2_128_concat) T::OrgId => Option<Election<T>>;
+
+        /// Block at which the term of an elected supervisor ends
+        pub SupervisorTerms get(fn supervisor_terms): map
+            hasher(blake2_128_concat) T::OrgId => Option<T::BlockNumber>;
+
+        /// Last supervisor elected in each org and the number of consecutive terms they were elected to
+        pub ConsecutiveTerms get(fn consecutive_terms): map
+            hasher(blake2_128_concat) T::OrgId => Option<(T::AccountId, u32)>;
+
+        /// Orgs whose supervisor term ends at the block
+        TermEndings get(fn term_endings): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::OrgId>;
+
+        /// Restrictions on share transfers between accounts for each org
+        pub TransferPolicies get(fn transfer_policies): map
+            hasher(blake2_128_concat) T::OrgId => TransferPolicy;
+
+        /// Shares the supervisor or the org approved for transfer from the first to the second account
+        pub TransferApprovals get(fn transfer_approvals): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) (T::AccountId, T::AccountId) => Option<T::Shares>;
+
+        /// Identity nonce for sell orders
+        SellOrderNonce get(fn sell_order_nonce): T::OrderId;
+
+        /// Open offers to sell shares for the native currency
+        pub SellOrders get(fn sell_orders): map
+            hasher(blake2_128_concat) T::OrderId => Option<Order<T>>;
+
+        /// Vesting schedule for shares issued with `issue_vested_shares`, one per member
+        pub VestingSchedules get(fn vesting_schedules): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<Vesting<T>>;
+
+        /// Locks held on a member's shares by other pallets, kept apart from
+        /// the lock set through `lock_shares`
+        pub ShareLocks get(fn share_locks): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => u32;
+
+        /// Whether unvested shares count toward voting power for each org
+        pub VestingVotePolicies get(fn vesting_vote_policies): map
+            hasher(blake2_128_concat) T::OrgId => VestingVotePolicy;
     }
     add_extra_genesis {
         config(first_organization_supervisor): T::AccountId;
@@ -226,7 +544,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::register_flat_org(members.len() as u32)]
         fn register_flat_org(
             origin,
             sudo: Option<T::AccountId>,
@@ -244,7 +562,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewFlatOrganizationRegistered(caller, new_id, constitution, total));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::register_weighted_org(weighted_members.len() as u32)]
         fn register_weighted_org(
             origin,
             sudo: Option<T::AccountId>,
@@ -263,92 +581,597 @@ decl_module! {
             Self::deposit_event(RawEvent::NewWeightedOrganizationRegistered(caller, new_id, constitution, wm_cpy.total()));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::issue_shares()]
         fn issue_shares(origin, organization: T::OrgId, who: T::AccountId, shares: T::Shares) -> DispatchResult {
-            let issuer = ensure_signed(origin)?;
             // first check is that the organization exists
             ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToIssueShares);
-            // second check is that this is an authorized party for issuance (the supervisor or the module's sudo account)
-            let authentication: bool = Self::is_organization_supervisor(organization, &issuer);
-            ensure!(authentication, Error::<T>::NotAuthorizedToIssueShares);
+            // second check is that this is an authorized party for issuance (the supervisor or the org itself)
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToIssueShares)?;
 
             Self::issue(organization, who.clone(), shares, false)?;
             Self::deposit_event(RawEvent::SharesIssued(organization, who, shares));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::burn_shares()]
         fn burn_shares(origin, organization: T::OrgId, who: T::AccountId, shares: T::Shares) -> DispatchResult {
-            let burner = ensure_signed(origin)?;
             // first check is that the organization exists
             ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToBurnShares);
             // second check is that this is an authorized party for burning
-            let authentication: bool = Self::is_organization_supervisor(organization, &burner);
-            ensure!(authentication, Error::<T>::NotAuthorizedToBurnShares);
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToBurnShares)?;
 
-            Self::burn(organization, who.clone(), Some(shares), false)?;
+            // unvested shares return to their issuer instead of being burned
+            let (shares, unvested) = Self::split_burn(organization, &who, shares)?;
+            Self::return_unvested(organization, &who, unvested)?;
+            if !shares.is_zero() {
+                Self::burn(organization, who.clone(), Some(shares), false)?;
+            }
             Self::deposit_event(RawEvent::SharesBurned(organization, who, shares));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::batch_issue_shares(new_accounts.len() as u32)]
         fn batch_issue_shares(origin, organization: T::OrgId, new_accounts: Vec<(T::AccountId, T::Shares)>) -> DispatchResult {
-            let issuer = ensure_signed(origin)?;
             // first check is that the organization exists
             ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToIssueShares);
             // second check is that this is an authorized party for issuance
-            let authentication: bool = Self::is_organization_supervisor(organization, &issuer);
-            ensure!(authentication, Error::<T>::NotAuthorizedToIssueShares);
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToIssueShares)?;
             let genesis: WeightedVector<T::AccountId, T::Shares> = new_accounts.into();
             let total_new_shares_minted = genesis.total();
             Self::batch_issue(organization, genesis)?;
             Self::deposit_event(RawEvent::SharesBatchIssued(organization, total_new_shares_minted));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::batch_burn_shares(old_accounts.len() as u32)]
         fn batch_burn_shares(origin, organization: T::OrgId, old_accounts: Vec<(T::AccountId, T::Shares)>) -> DispatchResult {
-            let issuer = ensure_signed(origin)?;
             // first check is that the organization exists
             ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToIssueShares);
             // second check is that this is an authorized party for burning
-            let authentication: bool = Self::is_organization_supervisor(organization, &issuer);
-            ensure!(authentication, Error::<T>::NotAuthorizedToBurnShares);
-            let genesis: WeightedVector<T::AccountId, T::Shares> = old_accounts.into();
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToBurnShares)?;
+            // every burn is checked before any shares move
+            let mut accounts = old_accounts.iter().map(|(who, _)| who).collect::<Vec<_>>();
+            accounts.sort();
+            accounts.dedup();
+            ensure!(accounts.len() == old_accounts.len(), Error::<T>::BatchBurnAccountsMustBeUnique);
+            let mut to_burn = Vec::new();
+            let mut to_return = Vec::new();
+            for (who, shares) in old_accounts {
+                let (shares, unvested) = Self::split_burn(organization, &who, shares)?;
+                to_return.push((who.clone(), unvested));
+                to_burn.push((who, shares));
+            }
+            // unvested shares return to their issuer instead of being burned
+            for (who, unvested) in to_return {
+                Self::return_unvested(organization, &who, unvested)?;
+            }
+            let genesis: WeightedVector<T::AccountId, T::Shares> = to_burn.into();
             let total_new_shares_burned = genesis.total();
             Self::batch_burn(organization, genesis)?;
             Self::deposit_event(RawEvent::SharesBatchBurned(organization, total_new_shares_burned));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::lock_shares()]
         fn lock_shares(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
-            let locker = ensure_signed(origin)?;
             // first check is that the organization exists
             ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToLockShares);
             // second check is that this is an authorized party for locking shares
-            let authentication: bool = Self::is_organization_supervisor(organization, &locker)
-                                    || locker == who;
-            ensure!(authentication, Error::<T>::NotAuthorizedToLockShares);
+            if let Err(origin) = EnsureOrgSupervisor::<T>::try_org_origin(origin, organization) {
+                let locker = ensure_signed(origin)?;
+                ensure!(locker == who, Error::<T>::NotAuthorizedToLockShares);
+            }
 
             Self::lock_profile(organization, &who)?;
             Self::deposit_event(RawEvent::SharesLocked(organization, who));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::unlock_shares()]
         fn unlock_shares(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
-            let unlocker = ensure_signed(origin)?;
             // first check is that the organization exists
             ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToUnLockShares);
             // second check is that this is an authorized party for unlocking shares
-            let authentication: bool = Self::is_organization_supervisor(organization, &unlocker)
-                                    || unlocker == who;
-            ensure!(authentication, Error::<T>::NotAuthorizedToUnLockShares);
+            if let Err(origin) = EnsureOrgSupervisor::<T>::try_org_origin(origin, organization) {
+                let unlocker = ensure_signed(origin)?;
+                ensure!(unlocker == who, Error::<T>::NotAuthorizedToUnLockShares);
+            }
 
             Self::unlock_profile(organization, &who)?;
             Self::deposit_event(RawEvent::SharesUnlocked(organization, who));
             Ok(())
         }
+        #[weight = T::WeightInfo::put_supervisor()]
+        fn put_supervisor(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
+            // the current supervisor or the org itself may replace the supervisor
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToPutSupervisor)?;
+            Self::put_organization_supervisor(organization, who.clone())?;
+            // appointed supervisors serve without a term
+            <SupervisorTerms<T>>::remove(organization);
+            if <ConsecutiveTerms<T>>::get(organization).map(|(last, _)| last != who).unwrap_or(false) {
+                <ConsecutiveTerms<T>>::remove(organization);
+            }
+            Self::deposit_event(RawEvent::SupervisorPut(organization, who));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::propose_supervisor()]
+        fn propose_supervisor(
+            origin,
+            organization: T::OrgId,
+            candidate: T::AccountId,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &proposer), Error::<T>::NotAuthorizedToProposeSupervisor);
+            ensure!(Self::is_member_of_group(organization, &candidate), Error::<T>::SupervisorCandidateMustBeMember);
+            ensure!(
+                Self::terms_if_elected(organization, &candidate) <= T::MaxConsecutiveTerms::get(),
+                Error::<T>::SupervisorTermLimitReached
+            );
+            let vote_id = Self::open_election(organization, Some(candidate.clone()))?;
+            Self::deposit_event(RawEvent::SupervisorProposed(proposer, organization, candidate, vote_id));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::recall_supervisor()]
+        fn recall_supervisor(
+            origin,
+            organization: T::OrgId,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &proposer), Error::<T>::NotAuthorizedToRecallSupervisor);
+            let org = <OrganizationStates<T>>::get(organization).ok_or(Error::<T>::OrgDNE)?;
+            ensure!(org.sudo().is_some(), Error::<T>::NoSupervisorToRecall);
+            let vote_id = Self::open_election(organization, None)?;
+            Self::deposit_event(RawEvent::SupervisorRecallProposed(proposer, organization, vote_id));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::set_transfer_policy()]
+        fn set_transfer_policy(origin, organization: T::OrgId, policy: TransferPolicy) -> DispatchResult {
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrgDNE);
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToSetTransferPolicy)?;
+            <TransferPolicies<T>>::insert(organization, policy);
+            Self::deposit_event(RawEvent::TransferPolicySet(organization, policy));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::approve_transfer()]
+        fn approve_transfer(
+            origin,
+            organization: T::OrgId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: T::Shares,
+        ) -> DispatchResult {
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrgDNE);
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToApproveTransfer)?;
+            // replaces any previous approval for the pair
+            <TransferApprovals<T>>::insert(organization, (from.clone(), to.clone()), amount);
+            Self::deposit_event(RawEvent::TransferApproved(organization, from, to, amount));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::transfer_shares()]
+        fn transfer_shares(origin, organization: T::OrgId, to: T::AccountId, amount: T::Shares) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            Self::check_transfer(organization, &from, &to, amount)?;
+            Self::move_shares(organization, &from, &to, amount)?;
+            Self::deposit_event(RawEvent::SharesTransferred(organization, from, to, amount));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::list_shares()]
+        fn list_shares(origin, organization: T::OrgId, amount: T::Shares, price: BalanceOf<T>) -> DispatchResult {
+            let seller = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToTransferShares);
+            ensure!(!amount.is_zero(), Error::<T>::CannotTransferZeroShares);
+            // shares are not escrowed, the seller must still hold them when the order is filled
+            let profile = <Members<T>>::get(organization, &seller).ok_or(Error::<T>::AccountHasNoOwnershipInOrg)?;
+            ensure!(!Self::shares_locked(organization, &seller), Error::<T>::CannotTransferLockedShares);
+            ensure!(profile.total() >= amount, Error::<T>::NotEnoughSharesToTransfer);
+            ensure!(Self::vested_shares(organization, &seller, profile.total()) >= amount, Error::<T>::CannotTransferUnvestedShares);
+            let order_id = Self::generate_order_id();
+            <SellOrders<T>>::insert(order_id, SellOrder::new(organization, seller.clone(), amount, price));
+            Self::deposit_event(RawEvent::SharesListed(seller, organization, order_id, amount, price));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::cancel_sell_order()]
+        fn cancel_sell_order(origin, order_id: T::OrderId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let order = <SellOrders<T>>::get(order_id).ok_or(Error::<T>::SellOrderDNE)?;
+            ensure!(order.seller() == caller, Error::<T>::NotAuthorizedToCancelSellOrder);
+            <SellOrders<T>>::remove(order_id);
+            Self::deposit_event(RawEvent::SellOrderCancelled(order_id));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::buy_shares()]
+        fn buy_shares(origin, order_id: T::OrderId, amount: T::Shares) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+            let order = <SellOrders<T>>::get(order_id).ok_or(Error::<T>::SellOrderDNE)?;
+            ensure!(order.amount() >= amount, Error::<T>::CannotBuyMoreSharesThanListed);
+            let (org, seller) = (order.org(), order.seller());
+            Self::check_transfer(org, &seller, &buyer, amount)?;
+            let cost = order.price()
+                .checked_mul(&amount.saturated_into::<u128>().saturated_into())
+                .ok_or(Error::<T>::SellOrderPriceOverflow)?;
+            // `check_transfer` makes every check `move_shares` needs, so a payment that
+            // goes through is always followed by the shares and a failed one changes nothing
+            T::Currency::transfer(&buyer, &seller, cost, ExistenceRequirement::KeepAlive)?;
+            Self::move_shares(org, &seller, &buyer, amount)?;
+            if let Some(remaining) = order.fill(amount) {
+                <SellOrders<T>>::insert(order_id, remaining);
+            } else {
+                <SellOrders<T>>::remove(order_id);
+            }
+            Self::deposit_event(RawEvent::SharesBought(buyer, order_id, amount, cost));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::issue_vested_shares()]
+        fn issue_vested_shares(
+            origin,
+            organization: T::OrgId,
+            who: T::AccountId,
+            shares: T::Shares,
+            cliff: T::BlockNumber,
+            duration: T::BlockNumber,
+        ) -> DispatchResult {
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToIssueShares);
+            let issuer = EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToIssueShares)?;
+            ensure!(!shares.is_zero(), Error::<T>::CannotVestZeroShares);
+            ensure!(!duration.is_zero(), Error::<T>::VestingDurationMustBeNonZero);
+            ensure!(cliff <= duration, Error::<T>::VestingCliffCannotExceedDuration);
+            let now = <frame_system::Module<T>>::block_number();
+            // a finished schedule may be replaced
+            if let Some(existing) = <VestingSchedules<T>>::get(organization, &who) {
+                ensure!(existing.unvested_at(now).is_zero(), Error::<T>::VestingScheduleAlreadyExists);
+            }
+            let schedule = VestingSchedule::new(issuer, shares, now, cliff, duration);
+            let (cliff_ends, vesting_ends) = (schedule.cliff_ends(), schedule.vesting_ends());
+            Self::issue(organization, who.clone(), shares, false)?;
+            <VestingSchedules<T>>::insert(organization, &who, schedule);
+            Self::deposit_event(RawEvent::VestedSharesIssued(organization, who, shares, cliff_ends, vesting_ends));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::revoke_vesting()]
+        fn revoke_vesting(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToRevokeVesting)?;
+            let schedule = <VestingSchedules<T>>::get(organization, &who).ok_or(Error::<T>::NoVestingScheduleToRevoke)?;
+            Self::revoke(organization, &who, schedule)?;
+            Ok(())
+        }
+        #[weight = T::WeightInfo::set_vesting_vote_policy()]
+        fn set_vesting_vote_policy(origin, organization: T::OrgId, policy: VestingVotePolicy) -> DispatchResult {
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrgDNE);
+            EnsureOrgSupervisor::<T>::try_org_origin(origin, organization)
+                .map_err(|_| Error::<T>::NotAuthorizedToSetVestingVotePolicy)?;
+            <VestingVotePolicies<T>>::insert(organization, policy);
+            Self::deposit_event(RawEvent::VestingVotePolicySet(organization, policy));
+            Ok(())
+        }
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            // charges the work `on_finalize` does in this block, counting it reads the same keys
+            let pending = <SupervisorElections<T>>::iter().count() as u32;
+            let ending = <TermEndings<T>>::get(n).len() as u32;
+            T::WeightInfo::poll_supervisor_elections(pending)
+                .saturating_add(T::DbWeight::get().reads(pending as Weight + 1))
+                .saturating_add(T::WeightInfo::end_supervisor_terms(ending))
+        }
+        fn on_finalize(n: T::BlockNumber) {
+            let pending: Vec<T::OrgId> = <SupervisorElections<T>>::iter().map(|(org, _)| org).collect();
+            pending.into_iter().for_each(Self::poll_supervisor_election);
+            <TermEndings<T>>::take(n).into_iter().for_each(|org| Self::end_supervisor_term(org, n));
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Opens the vote on electing `candidate`, or on recalling the supervisor if `None`
+    /// -> every election ends after `ElectionDuration` so a pending one cannot block the next
+    fn open_election(
+        org: T::OrgId,
+        candidate: Option<T::AccountId>,
+    ) -> Result<T::ElectionId, DispatchError> {
+        ensure!(
+            <SupervisorElections<T>>::get(org).is_none(),
+            Error::<T>::SupervisorElectionAlreadyPending
+        );
+        let vote_id = T::SupervisorVote::open_percent_vote(
+            None,
+            OrgRep::Weighted(org),
+            Threshold::new(T::ElectionThreshold::get(), None),
+            Some(T::ElectionDuration::get()),
+        )?;
+        <SupervisorElections<T>>::insert(
+            org,
+            SupervisorElection::new(vote_id, candidate),
+        );
+        Ok(vote_id)
+    }
+    /// Applies the election once its vote passes and drops it if the vote fails
+    fn poll_supervisor_election(org: T::OrgId) {
+        let election = if let Some(e) = <SupervisorElections<T>>::get(org) {
+            e
+        } else {
+            return
+        };
+        match T::SupervisorVote::get_vote_outcome(election.vote_id()) {
+            Ok(VoteOutcome::Approved) => {
+                <SupervisorElections<T>>::remove(org);
+                if let Some(candidate) = election.candidate() {
+                    let terms = Self::terms_if_elected(org, &candidate);
+                    if terms > T::MaxConsecutiveTerms::get() {
+                        Self::deposit_event(
+                            RawEvent::SupervisorElectionFailed(
+                                org,
+                                election.vote_id(),
+                            ),
+                        );
+                    } else if Self::put_organization_supervisor(
+                        org,
+                        candidate.clone(),
+                    )
+                    .is_ok()
+                    {
+                        let ends = <frame_system::Module<T>>::block_number()
+                            + T::SupervisorTermLength::get();
+                        <SupervisorTerms<T>>::insert(org, ends);
+                        <TermEndings<T>>::mutate(ends, |v| v.push(org));
+                        <ConsecutiveTerms<T>>::insert(
+                            org,
+                            (candidate.clone(), terms),
+                        );
+                        Self::deposit_event(RawEvent::SupervisorElected(
+                            org, candidate, ends,
+                        ));
+                    }
+                } else if let Some(state) = <OrganizationStates<T>>::get(org) {
+                    if let Some(recalled) = state.sudo() {
+                        <OrganizationStates<T>>::insert(
+                            org,
+                            state.clear_sudo(),
+                        );
+                        <SupervisorTerms<T>>::remove(org);
+                        Self::deposit_event(RawEvent::SupervisorRecalled(
+                            org, recalled,
+                        ));
+                    }
+                }
+            }
+            Ok(VoteOutcome::NotStarted) | Ok(VoteOutcome::Voting) => (),
+            // rejected or the vote was pruned
+            _ => {
+                <SupervisorElections<T>>::remove(org);
+                Self::deposit_event(RawEvent::SupervisorElectionFailed(
+                    org,
+                    election.vote_id(),
+                ));
+            }
+        }
+    }
+    /// The number of consecutive terms `candidate` would serve if elected now
+    /// -> the count restarts once another supervisor held the seat
+    fn terms_if_elected(org: T::OrgId, candidate: &T::AccountId) -> u32 {
+        match <ConsecutiveTerms<T>>::get(org) {
+            Some((last, terms)) if &last == candidate => terms + 1,
+            _ => 1,
+        }
+    }
+    /// Vacates the seat if the supervisor's term still ends at `n`
+    fn end_supervisor_term(org: T::OrgId, n: T::BlockNumber) {
+        if <SupervisorTerms<T>>::get(org) != Some(n) {
+            return
+        }
+        <SupervisorTerms<T>>::remove(org);
+        if let Some(state) = <OrganizationStates<T>>::get(org) {
+            if let Some(supervisor) = state.sudo() {
+                <OrganizationStates<T>>::insert(org, state.clear_sudo());
+                Self::deposit_event(RawEvent::SupervisorTermEnded(
+                    org, supervisor,
+                ));
+            }
+        }
+    }
+    /// Checks the transfer against the sender's profile and the org's transfer policy
+    fn check_transfer(
+        org: T::OrgId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: T::Shares,
+    ) -> DispatchResult {
+        ensure!(
+            !Self::id_is_available(org),
+            Error::<T>::OrganizationMustBeRegisteredToTransferShares
+        );
+        ensure!(!amount.is_zero(), Error::<T>::CannotTransferZeroShares);
+        ensure!(from != to, Error::<T>::CannotTransferSharesToSelf);
+        let profile = <Members<T>>::get(org, from)
+            .ok_or(Error::<T>::AccountHasNoOwnershipInOrg)?;
+        ensure!(
+            !Self::shares_locked(org, from),
+            Error::<T>::CannotTransferLockedShares
+        );
+        ensure!(
+            profile.total() >= amount,
+            Error::<T>::NotEnoughSharesToTransfer
+        );
+        ensure!(
+            <TotalIssuance<T>>::get(org) >= amount,
+            Error::<T>::CannotBurnMoreThanTotalIssuance
+        );
+        ensure!(
+            Self::vested_shares(org, from, profile.total()) >= amount,
+            Error::<T>::CannotTransferUnvestedShares
+        );
+        match <TransferPolicies<T>>::get(org) {
+            TransferPolicy::Unrestricted => Ok(()),
+            TransferPolicy::MembersOnly => {
+                ensure!(
+                    Self::is_member_of_group(org, to),
+                    Error::<T>::RecipientMustBeMemberToReceiveShares
+                );
+                Ok(())
+            }
+            TransferPolicy::ApprovalRequired => {
+                let approved = <TransferApprovals<T>>::get(
+                    org,
+                    (from.clone(), to.clone()),
+                )
+                .unwrap_or_else(Zero::zero);
+                ensure!(approved >= amount, Error::<T>::TransferNotApproved);
+                Ok(())
+            }
+        }
+    }
+    /// Moves shares without changing total issuance
+    /// -> cannot fail once `check_transfer` passes, nothing is written if it does
+    fn move_shares(
+        org: T::OrgId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: T::Shares,
+    ) -> DispatchResult {
+        Self::burn(org, from.clone(), Some(amount), true)?;
+        Self::issue(org, to.clone(), amount, true)?;
+        if let Some(approved) =
+            <TransferApprovals<T>>::get(org, (from.clone(), to.clone()))
+        {
+            let left = approved.saturating_sub(amount);
+            if left.is_zero() {
+                <TransferApprovals<T>>::remove(org, (from.clone(), to.clone()));
+            } else {
+                <TransferApprovals<T>>::insert(
+                    org,
+                    (from.clone(), to.clone()),
+                    left,
+                );
+            }
+        }
+        Ok(())
+    }
+    /// Whether the member's shares are locked by the org or by another pallet
+    pub fn shares_locked(org: T::OrgId, who: &T::AccountId) -> bool {
+        <ShareLocks<T>>::get(org, who) > 0
+            || <Members<T>>::get(org, who)
+                .map(|p| !p.is_unlocked())
+                .unwrap_or(false)
+    }
+    /// Adds a lock on the member's shares without touching their profile state
+    pub fn add_share_lock(org: T::OrgId, who: &T::AccountId) -> DispatchResult {
+        ensure!(
+            Self::is_member_of_group(org, who),
+            Error::<T>::CannotLockProfileThatDNE
+        );
+        <ShareLocks<T>>::mutate(org, who, |count| *count += 1);
+        Ok(())
+    }
+    /// Releases a lock added with `add_share_lock`
+    pub fn remove_share_lock(org: T::OrgId, who: &T::AccountId) {
+        let count = <ShareLocks<T>>::get(org, who).saturating_sub(1);
+        if count == 0 {
+            <ShareLocks<T>>::remove(org, who);
+        } else {
+            <ShareLocks<T>>::insert(org, who, count);
+        }
+    }
+    /// Removes the member's vesting schedule and returns their unvested shares to the issuer
+    fn revoke(
+        org: T::OrgId,
+        who: &T::AccountId,
+        schedule: Vesting<T>,
+    ) -> Result<T::Shares, DispatchError> {
+        let held = <Members<T>>::get(org, who)
+            .map(|p| p.total())
+            .unwrap_or_else(Zero::zero);
+        // every share is returned before the cliff, only the unvested remainder after it
+        let returned = schedule
+            .unvested_at(<frame_system::Module<T>>::block_number())
+            .min(held);
+        <VestingSchedules<T>>::remove(org, who);
+        if !returned.is_zero() {
+            Self::burn(org, who.clone(), Some(returned), false)?;
+            // shares issued by the org itself return to its unissued supply
+            if let Some(issuer) = schedule.issuer() {
+                Self::issue(org, issuer, returned, false)?;
+            }
+        }
+        Self::deposit_event(RawEvent::VestingRevoked(
+            org,
+            who.clone(),
+            returned,
+        ));
+        Ok(returned)
+    }
+    /// Splits burning `amount` of the member's shares into the vested part and the unvested part
+    /// -> checks the member holds `amount` without changing any storage
+    fn split_burn(
+        org: T::OrgId,
+        who: &T::AccountId,
+        amount: T::Shares,
+    ) -> Result<(T::Shares, T::Shares), DispatchError> {
+        let held = <Members<T>>::get(org, who)
+            .map(|p| p.total())
+            .unwrap_or_else(Zero::zero);
+        ensure!(
+            held >= amount,
+            Error::<T>::NotEnoughSharesToSatisfyBurnRequest
+        );
+        let vested = Self::vested_shares(org, who, held);
+        let unvested = amount.saturating_sub(vested);
+        Ok((amount - unvested, unvested))
+    }
+    /// Returns `amount` of the member's unvested shares to the issuer
+    /// -> the rest of the schedule keeps vesting
+    fn return_unvested(
+        org: T::OrgId,
+        who: &T::AccountId,
+        amount: T::Shares,
+    ) -> DispatchResult {
+        if amount.is_zero() {
+            return Ok(())
+        }
+        let schedule = <VestingSchedules<T>>::get(org, who)
+            .ok_or(Error::<T>::NoVestingScheduleToRevoke)?;
+        Self::burn(org, who.clone(), Some(amount), false)?;
+        // shares issued by the org itself return to its unissued supply
+        if let Some(issuer) = schedule.issuer() {
+            Self::issue(org, issuer, amount, false)?;
+        }
+        let now = <frame_system::Module<T>>::block_number();
+        let remaining = schedule.reduce_unvested(now, amount);
+        if remaining.unvested_at(now).is_zero() {
+            <VestingSchedules<T>>::remove(org, who);
+        } else {
+            <VestingSchedules<T>>::insert(org, who, remaining);
+        }
+        Self::deposit_event(RawEvent::VestingRevoked(org, who.clone(), amount));
+        Ok(())
+    }
+    /// The part of `held` which is not locked up by the member's vesting schedule
+    pub fn vested_shares(
+        org: T::OrgId,
+        who: &T::AccountId,
+        held: T::Shares,
+    ) -> T::Shares {
+        let unvested = <VestingSchedules<T>>::get(org, who)
+            .map(|s| s.unvested_at(<frame_system::Module<T>>::block_number()))
+            .unwrap_or_else(Zero::zero);
+        held.saturating_sub(unvested)
+    }
+    fn generate_order_id() -> T::OrderId {
+        let id = <SellOrderNonce<T>>::get() + 1u32.into();
+        <SellOrderNonce<T>>::put(id);
+        id
+    }
+    /// `None` if the org itself dispatched the call, otherwise the signer
+    fn org_or_signer(
+        o: T::Origin,
+        org: T::OrgId,
+    ) -> Result<Option<T::AccountId>, T::Origin> {
+        match T::OrgOrigin::try_origin(o.clone()) {
+            Ok(caller_org) if caller_org == org => Ok(None),
+            Ok(_) => Err(o),
+            Err(o) => ensure_signed(o.clone()).map(Some).map_err(|_| o),
+        }
+    }
     pub fn is_immediate_child(parent: T::OrgId, child: T::OrgId) -> bool {
         <OrgHierarchy<T>>::get(parent, child).is_some()
     }
@@ -514,10 +1337,8 @@ impl<T: Trait> GetGroup<T::OrgId, T::AccountId> for Module<T> {
     fn get_group(organization: T::OrgId) -> Option<OrderedSet<T::AccountId>> {
         if !Self::id_is_available(organization) {
             Some(
-                // is this the same performance as a get() with Key=Vec<AccountId>
-                <Members<T>>::iter()
-                    .filter(|(org, _, _)| *org == organization)
-                    .map(|(_, account, _)| account)
+                <Members<T>>::iter_prefix(organization)
+                    .map(|(account, _)| account)
                     .collect::<Vec<_>>()
                     .into(),
             )
@@ -544,15 +1365,28 @@ impl<T: Trait> ShareInformation<T::OrgId, T::AccountId, T::Shares>
         <Members<T>>::get(organization, who)
     }
     /// Returns the entire membership group associated with a share identifier, fallible bc checks existence
+    /// -> unvested shares are left out unless the org's vesting vote policy counts them
     fn get_membership_with_shape(
         organization: T::OrgId,
     ) -> Option<Self::Genesis> {
         if !Self::id_is_available(organization) {
+            let vested_only = <VestingVotePolicies<T>>::get(organization)
+                == VestingVotePolicy::VestedOnly;
             Some(
-                // is this the same performance as a get() with Key=Vec<AccountId>
-                <Members<T>>::iter()
-                    .filter(|(org, _, _)| *org == organization)
-                    .map(|(_, account, profile)| (account, profile.total()))
+                <Members<T>>::iter_prefix(organization)
+                    .map(|(account, profile)| {
+                        let shares = if vested_only {
+                            Self::vested_shares(
+                                organization,
+                                &account,
+                                profile.total(),
+                            )
+                        } else {
+                            profile.total()
+                        };
+                        (account, shares)
+                    })
+                    .filter(|(_, shares)| !shares.is_zero())
                     .collect::<Vec<(T::AccountId, T::Shares)>>()
                     .into(),
             )
@@ -574,6 +1408,7 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         {
             existing_profile.add_shares(amount)
         } else {
+            <MemberCount<T>>::mutate(organization, |count| *count += 1);
             ShareProfile::new_shares(amount)
         };
         if !batch {
@@ -614,6 +1449,9 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         let new_profile = old_profile.subtract_shares(amt_to_burn);
         if new_profile.is_zero() {
             // leave the group
+            <MemberCount<T>>::mutate(organization, |count| {
+                *count = count.saturating_sub(1)
+            });
             <Members<T>>::remove(organization, old_owner);
         } else {
             <Members<T>>::insert(organization, old_owner, new_profile);
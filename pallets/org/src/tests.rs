@@ -2,10 +2,12 @@
 
 use super::*;
 use frame_support::{
+    assert_noop,
     assert_ok,
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::OnFinalize,
     weights::Weight,
 };
 use sp_core::H256;
@@ -14,12 +16,15 @@ use sp_runtime::{
     traits::IdentityLookup,
     Perbill,
 };
+use std::cell::RefCell;
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
 
 impl_outer_origin! {
-    pub enum Origin for TestRuntime {}
+    pub enum Origin for TestRuntime {
+        org<T>,
+    }
 }
 
 mod org {
@@ -29,6 +34,7 @@ mod org {
 impl_outer_event! {
     pub enum TestEvent for TestRuntime {
         system<T>,
+        pallet_balances<T>,
         org<T>,
     }
 }
@@ -68,16 +74,94 @@ impl frame_system::Trait for TestRuntime {
     type BaseCallFilter = ();
     type SystemWeightInfo = ();
 }
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for TestRuntime {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+thread_local! {
+    static ELECTIONS: RefCell<Vec<VoteOutcome>> = RefCell::new(Vec::new());
+}
+/// Stands in for the vote module, outcomes are set with `decide_election`
+pub struct TestVote;
+impl OpenVote<OrgRep<u64>, Threshold<u64>, Threshold<Permill>, u64, u32>
+    for TestVote
+{
+    type VoteIdentifier = u64;
+    fn open_vote(
+        topic: Option<u32>,
+        organization: OrgRep<u64>,
+        _threshold: Threshold<u64>,
+        duration: Option<u64>,
+    ) -> Result<u64, DispatchError> {
+        Self::open_percent_vote(
+            topic,
+            organization,
+            Threshold::new(Permill::one(), None),
+            duration,
+        )
+    }
+    fn open_percent_vote(
+        _topic: Option<u32>,
+        _organization: OrgRep<u64>,
+        _threshold: Threshold<Permill>,
+        duration: Option<u64>,
+    ) -> Result<u64, DispatchError> {
+        assert_eq!(duration, Some(ElectionDuration::get()));
+        ELECTIONS.with(|e| {
+            let mut elections = e.borrow_mut();
+            elections.push(VoteOutcome::Voting);
+            Ok(elections.len() as u64)
+        })
+    }
+}
+impl GetVoteOutcome<u64> for TestVote {
+    type Outcome = VoteOutcome;
+    fn get_vote_outcome(vote_id: u64) -> Result<VoteOutcome, DispatchError> {
+        ELECTIONS.with(|e| {
+            e.borrow()
+                .get(vote_id as usize - 1)
+                .copied()
+                .ok_or(DispatchError::Other("no such election"))
+        })
+    }
+}
+fn decide_election(vote_id: u64, outcome: VoteOutcome) {
+    ELECTIONS.with(|e| e.borrow_mut()[vote_id as usize - 1] = outcome);
+}
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: u64 = 5;
+    pub const SupervisorTermLength: u64 = 10;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
 impl Trait for TestRuntime {
     type Event = TestEvent;
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type OrgOrigin = EnsureOrg<TestRuntime>;
+    type ElectionId = u64;
+    type SupervisorVote = TestVote;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = ();
 }
 pub type System = system::Module<TestRuntime>;
+pub type Balances = pallet_balances::Module<TestRuntime>;
 pub type Org = Module<TestRuntime>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u32> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u32, u64, u64, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -92,6 +176,14 @@ fn get_last_event() -> RawEvent<u64, u64, u64, u32> {
         .unwrap()
 }
 
+/// Auxiliary method for simulating block time passing
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Org::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+    }
+}
+
 fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::default()
         .build_storage::<TestRuntime>()
@@ -237,3 +329,529 @@ fn share_burn() {
         assert_eq!(post_shares, 6);
     });
 }
+
+#[test]
+fn member_count_tracks_group_size() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_eq!(Org::member_count(1), 6);
+        // existing members do not change the count
+        assert_ok!(Org::issue_shares(one.clone(), 1, 1, 10));
+        assert_eq!(Org::member_count(1), 6);
+        assert_ok!(Org::issue_shares(one.clone(), 1, 7, 10));
+        assert_eq!(Org::member_count(1), 7);
+        // burning all shares leaves the group
+        assert_ok!(Org::burn_shares(one.clone(), 1, 7, 10));
+        assert_eq!(Org::member_count(1), 6);
+        assert!(!Org::is_member_of_group(1, &7));
+    });
+}
+
+#[test]
+fn org_origin_can_issue_shares_and_put_supervisor() {
+    new_test_ext().execute_with(|| {
+        let org_one: Origin = RawOrigin::Org(1).into();
+        assert_noop!(
+            Org::issue_shares(Origin::signed(2), 1, 2, 10),
+            Error::<TestRuntime>::NotAuthorizedToIssueShares
+        );
+        assert_ok!(Org::issue_shares(org_one.clone(), 1, 2, 10));
+        assert_eq!(Org::members(1, 2).unwrap().total(), 11);
+        // an org cannot act on behalf of another org
+        assert_ok!(Org::register_flat_org(
+            Origin::signed(1),
+            Some(1),
+            None,
+            1110011,
+            vec![1, 2]
+        ));
+        assert_noop!(
+            Org::burn_shares(RawOrigin::Org(2).into(), 1, 2, 10),
+            Error::<TestRuntime>::NotAuthorizedToBurnShares
+        );
+        assert_ok!(Org::put_supervisor(org_one, 1, 2));
+        assert_eq!(get_last_event(), RawEvent::SupervisorPut(1, 2));
+        assert!(Org::is_organization_supervisor(1, &2));
+        assert_noop!(
+            Org::put_supervisor(Origin::signed(1), 1, 1),
+            Error::<TestRuntime>::NotAuthorizedToPutSupervisor
+        );
+    });
+}
+
+#[test]
+fn org_origin_checks_cover_supervisor_member_and_vote_passed() {
+    new_test_ext().execute_with(|| {
+        let org_one = || -> Origin { RawOrigin::Org(1).into() };
+        // the supervisor check passes for the supervisor and the org itself
+        assert_eq!(
+            EnsureOrgSupervisor::<TestRuntime>::try_org_origin(
+                Origin::signed(1),
+                1
+            )
+            .ok(),
+            Some(Some(1))
+        );
+        assert!(EnsureOrgSupervisor::<TestRuntime>::try_org_origin(
+            Origin::signed(2),
+            1
+        )
+        .is_err());
+        assert_eq!(
+            EnsureOrgSupervisor::<TestRuntime>::try_org_origin(org_one(), 1)
+                .ok(),
+            Some(None)
+        );
+        // the member check passes for any member and the org itself
+        assert_eq!(
+            EnsureOrgMember::<TestRuntime>::try_org_origin(
+                Origin::signed(2),
+                1
+            )
+            .ok(),
+            Some(Some(2))
+        );
+        assert!(EnsureOrgMember::<TestRuntime>::try_org_origin(
+            Origin::signed(7),
+            1
+        )
+        .is_err());
+        assert!(EnsureOrgMember::<TestRuntime>::try_org_origin(org_one(), 2)
+            .is_err());
+        // only the org itself passes the vote passed check
+        assert!(EnsureOrgVotePassed::<TestRuntime>::try_org_origin(
+            Origin::signed(1),
+            1
+        )
+        .is_err());
+        assert_ok!(EnsureOrgVotePassed::<TestRuntime>::ensure_org_origin(
+            org_one(),
+            1
+        ));
+        // members may only lock their own shares while the org may lock any
+        assert_noop!(
+            Org::lock_shares(Origin::signed(2), 1, 3),
+            Error::<TestRuntime>::NotAuthorizedToLockShares
+        );
+        assert_ok!(Org::lock_shares(org_one(), 1, 3));
+    });
+}
+
+#[test]
+fn members_elect_and_recall_supervisor() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::propose_supervisor(Origin::signed(7), 1, 2),
+            Error::<TestRuntime>::NotAuthorizedToProposeSupervisor
+        );
+        assert_noop!(
+            Org::propose_supervisor(Origin::signed(2), 1, 7),
+            Error::<TestRuntime>::SupervisorCandidateMustBeMember
+        );
+        assert_ok!(Org::propose_supervisor(Origin::signed(2), 1, 3));
+        assert_eq!(get_last_event(), RawEvent::SupervisorProposed(2, 1, 3, 1));
+        assert_noop!(
+            Org::recall_supervisor(Origin::signed(4), 1),
+            Error::<TestRuntime>::SupervisorElectionAlreadyPending
+        );
+        run_to_block(2);
+        assert!(Org::is_organization_supervisor(1, &1));
+        decide_election(1, VoteOutcome::Approved);
+        run_to_block(3);
+        assert_eq!(get_last_event(), RawEvent::SupervisorElected(1, 3, 12));
+        assert!(Org::is_organization_supervisor(1, &3));
+        assert_eq!(Org::supervisor_terms(1), Some(12));
+        assert_ok!(Org::recall_supervisor(Origin::signed(4), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SupervisorRecallProposed(4, 1, 2)
+        );
+        decide_election(2, VoteOutcome::Approved);
+        run_to_block(4);
+        assert_eq!(get_last_event(), RawEvent::SupervisorRecalled(1, 3));
+        assert!(!Org::is_organization_supervisor(1, &3));
+        assert!(Org::supervisor_terms(1).is_none());
+        assert_noop!(
+            Org::recall_supervisor(Origin::signed(4), 1),
+            Error::<TestRuntime>::NoSupervisorToRecall
+        );
+    });
+}
+
+#[test]
+fn elected_supervisor_serves_until_term_ends() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::propose_supervisor(Origin::signed(2), 1, 3));
+        decide_election(1, VoteOutcome::Rejected);
+        run_to_block(2);
+        assert_eq!(get_last_event(), RawEvent::SupervisorElectionFailed(1, 1));
+        assert!(Org::supervisor_elections(1).is_none());
+        assert!(Org::is_organization_supervisor(1, &1));
+        assert_ok!(Org::propose_supervisor(Origin::signed(2), 1, 3));
+        decide_election(2, VoteOutcome::Approved);
+        run_to_block(3);
+        assert_eq!(get_last_event(), RawEvent::SupervisorElected(1, 3, 12));
+        run_to_block(12);
+        assert!(Org::is_organization_supervisor(1, &3));
+        run_to_block(13);
+        assert_eq!(get_last_event(), RawEvent::SupervisorTermEnded(1, 3));
+        assert!(!Org::is_organization_supervisor(1, &3));
+        assert!(Org::supervisor_terms(1).is_none());
+    });
+}
+
+#[test]
+fn supervisor_cannot_be_elected_beyond_consecutive_term_limit() {
+    new_test_ext().execute_with(|| {
+        // 3 is elected for the 2 consecutive terms allowed
+        for vote_id in 1u64..3u64 {
+            assert_ok!(Org::propose_supervisor(Origin::signed(2), 1, 3));
+            decide_election(vote_id, VoteOutcome::Approved);
+            run_to_block(System::block_number() + 1);
+            assert!(Org::is_organization_supervisor(1, &3));
+        }
+        assert_eq!(Org::consecutive_terms(1), Some((3, 2)));
+        assert_noop!(
+            Org::propose_supervisor(Origin::signed(2), 1, 3),
+            Error::<TestRuntime>::SupervisorTermLimitReached
+        );
+        // the count restarts once another supervisor holds the seat
+        assert_ok!(Org::propose_supervisor(Origin::signed(2), 1, 4));
+        decide_election(3, VoteOutcome::Approved);
+        run_to_block(System::block_number() + 1);
+        assert!(Org::is_organization_supervisor(1, &4));
+        assert_eq!(Org::consecutive_terms(1), Some((4, 1)));
+        assert_ok!(Org::propose_supervisor(Origin::signed(2), 1, 3));
+    });
+}
+
+#[test]
+fn share_transfers_respect_locks_and_transfer_policy() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::register_weighted_org(
+            Origin::signed(1),
+            Some(1),
+            None,
+            1738,
+            vec![(1, 10), (2, 10)]
+        ));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(1), 2, 7, 0),
+            Error::<TestRuntime>::CannotTransferZeroShares
+        );
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(7), 2, 1, 1),
+            Error::<TestRuntime>::AccountHasNoOwnershipInOrg
+        );
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(1), 2, 7, 11),
+            Error::<TestRuntime>::NotEnoughSharesToTransfer
+        );
+        assert_ok!(Org::lock_shares(Origin::signed(1), 2, 1));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(1), 2, 7, 4),
+            Error::<TestRuntime>::CannotTransferLockedShares
+        );
+        assert_ok!(Org::unlock_shares(Origin::signed(1), 2, 1));
+        assert_ok!(Org::transfer_shares(Origin::signed(1), 2, 7, 4));
+        assert_eq!(get_last_event(), RawEvent::SharesTransferred(2, 1, 7, 4));
+        assert_eq!(Org::members(2, 1).unwrap().total(), 6);
+        assert_eq!(Org::members(2, 7).unwrap().total(), 4);
+        assert_eq!(Org::total_issuance(2), 20);
+        assert_eq!(Org::member_count(2), 3);
+        // members only
+        assert_noop!(
+            Org::set_transfer_policy(
+                Origin::signed(2),
+                2,
+                TransferPolicy::MembersOnly
+            ),
+            Error::<TestRuntime>::NotAuthorizedToSetTransferPolicy
+        );
+        assert_ok!(Org::set_transfer_policy(
+            Origin::signed(1),
+            2,
+            TransferPolicy::MembersOnly
+        ));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(7), 2, 8, 1),
+            Error::<TestRuntime>::RecipientMustBeMemberToReceiveShares
+        );
+        // sending every share leaves the group
+        assert_ok!(Org::transfer_shares(Origin::signed(7), 2, 2, 4));
+        assert!(!Org::is_member_of_group(2, &7));
+        assert_eq!(Org::member_count(2), 2);
+        // approval required
+        assert_ok!(Org::set_transfer_policy(
+            Origin::signed(1),
+            2,
+            TransferPolicy::ApprovalRequired
+        ));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(2), 2, 8, 2),
+            Error::<TestRuntime>::TransferNotApproved
+        );
+        assert_noop!(
+            Org::approve_transfer(Origin::signed(2), 2, 2, 8, 3),
+            Error::<TestRuntime>::NotAuthorizedToApproveTransfer
+        );
+        assert_ok!(Org::approve_transfer(Origin::signed(1), 2, 2, 8, 3));
+        assert_ok!(Org::transfer_shares(Origin::signed(2), 2, 8, 2));
+        assert_eq!(Org::transfer_approvals(2, (2, 8)), Some(1));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(2), 2, 8, 2),
+            Error::<TestRuntime>::TransferNotApproved
+        );
+        assert_ok!(Org::transfer_shares(Origin::signed(2), 2, 8, 1));
+        assert!(Org::transfer_approvals(2, (2, 8)).is_none());
+    });
+}
+
+#[test]
+fn sell_orders_trade_shares_for_native_currency() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::register_weighted_org(
+            Origin::signed(1),
+            Some(1),
+            None,
+            1738,
+            vec![(1, 10), (2, 10)]
+        ));
+        Balances::make_free_balance_be(&7, 100);
+        assert_noop!(
+            Org::list_shares(Origin::signed(1), 2, 11, 3),
+            Error::<TestRuntime>::NotEnoughSharesToTransfer
+        );
+        assert_ok!(Org::list_shares(Origin::signed(1), 2, 5, 3));
+        assert_eq!(get_last_event(), RawEvent::SharesListed(1, 2, 1, 5, 3));
+        assert_noop!(
+            Org::buy_shares(Origin::signed(7), 1, 6),
+            Error::<TestRuntime>::CannotBuyMoreSharesThanListed
+        );
+        assert_noop!(
+            Org::buy_shares(Origin::signed(8), 1, 2),
+            pallet_balances::Error::<
+                TestRuntime,
+                pallet_balances::DefaultInstance,
+            >::InsufficientBalance
+        );
+        assert_ok!(Org::buy_shares(Origin::signed(7), 1, 2));
+        assert_eq!(get_last_event(), RawEvent::SharesBought(7, 1, 2, 6));
+        assert_eq!(Balances::free_balance(7), 94);
+        assert_eq!(Balances::free_balance(1), 6);
+        assert_eq!(Org::members(2, 1).unwrap().total(), 8);
+        assert_eq!(Org::members(2, 7).unwrap().total(), 2);
+        assert_eq!(Org::sell_orders(1).unwrap().amount(), 3);
+        // the seller must still hold the shares when the order is filled
+        assert_ok!(Org::lock_shares(Origin::signed(1), 2, 1));
+        assert_noop!(
+            Org::buy_shares(Origin::signed(7), 1, 3),
+            Error::<TestRuntime>::CannotTransferLockedShares
+        );
+        assert_ok!(Org::unlock_shares(Origin::signed(1), 2, 1));
+        assert_ok!(Org::buy_shares(Origin::signed(7), 1, 3));
+        assert!(Org::sell_orders(1).is_none());
+        assert_eq!(Org::members(2, 7).unwrap().total(), 5);
+        assert_ok!(Org::list_shares(Origin::signed(2), 2, 1, 1));
+        assert_noop!(
+            Org::cancel_sell_order(Origin::signed(7), 2),
+            Error::<TestRuntime>::NotAuthorizedToCancelSellOrder
+        );
+        assert_ok!(Org::cancel_sell_order(Origin::signed(2), 2));
+        assert_eq!(get_last_event(), RawEvent::SellOrderCancelled(2));
+        assert_noop!(
+            Org::buy_shares(Origin::signed(7), 2, 1),
+            Error::<TestRuntime>::SellOrderDNE
+        );
+        // the buyer pays nothing if the seller no longer holds the shares
+        assert_ok!(Org::list_shares(Origin::signed(2), 2, 10, 1));
+        assert_ok!(Org::transfer_shares(Origin::signed(2), 2, 1, 5));
+        assert_noop!(
+            Org::buy_shares(Origin::signed(7), 3, 10),
+            Error::<TestRuntime>::NotEnoughSharesToTransfer
+        );
+        assert_eq!(Balances::free_balance(7), 85);
+    });
+}
+
+#[test]
+fn burning_member_before_cliff_returns_unvested_shares() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::issue_vested_shares(
+            Origin::signed(1),
+            1,
+            7,
+            100,
+            4,
+            10
+        ));
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 7, 10));
+        assert_eq!(Org::total_issuance(1), 116);
+        // removing the member before the cliff only burns the shares they own outright
+        System::set_block_number(3);
+        assert_noop!(
+            Org::burn_shares(Origin::signed(1), 1, 7, 111),
+            Error::<TestRuntime>::NotEnoughSharesToSatisfyBurnRequest
+        );
+        assert_ok!(Org::burn_shares(Origin::signed(1), 1, 7, 110));
+        assert_eq!(get_last_event(), RawEvent::SharesBurned(1, 7, 10));
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::org(RawEvent::VestingRevoked(1, 7, 100))
+        }));
+        assert!(!Org::is_member_of_group(1, &7));
+        assert!(Org::vesting_schedules(1, 7).is_none());
+        assert_eq!(Org::members(1, 1).unwrap().total(), 101);
+        assert_eq!(Org::total_issuance(1), 106);
+        // the same holds for batch burns
+        assert_ok!(Org::issue_vested_shares(
+            Origin::signed(1),
+            1,
+            8,
+            20,
+            4,
+            10
+        ));
+        assert_ok!(Org::batch_burn_shares(Origin::signed(1), 1, vec![(8, 20)]));
+        assert!(!Org::is_member_of_group(1, &8));
+        assert_eq!(Org::members(1, 1).unwrap().total(), 121);
+        assert_eq!(Org::total_issuance(1), 126);
+    });
+}
+
+#[test]
+fn partial_burn_only_returns_the_unvested_shares_it_takes() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::issue_vested_shares(
+            Origin::signed(1),
+            1,
+            7,
+            100,
+            4,
+            10
+        ));
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 7, 10));
+        // half vested so 60 of the 110 held are vested
+        System::set_block_number(6);
+        // a batch with any invalid entry changes nothing
+        assert_noop!(
+            Org::batch_burn_shares(Origin::signed(1), 1, vec![(7, 80), (8, 5)]),
+            Error::<TestRuntime>::NotEnoughSharesToSatisfyBurnRequest
+        );
+        assert_noop!(
+            Org::batch_burn_shares(
+                Origin::signed(1),
+                1,
+                vec![(7, 60), (7, 60)]
+            ),
+            Error::<TestRuntime>::BatchBurnAccountsMustBeUnique
+        );
+        // burning 80 burns the 60 vested and returns 20 of the 50 unvested
+        assert_ok!(Org::burn_shares(Origin::signed(1), 1, 7, 80));
+        assert_eq!(get_last_event(), RawEvent::SharesBurned(1, 7, 60));
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::org(RawEvent::VestingRevoked(1, 7, 20))
+        }));
+        assert_eq!(Org::members(1, 7).unwrap().total(), 30);
+        assert_eq!(Org::members(1, 1).unwrap().total(), 21);
+        assert_eq!(Org::total_issuance(1), 56);
+        // the other 30 keep vesting on the same timeline
+        assert_eq!(Org::vested_shares(1, &7, 30), 0);
+        System::set_block_number(8);
+        assert_eq!(Org::vested_shares(1, &7, 30), 12);
+        System::set_block_number(11);
+        assert_eq!(Org::vested_shares(1, &7, 30), 30);
+    });
+}
+
+#[test]
+fn vested_shares_unlock_linearly_and_return_on_revoke() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::issue_vested_shares(Origin::signed(2), 1, 7, 100, 4, 10),
+            Error::<TestRuntime>::NotAuthorizedToIssueShares
+        );
+        assert_noop!(
+            Org::issue_vested_shares(Origin::signed(1), 1, 7, 0, 4, 10),
+            Error::<TestRuntime>::CannotVestZeroShares
+        );
+        assert_noop!(
+            Org::issue_vested_shares(Origin::signed(1), 1, 7, 100, 0, 0),
+            Error::<TestRuntime>::VestingDurationMustBeNonZero
+        );
+        assert_noop!(
+            Org::issue_vested_shares(Origin::signed(1), 1, 7, 100, 11, 10),
+            Error::<TestRuntime>::VestingCliffCannotExceedDuration
+        );
+        assert_ok!(Org::issue_vested_shares(
+            Origin::signed(1),
+            1,
+            7,
+            100,
+            4,
+            10
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VestedSharesIssued(1, 7, 100, 5, 11)
+        );
+        assert_eq!(Org::total_issuance(1), 106);
+        // unvested shares do not mint signal by default
+        assert_eq!(Org::get_membership_with_shape(1).unwrap().total(), 6);
+        assert_ok!(Org::set_vesting_vote_policy(
+            Origin::signed(1),
+            1,
+            VestingVotePolicy::AllShares
+        ));
+        assert_eq!(Org::get_membership_with_shape(1).unwrap().total(), 106);
+        assert_ok!(Org::set_vesting_vote_policy(
+            Origin::signed(1),
+            1,
+            VestingVotePolicy::VestedOnly
+        ));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(7), 1, 2, 1),
+            Error::<TestRuntime>::CannotTransferUnvestedShares
+        );
+        // half vested
+        System::set_block_number(6);
+        assert_eq!(Org::vested_shares(1, &7, 100), 50);
+        assert_eq!(Org::get_membership_with_shape(1).unwrap().total(), 56);
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(7), 1, 2, 51),
+            Error::<TestRuntime>::CannotTransferUnvestedShares
+        );
+        assert_ok!(Org::transfer_shares(Origin::signed(7), 1, 2, 50));
+        assert_noop!(
+            Org::issue_vested_shares(Origin::signed(1), 1, 7, 10, 0, 10),
+            Error::<TestRuntime>::VestingScheduleAlreadyExists
+        );
+        // the unvested half returns to the issuer
+        assert_noop!(
+            Org::revoke_vesting(Origin::signed(2), 1, 7),
+            Error::<TestRuntime>::NotAuthorizedToRevokeVesting
+        );
+        assert_ok!(Org::revoke_vesting(Origin::signed(1), 1, 7));
+        assert_eq!(get_last_event(), RawEvent::VestingRevoked(1, 7, 50));
+        assert!(!Org::is_member_of_group(1, &7));
+        assert_eq!(Org::members(1, 1).unwrap().total(), 51);
+        assert_eq!(Org::total_issuance(1), 106);
+        assert_noop!(
+            Org::revoke_vesting(Origin::signed(1), 1, 7),
+            Error::<TestRuntime>::NoVestingScheduleToRevoke
+        );
+        // shares vested by the org itself return to its unissued supply
+        assert_ok!(Org::issue_vested_shares(
+            RawOrigin::Org(1).into(),
+            1,
+            8,
+            20,
+            5,
+            10
+        ));
+        System::set_block_number(10);
+        assert_ok!(Org::revoke_vesting(RawOrigin::Org(1).into(), 1, 8));
+        assert_eq!(get_last_event(), RawEvent::VestingRevoked(1, 8, 20));
+        assert!(!Org::is_member_of_group(1, &8));
+        assert_eq!(Org::total_issuance(1), 106);
+    });
+}
@@ -0,0 +1,74 @@
+//! Proposals pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_support::traits::OnFinalize;
+use frame_system::RawOrigin;
+use sp_std::vec;
+use util::{
+    organization::{
+        OrgRep,
+        OrganizationSource,
+    },
+    traits::RegisterOrganization,
+    vote::{
+        Threshold,
+        ThresholdInput,
+        VoterView,
+    },
+};
+
+const MAX_PROPOSALS: u32 = 100;
+
+benchmarks! {
+    _ { }
+
+    propose {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = <org::Module<T>>::register_organization(
+            OrganizationSource::Accounts(vec![caller.clone()]),
+            Some(caller.clone()),
+            T::Cid::default(),
+        )?;
+        let threshold_id = <vote::Module<T>>::register_threshold(ThresholdInput::new(
+            OrgRep::Equal(org),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        ))?;
+        let call: <T as Trait>::Proposal = frame_system::Call::<T>::remark(vec![]).into();
+    }: _(RawOrigin::Signed(caller), None, threshold_id, Box::new(call))
+    verify {
+        assert_eq!(<Proposals<T>>::iter().count(), 1);
+    }
+
+    poll_proposals {
+        let p in 1 .. MAX_PROPOSALS;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = <org::Module<T>>::register_organization(
+            OrganizationSource::Accounts(vec![caller.clone()]),
+            Some(caller.clone()),
+            T::Cid::default(),
+        )?;
+        let threshold_id = <vote::Module<T>>::register_threshold(ThresholdInput::new(
+            OrgRep::Equal(org),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        ))?;
+        for _ in 0..p {
+            let call: <T as Trait>::Proposal = frame_system::Call::<T>::remark(vec![]).into();
+            Module::<T>::propose(RawOrigin::Signed(caller.clone()).into(), None, threshold_id, Box::new(call))?;
+        }
+        // every proposal passes so every call is dispatched
+        let pending: Vec<T::VoteId> = <Proposals<T>>::iter().map(|(vote_id, _)| vote_id).collect();
+        for vote_id in pending {
+            <vote::Module<T>>::submit_vote(RawOrigin::Signed(caller.clone()).into(), vote_id, VoterView::InFavor, None)?;
+        }
+        let now = <frame_system::Module<T>>::block_number();
+        <ProposalPollFrequency<T>>::put(T::BlockNumber::from(1u32));
+    }: { Module::<T>::on_finalize(now) }
+    verify {
+        assert_eq!(Module::<T>::proposal_count(), 0);
+    }
+}
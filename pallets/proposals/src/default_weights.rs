@@ -0,0 +1,45 @@
+//! Default weights for the proposals pallet, to be regenerated from `benchmarking.rs`
+//! with the `benchmark` subcommand of a node built with `runtime-benchmarks`
+//!
+//! The runtime charges database access at its own `DbWeight` with `SubstrateWeight`,
+//! `()` charges `RocksDbWeight` for the mocks
+
+use frame_support::{
+    traits::Get,
+    weights::{
+        constants::RocksDbWeight as DbWeight,
+        Weight,
+    },
+};
+use sp_std::marker::PhantomData;
+
+impl crate::WeightInfo for () {
+    fn propose() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(DbWeight::get().writes(7 as Weight))
+    }
+    fn poll_proposals(p: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((30_000_000 as Weight).saturating_mul(p as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads(2 * p as Weight))
+            .saturating_add(DbWeight::get().writes(2 * p as Weight))
+    }
+}
+
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> crate::WeightInfo for SubstrateWeight<T> {
+    fn propose() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(8 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
+    }
+    fn poll_proposals(p: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((30_000_000 as Weight).saturating_mul(p as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 * p as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 * p as Weight))
+    }
+}
@@ -0,0 +1,214 @@
+#![recursion_limit = "256"]
+#![allow(clippy::string_lit_as_bytes)]
+#![allow(clippy::redundant_closure_call)]
+#![allow(clippy::type_complexity)]
+#![cfg_attr(not(feature = "std"), no_std)]
+//! Governance proposals which dispatch a runtime call as the organization
+//! once the vote on the proposal passes
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
+#[cfg(test)]
+mod tests;
+
+use frame_support::{
+    decl_error,
+    decl_event,
+    decl_module,
+    decl_storage,
+    dispatch::{
+        Dispatchable,
+        PostDispatchInfo,
+    },
+    ensure,
+    storage::IterableStorageMap,
+    traits::Get,
+    weights::{
+        GetDispatchInfo,
+        Weight,
+    },
+    Parameter,
+};
+use sp_runtime::{
+    traits::Zero,
+    DispatchResult,
+    Permill,
+};
+use sp_std::prelude::*;
+use util::{
+    proposal::Proposal,
+    traits::{
+        ConfigureThreshold,
+        EnsureOrgOrigin,
+        GetVoteOutcome,
+    },
+    vote::{
+        VoteOutcome,
+        XorThreshold,
+    },
+};
+
+type Prop<T> = Proposal<
+    <T as org::Trait>::OrgId,
+    <T as frame_system::Trait>::AccountId,
+    <T as Trait>::Proposal,
+>;
+
+pub trait WeightInfo {
+    fn propose() -> Weight;
+    fn poll_proposals(p: u32) -> Weight;
+}
+
+pub trait Trait: frame_system::Trait + org::Trait + vote::Trait {
+    /// The overarching event type
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// The outer origin, must be constructible from the org origin
+    type Origin: From<org::RawOrigin<Self::OrgId>>;
+
+    /// The outer call dispatched by passing proposals
+    type Proposal: Parameter
+        + Dispatchable<
+            Origin = <Self as Trait>::Origin,
+            PostInfo = PostDispatchInfo,
+        > + GetDispatchInfo
+        + From<frame_system::Call<Self>>;
+
+    /// Lowest percent threshold a proposal's vote may be opened with
+    type MinProposalThreshold: Get<Permill>;
+
+    /// Number of blocks that members may vote on a proposal
+    type ProposalDuration: Get<Self::BlockNumber>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+decl_event!(
+    pub enum Event<T>
+    where
+        <T as frame_system::Trait>::AccountId,
+        <T as org::Trait>::OrgId,
+        <T as vote::Trait>::VoteId,
+    {
+        /// Proposer, Organization, Vote Identifier
+        Proposed(AccountId, OrgId, VoteId),
+        /// Vote Identifier, Result of dispatching the call as the organization
+        ProposalExecuted(VoteId, DispatchResult),
+        /// Vote Identifier
+        ProposalRejected(VoteId),
+    }
+);
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        NotAuthorizedToProposeForOrg,
+        ProposalThresholdDNE,
+        ProposalThresholdMustBePercent,
+        ProposalThresholdBelowMinimum,
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as Proposals {
+        /// Calls waiting on the outcome of their vote
+        pub Proposals get(fn proposals): map
+            hasher(blake2_128_concat) T::VoteId => Option<Prop<T>>;
+        /// Number of proposals waiting on their vote
+        pub ProposalCount get(fn proposal_count): u32;
+        /// Sum of the weights of the calls waiting on their vote
+        pub PendingCallWeight get(fn pending_call_weight): Weight;
+
+        /// Frequency for which all proposals are polled and dispatched if passed
+        ProposalPollFrequency get(fn proposal_poll_frequency) config(): T::BlockNumber;
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: <T as frame_system::Trait>::Origin {
+        type Error = Error<T>;
+        fn deposit_event() = default;
+
+        #[weight = <T as Trait>::WeightInfo::propose().saturating_add(call.get_dispatch_info().weight)]
+        fn propose(
+            origin,
+            topic: Option<T::Cid>,
+            threshold_id: T::ThresholdId,
+            call: Box<<T as Trait>::Proposal>,
+        ) -> DispatchResult {
+            // the vote uses a threshold registered by the supervisor or the org
+            let config = <vote::Module<T>>::vote_thresholds(threshold_id)
+                .ok_or(Error::<T>::ProposalThresholdDNE)?;
+            let org = config.org().org();
+            // only members may propose, the org itself acts through passed proposals
+            let proposer = org::EnsureOrgMember::<T>::try_org_origin(origin, org)
+                .ok()
+                .flatten()
+                .ok_or(Error::<T>::NotAuthorizedToProposeForOrg)?;
+            match config.threshold() {
+                XorThreshold::Percent(t) => ensure!(
+                    t.in_favor() >= T::MinProposalThreshold::get(),
+                    Error::<T>::ProposalThresholdBelowMinimum
+                ),
+                XorThreshold::Signal(_) => return Err(Error::<T>::ProposalThresholdMustBePercent.into()),
+            }
+            let vote_id = <vote::Module<T>>::invoke_threshold(threshold_id, topic, Some(T::ProposalDuration::get()))?;
+            let call_weight = call.get_dispatch_info().weight;
+            <Proposals<T>>::insert(vote_id, Proposal::new(org, proposer.clone(), *call));
+            <ProposalCount>::mutate(|count| *count += 1);
+            <PendingCallWeight>::mutate(|weight| *weight = weight.saturating_add(call_weight));
+            Self::deposit_event(RawEvent::Proposed(proposer, org, vote_id));
+            Ok(())
+        }
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            // charges polling every proposal and dispatching every pending call, as if all passed
+            if n % Self::proposal_poll_frequency() == Zero::zero() {
+                <T as Trait>::WeightInfo::poll_proposals(<ProposalCount>::get())
+                    .saturating_add(<PendingCallWeight>::get())
+                    .saturating_add(T::DbWeight::get().reads(2))
+            } else {
+                0
+            }
+        }
+        fn on_finalize(_n: T::BlockNumber) {
+            if <frame_system::Module<T>>::block_number() % Self::proposal_poll_frequency() == Zero::zero() {
+                let pending: Vec<T::VoteId> = <Proposals<T>>::iter().map(|(vote_id, _)| vote_id).collect();
+                pending.into_iter().for_each(Self::poll_proposal);
+            }
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Dispatches the call if its vote passed and removes it once the vote is decided
+    fn poll_proposal(vote_id: T::VoteId) {
+        match <vote::Module<T>>::get_vote_outcome(vote_id) {
+            Ok(VoteOutcome::Approved) => {
+                if let Some(proposal) = Self::take_proposal(vote_id) {
+                    let origin = org::RawOrigin::Org(proposal.org()).into();
+                    let result = proposal.call().dispatch(origin);
+                    Self::deposit_event(RawEvent::ProposalExecuted(
+                        vote_id,
+                        result.map(|_| ()).map_err(|e| e.error),
+                    ));
+                }
+            }
+            Ok(VoteOutcome::NotStarted) | Ok(VoteOutcome::Voting) => (),
+            // rejected or the vote was pruned
+            _ => {
+                Self::take_proposal(vote_id);
+                Self::deposit_event(RawEvent::ProposalRejected(vote_id));
+            }
+        }
+    }
+    fn take_proposal(vote_id: T::VoteId) -> Option<Prop<T>> {
+        let proposal = <Proposals<T>>::take(vote_id)?;
+        let call_weight = proposal.call_ref().get_dispatch_info().weight;
+        <ProposalCount>::mutate(|count| *count = count.saturating_sub(1));
+        <PendingCallWeight>::mutate(|weight| {
+            *weight = weight.saturating_sub(call_weight)
+        });
+        Some(proposal)
+    }
+}
@@ -0,0 +1,326 @@
+use super::*;
+use frame_support::{
+    assert_noop,
+    assert_ok,
+    impl_outer_dispatch,
+    impl_outer_event,
+    impl_outer_origin,
+    parameter_types,
+    traits::{
+        OnFinalize,
+        OnInitialize,
+    },
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::IdentityLookup,
+    Perbill,
+};
+use util::{
+    organization::OrgRep,
+    vote::{
+        Threshold,
+        ThresholdInput,
+        VoterView,
+    },
+};
+
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {
+        org<T>,
+    }
+}
+
+impl_outer_dispatch! {
+    pub enum Call for Test where origin: Origin {
+        frame_system::System,
+        org::Org,
+    }
+}
+
+mod proposals {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        frame_system<T>,
+        pallet_balances<T>,
+        org<T>,
+        vote<T>,
+        proposals<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+    type Origin = Origin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Call = Call;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type MaximumBlockLength = MaximumBlockLength;
+    type Version = ();
+    type ModuleToIndex = ();
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type BaseCallFilter = ();
+    type SystemWeightInfo = ();
+}
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: u64 = 5;
+    pub const SupervisorTermLength: u64 = 10;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
+impl org::Trait for Test {
+    type Event = TestEvent;
+    type Cid = u32;
+    type OrgId = u64;
+    type Shares = u64;
+    type OrgOrigin = org::EnsureOrg<Test>;
+    type ElectionId = u64;
+    type SupervisorVote = vote::Module<Test>;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const MaxDelegators: u32 = 3;
+    pub const MaxOptions: u32 = 5;
+    pub const VoteRetentionPeriod: Option<u64> = None;
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type VoteId = u64;
+    type Signal = u64;
+    type ThresholdId = u64;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type MaxDelegators = MaxDelegators;
+    type MaxOptions = MaxOptions;
+    type VoteRetentionPeriod = VoteRetentionPeriod;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const MinProposalThreshold: Permill = Permill::from_percent(51);
+    pub const ProposalDuration: u64 = 2;
+}
+impl Trait for Test {
+    type Event = TestEvent;
+    type Origin = Origin;
+    type Proposal = Call;
+    type MinProposalThreshold = MinProposalThreshold;
+    type ProposalDuration = ProposalDuration;
+    type WeightInfo = ();
+}
+pub type System = frame_system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type Org = org::Module<Test>;
+pub type Vote = vote::Module<Test>;
+pub type Proposals = Module<Test>;
+
+fn get_last_event() -> RawEvent<u64, u64, u64> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let TestEvent::proposals(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .last()
+        .unwrap()
+}
+
+/// Auxiliary method for simulating block time passing
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Vote::on_finalize(System::block_number());
+        Proposals::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+    }
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    org::GenesisConfig::<Test> {
+        first_organization_supervisor: 1,
+        first_organization_value_constitution: 1738,
+        first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    GenesisConfig::<Test> {
+        proposal_poll_frequency: 1,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn register_percent_threshold(percent: u32) -> u64 {
+    Vote::register_threshold(ThresholdInput::new(
+        OrgRep::Equal(1),
+        XorThreshold::Percent(Threshold::new(
+            Permill::from_percent(percent),
+            None,
+        )),
+    ))
+    .unwrap()
+}
+
+fn issue_shares_to_seven() -> Box<Call> {
+    Box::new(Call::Org(org::Call::issue_shares(1, 7, 10)))
+}
+
+#[test]
+fn passed_proposal_dispatches_call_as_org() {
+    new_test_ext().execute_with(|| {
+        let majority = register_percent_threshold(51);
+        assert_noop!(
+            Proposals::propose(
+                Origin::signed(7),
+                None,
+                majority,
+                issue_shares_to_seven()
+            ),
+            Error::<Test>::NotAuthorizedToProposeForOrg
+        );
+        assert_ok!(Proposals::propose(
+            Origin::signed(2),
+            None,
+            majority,
+            issue_shares_to_seven()
+        ));
+        assert_eq!(get_last_event(), RawEvent::Proposed(2, 1, 1));
+        // the poll is charged for dispatching the pending call
+        let call_weight = issue_shares_to_seven().get_dispatch_info().weight;
+        assert_eq!(Proposals::proposal_count(), 1);
+        assert_eq!(Proposals::pending_call_weight(), call_weight);
+        assert!(Proposals::on_initialize(1) > call_weight);
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert!(!Org::is_member_of_group(1, &7));
+        run_to_block(2);
+        assert_eq!(get_last_event(), RawEvent::ProposalExecuted(1, Ok(())));
+        assert!(Org::is_member_of_group(1, &7));
+        assert!(Proposals::proposals(1).is_none());
+        assert_eq!(Proposals::proposal_count(), 0);
+        assert_eq!(Proposals::pending_call_weight(), 0);
+    });
+}
+
+#[test]
+fn rejected_proposal_is_removed_without_dispatch() {
+    new_test_ext().execute_with(|| {
+        let majority = register_percent_threshold(51);
+        assert_ok!(Proposals::propose(
+            Origin::signed(2),
+            None,
+            majority,
+            issue_shares_to_seven()
+        ));
+        run_to_block(3);
+        assert!(Proposals::proposals(1).is_some());
+        // the vote closes without support at its end block
+        run_to_block(4);
+        assert_eq!(get_last_event(), RawEvent::ProposalRejected(1));
+        assert!(Proposals::proposals(1).is_none());
+        assert!(!Org::is_member_of_group(1, &7));
+    });
+}
+
+#[test]
+fn proposal_below_minimum_threshold_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let zero = register_percent_threshold(0);
+        assert_noop!(
+            Proposals::propose(
+                Origin::signed(2),
+                None,
+                zero,
+                issue_shares_to_seven()
+            ),
+            Error::<Test>::ProposalThresholdBelowMinimum
+        );
+        let signal = Vote::register_threshold(ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Signal(Threshold::new(1, None)),
+        ))
+        .unwrap();
+        assert_noop!(
+            Proposals::propose(
+                Origin::signed(2),
+                None,
+                signal,
+                issue_shares_to_seven()
+            ),
+            Error::<Test>::ProposalThresholdMustBePercent
+        );
+        assert_noop!(
+            Proposals::propose(
+                Origin::signed(2),
+                None,
+                22,
+                issue_shares_to_seven()
+            ),
+            Error::<Test>::ProposalThresholdDNE
+        );
+        assert!(Proposals::proposals(1).is_none());
+    });
+}
@@ -0,0 +1,40 @@
+//! Tokens pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+    _ { }
+
+    transfer {
+        let caller: T::AccountId = whitelisted_caller();
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = T::CurrencyId::default();
+        <Module<T> as MultiCurrency<T::AccountId>>::deposit(currency_id, &caller, 1_000_000u32.into())?;
+    }: _(RawOrigin::Signed(caller), dest.clone(), currency_id, 10_000u32.into())
+    verify {
+        assert_eq!(
+            <Module<T> as MultiCurrency<T::AccountId>>::free_balance(currency_id, &dest),
+            10_000u32.into()
+        );
+    }
+
+    mint {
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = T::CurrencyId::default();
+    }: _(RawOrigin::Root, dest.clone(), currency_id, 10_000u32.into())
+    verify {
+        assert_eq!(
+            <Module<T> as MultiCurrency<T::AccountId>>::free_balance(currency_id, &dest),
+            10_000u32.into()
+        );
+    }
+}
@@ -0,0 +1,308 @@
+#![allow(clippy::string_lit_as_bytes)]
+#![allow(clippy::redundant_closure_call)]
+#![allow(clippy::type_complexity)]
+#![cfg_attr(not(feature = "std"), no_std)]
+//! Multi-asset balances, the native token is routed to `NativeCurrency`
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
+#[cfg(test)]
+mod tests;
+
+use codec::Codec;
+use frame_support::{
+    decl_error,
+    decl_event,
+    decl_module,
+    decl_storage,
+    ensure,
+    traits::{
+        Currency,
+        ExistenceRequirement,
+        Get,
+        Imbalance,
+        WithdrawReasons,
+    },
+    weights::Weight,
+    Parameter,
+};
+use frame_system::{
+    ensure_root,
+    ensure_signed,
+};
+use sp_runtime::{
+    traits::{
+        AtLeast32BitUnsigned,
+        CheckedAdd,
+        CheckedSub,
+        MaybeSerializeDeserialize,
+        Member,
+        Zero,
+    },
+    DispatchResult,
+};
+use sp_std::{
+    fmt::Debug,
+    prelude::*,
+};
+use util::traits::MultiCurrency;
+
+pub trait WeightInfo {
+    fn transfer() -> Weight;
+    fn mint() -> Weight;
+}
+
+pub trait Trait: frame_system::Trait {
+    /// The overarching event type
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// The balance of an account for any asset
+    type Balance: Parameter
+        + Member
+        + AtLeast32BitUnsigned
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// Identifier for assets
+    type CurrencyId: Parameter
+        + Member
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + Ord;
+
+    /// The native token, balances for `GetNativeCurrencyId` are kept here
+    type NativeCurrency: Currency<Self::AccountId, Balance = Self::Balance>;
+
+    /// The asset identifier of the native token
+    type GetNativeCurrencyId: Get<Self::CurrencyId>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+decl_event!(
+    pub enum Event<T>
+    where
+        <T as frame_system::Trait>::AccountId,
+        <T as Trait>::CurrencyId,
+        <T as Trait>::Balance,
+    {
+        /// Asset, Source, Destination, Amount
+        Transferred(CurrencyId, AccountId, AccountId, Balance),
+        /// Asset, Destination, Amount
+        Minted(CurrencyId, AccountId, Balance),
+    }
+);
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        BalanceTooLow,
+        BalanceOverflow,
+        TotalIssuanceOverflow,
+        DepositBelowExistentialDeposit,
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as Tokens {
+        /// Total issuance of every non-native asset
+        pub TotalIssuance get(fn total_issuance): map
+            hasher(blake2_128_concat) T::CurrencyId => T::Balance;
+
+        /// Balances of every non-native asset, removed once they reach zero
+        pub Accounts get(fn accounts): double_map
+            hasher(blake2_128_concat) T::CurrencyId,
+            hasher(blake2_128_concat) T::AccountId => T::Balance;
+    }
+    add_extra_genesis {
+        config(endowed_accounts): Vec<(T::AccountId, T::CurrencyId, T::Balance)>;
+
+        build(|config: &GenesisConfig<T>| {
+            config.endowed_accounts.iter().for_each(|(who, currency_id, amount)| {
+                <Module<T> as MultiCurrency<T::AccountId>>::deposit(*currency_id, who, *amount)
+                    .expect("endowed accounts set up failed");
+            });
+        })
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+        fn deposit_event() = default;
+
+        #[weight = T::WeightInfo::transfer()]
+        fn transfer(
+            origin,
+            dest: T::AccountId,
+            currency_id: T::CurrencyId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            <Self as MultiCurrency<T::AccountId>>::transfer(
+                currency_id,
+                &from,
+                &dest,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            Self::deposit_event(RawEvent::Transferred(currency_id, from, dest, amount));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::mint()]
+        fn mint(
+            origin,
+            dest: T::AccountId,
+            currency_id: T::CurrencyId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            <Self as MultiCurrency<T::AccountId>>::deposit(currency_id, &dest, amount)?;
+            Self::deposit_event(RawEvent::Minted(currency_id, dest, amount));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    fn is_native(currency_id: T::CurrencyId) -> bool {
+        currency_id == T::GetNativeCurrencyId::get()
+    }
+    /// Stores a non-native balance, removing the entry once it reaches zero
+    fn set_balance(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        balance: T::Balance,
+    ) {
+        if balance.is_zero() {
+            <Accounts<T>>::remove(currency_id, who);
+        } else {
+            <Accounts<T>>::insert(currency_id, who, balance);
+        }
+    }
+}
+
+impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
+    type CurrencyId = T::CurrencyId;
+    type Balance = T::Balance;
+    fn native_currency_id() -> T::CurrencyId {
+        T::GetNativeCurrencyId::get()
+    }
+    /// Non-native assets have no existential deposit
+    fn minimum_balance(currency_id: T::CurrencyId) -> T::Balance {
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::minimum_balance()
+        } else {
+            Zero::zero()
+        }
+    }
+    fn total_issuance(currency_id: T::CurrencyId) -> T::Balance {
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::total_issuance()
+        } else {
+            <TotalIssuance<T>>::get(currency_id)
+        }
+    }
+    fn total_balance(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+    ) -> T::Balance {
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::total_balance(who)
+        } else {
+            <Accounts<T>>::get(currency_id, who)
+        }
+    }
+    fn free_balance(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+    ) -> T::Balance {
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::free_balance(who)
+        } else {
+            <Accounts<T>>::get(currency_id, who)
+        }
+    }
+    fn transfer(
+        currency_id: T::CurrencyId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: T::Balance,
+        existence_requirement: ExistenceRequirement,
+    ) -> DispatchResult {
+        if Self::is_native(currency_id) {
+            return T::NativeCurrency::transfer(
+                from,
+                to,
+                amount,
+                existence_requirement,
+            )
+        }
+        if amount.is_zero() || from == to {
+            return Ok(())
+        }
+        let new_from = <Accounts<T>>::get(currency_id, from)
+            .checked_sub(&amount)
+            .ok_or(Error::<T>::BalanceTooLow)?;
+        let new_to = <Accounts<T>>::get(currency_id, to)
+            .checked_add(&amount)
+            .ok_or(Error::<T>::BalanceOverflow)?;
+        Self::set_balance(currency_id, from, new_from);
+        <Accounts<T>>::insert(currency_id, to, new_to);
+        Ok(())
+    }
+    fn deposit(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        if Self::is_native(currency_id) {
+            // nothing is credited if the deposit cannot create the account
+            let credited = T::NativeCurrency::deposit_creating(who, amount);
+            ensure!(
+                credited.peek() == amount,
+                Error::<T>::DepositBelowExistentialDeposit
+            );
+            return Ok(())
+        }
+        let new_issuance = <TotalIssuance<T>>::get(currency_id)
+            .checked_add(&amount)
+            .ok_or(Error::<T>::TotalIssuanceOverflow)?;
+        // cannot overflow if total issuance does not
+        <Accounts<T>>::mutate(currency_id, who, |b| *b += amount);
+        <TotalIssuance<T>>::insert(currency_id, new_issuance);
+        Ok(())
+    }
+    fn withdraw(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        if Self::is_native(currency_id) {
+            let _ = T::NativeCurrency::withdraw(
+                who,
+                amount,
+                WithdrawReasons::all(),
+                ExistenceRequirement::AllowDeath,
+            )?;
+            return Ok(())
+        }
+        let new_balance = <Accounts<T>>::get(currency_id, who)
+            .checked_sub(&amount)
+            .ok_or(Error::<T>::BalanceTooLow)?;
+        ensure!(
+            <TotalIssuance<T>>::get(currency_id) >= amount,
+            Error::<T>::BalanceTooLow
+        );
+        Self::set_balance(currency_id, who, new_balance);
+        <TotalIssuance<T>>::mutate(currency_id, |i| *i -= amount);
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use super::*;
+use frame_support::{
+    assert_noop,
+    assert_ok,
+    impl_outer_event,
+    impl_outer_origin,
+    parameter_types,
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::IdentityLookup,
+    Perbill,
+};
+
+// type aliases
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+mod tokens {
+    pub use super::super::*;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        system<T>,
+        pallet_balances<T>,
+        tokens<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+    type Origin = Origin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Call = ();
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type MaximumBlockLength = MaximumBlockLength;
+    type Version = ();
+    type ModuleToIndex = ();
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type BaseCallFilter = ();
+    type SystemWeightInfo = ();
+}
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 5;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const NativeCurrencyId: u32 = 0;
+}
+impl Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type CurrencyId = u32;
+    type NativeCurrency = Balances;
+    type GetNativeCurrencyId = NativeCurrencyId;
+    type WeightInfo = ();
+}
+pub type System = frame_system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = Module<Test>;
+
+const NATIVE: u32 = 0;
+const USD: u32 = 1;
+
+fn get_last_event() -> RawEvent<u64, u32, u64> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let TestEvent::tokens(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .last()
+        .unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    GenesisConfig::<Test> {
+        endowed_accounts: vec![(1, USD, 100), (2, USD, 50)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn genesis_config_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Tokens::free_balance(USD, &1), 100);
+        assert_eq!(Tokens::free_balance(USD, &2), 50);
+        assert_eq!(Tokens::total_issuance(USD), 150);
+        // native balances are read from the balances pallet
+        assert_eq!(Tokens::free_balance(NATIVE, &1), 100);
+        assert_eq!(<Tokens as MultiCurrency<u64>>::total_issuance(NATIVE), 200);
+    });
+}
+
+#[test]
+fn transfer_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Tokens::transfer(Origin::signed(2), 3, USD, 51),
+            Error::<Test>::BalanceTooLow
+        );
+        assert_ok!(Tokens::transfer(Origin::signed(1), 3, USD, 40));
+        assert_eq!(get_last_event(), RawEvent::Transferred(USD, 1, 3, 40));
+        assert_eq!(Tokens::free_balance(USD, &1), 60);
+        assert_eq!(Tokens::free_balance(USD, &3), 40);
+        assert_eq!(Tokens::total_issuance(USD), 150);
+        assert_ok!(Tokens::transfer(Origin::signed(1), 3, NATIVE, 40));
+        assert_eq!(Balances::free_balance(&1), 60);
+        assert_eq!(Balances::free_balance(&3), 40);
+        // emptied balances are removed from storage
+        assert_ok!(Tokens::transfer(Origin::signed(2), 3, USD, 50));
+        assert!(!Accounts::<Test>::contains_key(USD, 2));
+        assert_eq!(Tokens::free_balance(USD, &3), 90);
+    });
+}
+
+#[test]
+fn mint_and_withdraw_update_issuance() {
+    new_test_ext().execute_with(|| {
+        assert!(Tokens::mint(Origin::signed(1), 1, USD, 10).is_err());
+        assert_ok!(Tokens::mint(Origin::root(), 3, USD, 10));
+        assert_eq!(get_last_event(), RawEvent::Minted(USD, 3, 10));
+        assert_eq!(Tokens::total_issuance(USD), 160);
+        assert_noop!(
+            <Tokens as MultiCurrency<u64>>::withdraw(USD, &3, 11),
+            Error::<Test>::BalanceTooLow
+        );
+        assert_ok!(<Tokens as MultiCurrency<u64>>::withdraw(USD, &3, 10));
+        assert_eq!(Tokens::free_balance(USD, &3), 0);
+        assert!(!Accounts::<Test>::contains_key(USD, 3));
+        assert_eq!(Tokens::total_issuance(USD), 150);
+        // native mints below the existential deposit fail instead of being dropped
+        assert_noop!(
+            Tokens::mint(Origin::root(), 3, NATIVE, 4),
+            Error::<Test>::DepositBelowExistentialDeposit
+        );
+        assert_eq!(<Tokens as MultiCurrency<u64>>::total_issuance(NATIVE), 200);
+        assert_ok!(Tokens::mint(Origin::root(), 1, NATIVE, 4));
+        assert_eq!(Balances::free_balance(&1), 104);
+        assert_eq!(get_last_event(), RawEvent::Minted(NATIVE, 1, 4));
+    });
+}
@@ -0,0 +1,62 @@
+//! Vote-direct pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+const MAX_VOTERS: u32 = 100;
+
+/// An electorate of `m` voters with equal signal, `first` included
+fn electorate<T: Trait>(
+    first: T::AccountId,
+    m: u32,
+) -> WeightedVector<T::AccountId, T::Signal> {
+    let mut voters = vec![(first, 1u32.into())];
+    voters.extend((1..m).map(|i| (account("voter", i, SEED), 1u32.into())));
+    voters.into()
+}
+
+benchmarks! {
+    _ { }
+
+    create_signal_vote {
+        let m in 1 .. MAX_VOTERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let src = electorate::<T>(caller.clone(), m);
+    }: _(RawOrigin::Signed(caller), None, src, Threshold::new(m.into(), None), None)
+    verify {
+        assert_eq!(Module::<T>::open_vote_counter(), 1);
+    }
+
+    create_percent_vote {
+        let m in 1 .. MAX_VOTERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let src = electorate::<T>(caller.clone(), m);
+    }: _(RawOrigin::Signed(caller), None, src, Threshold::new(Permill::one(), None), None)
+    verify {
+        assert_eq!(Module::<T>::open_vote_counter(), 1);
+    }
+
+    submit_vote {
+        let caller: T::AccountId = whitelisted_caller();
+        let vote_id = Module::<T>::open_vote(
+            None,
+            electorate::<T>(caller.clone(), 2),
+            Threshold::new(2u32.into(), None),
+            None,
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), vote_id, VoterView::InFavor, None)
+    verify {
+        assert_eq!(
+            Module::<T>::vote_logger(vote_id, &caller).unwrap().direction(),
+            VoterView::InFavor
+        );
+    }
+}
@@ -6,6 +6,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Voting from sets of weighted accounts
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -16,6 +19,7 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -70,6 +74,12 @@ type VoteSt<T> = VoteState<
 >;
 type VoteVec<T> = Vote<<T as Trait>::Signal, <T as Trait>::Cid>;
 
+pub trait WeightInfo {
+    fn create_signal_vote(m: u32) -> Weight;
+    fn create_percent_vote(m: u32) -> Weight;
+    fn submit_vote() -> Weight;
+}
+
 pub trait Trait: frame_system::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -102,6 +112,9 @@ pub trait Trait: frame_system::Trait {
         + PartialOrd
         + CheckedSub
         + Zero;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -130,7 +143,7 @@ decl_error! {
 }
 
 decl_storage! {
-    trait Store for Module<T: Trait> as Vote {
+    trait Store for Module<T: Trait> as VoteDirect {
         /// The nonce for unique vote id generation
         VoteIdCounter get(fn vote_id_counter): T::VoteId;
 
@@ -157,7 +170,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::create_signal_vote(src.vec().len() as u32)]
         pub fn create_signal_vote(
             origin,
             topic: Option<T::Cid>,
@@ -177,7 +190,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::create_percent_vote(src.vec().len() as u32)]
         pub fn create_percent_vote(
             origin,
             topic: Option<T::Cid>,
@@ -197,7 +210,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::submit_vote()]
         pub fn submit_vote(
             origin,
             vote_id: T::VoteId,
@@ -0,0 +1,286 @@
+//! Vote pallet benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+    whitelisted_caller,
+};
+use frame_support::traits::OnFinalize;
+use frame_system::RawOrigin;
+use sp_std::vec;
+use util::{
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+};
+
+const SEED: u32 = 0;
+const MAX_MEMBERS: u32 = 100;
+const MAX_PER_BLOCK: u32 = 100;
+
+/// Registers an org with `caller` as supervisor and the first member
+fn org_with_members<T: Trait>(caller: T::AccountId, m: u32) -> T::OrgId {
+    let mut members = vec![caller.clone()];
+    members.extend((1..m).map(|i| account("member", i, SEED)));
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        Some(caller),
+        T::Cid::default(),
+    )
+    .expect("org registration with supervisor works")
+}
+
+/// Makes `d` other members of the org delegate to `delegate`
+fn delegate_to<T: Trait>(
+    org: T::OrgId,
+    delegate: &T::AccountId,
+    d: u32,
+) -> Result<(), &'static str> {
+    for i in 1..=d {
+        let delegator: T::AccountId = account("member", i, SEED);
+        Module::<T>::delegate(
+            RawOrigin::Signed(delegator).into(),
+            org,
+            delegate.clone(),
+        )?;
+    }
+    Ok(())
+}
+
+benchmarks! {
+    _ { }
+
+    create_signal_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), m);
+        let count = Module::<T>::open_vote_counter();
+    }: _(RawOrigin::Signed(caller), None, OrgRep::Equal(org), Threshold::new(m.into(), None), None)
+    verify {
+        assert_eq!(Module::<T>::open_vote_counter(), count + 1);
+    }
+
+    create_percent_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), m);
+        let count = Module::<T>::open_vote_counter();
+    }: _(RawOrigin::Signed(caller), None, OrgRep::Weighted(org), Threshold::new(Permill::one(), None), None)
+    verify {
+        assert_eq!(Module::<T>::open_vote_counter(), count + 1);
+    }
+
+    create_conviction_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), m);
+        let count = Module::<T>::open_vote_counter();
+    }: _(RawOrigin::Signed(caller), None, OrgRep::Weighted(org), Threshold::new(Permill::one(), None), 10u32.into())
+    verify {
+        assert_eq!(Module::<T>::open_vote_counter(), count + 1);
+    }
+
+    set_threshold_default {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), 1);
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(org),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+    }: _(RawOrigin::Signed(caller), threshold)
+
+    submit_vote {
+        let d in 0 .. T::MaxDelegators::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), d + 2);
+        delegate_to::<T>(org, &caller, d)?;
+        let vote_id = Module::<T>::open_vote(
+            None,
+            OrgRep::Equal(org),
+            Threshold::new((d + 2).into(), None),
+            None,
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), vote_id, VoterView::InFavor, None)
+    verify {
+        assert_eq!(
+            Module::<T>::vote_logger(vote_id, &caller).unwrap().direction(),
+            VoterView::InFavor
+        );
+    }
+
+    submit_conviction_vote {
+        let d in 0 .. T::MaxDelegators::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), d + 2);
+        delegate_to::<T>(org, &caller, d)?;
+        let vote_id = Module::<T>::open_conviction_vote(
+            None,
+            OrgRep::Equal(org),
+            Threshold::new(Permill::one(), None),
+            10u32.into(),
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), vote_id, VoterView::InFavor, Conviction::Locked6x, None)
+    verify {
+        assert!(Module::<T>::conviction_locks(org, &caller).is_some());
+    }
+
+    delegate {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), 2);
+        let delegate: T::AccountId = account("member", 1, SEED);
+    }: _(RawOrigin::Signed(caller.clone()), org, delegate.clone())
+    verify {
+        assert_eq!(Module::<T>::org_delegates(org, &caller), Some(delegate));
+    }
+
+    undelegate {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), 2);
+        let delegate: T::AccountId = account("member", 1, SEED);
+        Module::<T>::delegate(RawOrigin::Signed(caller.clone()).into(), org, delegate)?;
+    }: _(RawOrigin::Signed(caller.clone()), org)
+    verify {
+        assert!(Module::<T>::org_delegates(org, &caller).is_none());
+    }
+
+    delegate_vote {
+        let d in 0 .. T::MaxDelegators::get();
+        // the caller passes the delegate's vote on to their own `d` delegators
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), d + 2);
+        let delegate: T::AccountId = account("member", d + 1, SEED);
+        delegate_to::<T>(org, &caller, d)?;
+        let vote_id = Module::<T>::open_vote(
+            None,
+            OrgRep::Equal(org),
+            Threshold::new((d + 2).into(), None),
+            None,
+        )?;
+        Module::<T>::submit_vote(RawOrigin::Signed(delegate.clone()).into(), vote_id, VoterView::InFavor, None)?;
+    }: _(RawOrigin::Signed(caller.clone()), vote_id, delegate.clone())
+    verify {
+        assert_eq!(Module::<T>::delegated_votes(vote_id, &caller), Some(delegate));
+    }
+
+    undelegate_vote {
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), 2);
+        let delegate: T::AccountId = account("member", 1, SEED);
+        let vote_id = Module::<T>::open_vote(
+            None,
+            OrgRep::Equal(org),
+            Threshold::new(2u32.into(), None),
+            None,
+        )?;
+        Module::<T>::delegate_vote(RawOrigin::Signed(caller.clone()).into(), vote_id, delegate)?;
+    }: _(RawOrigin::Signed(caller.clone()), vote_id)
+    verify {
+        assert!(Module::<T>::vote_delegates(vote_id, &caller).is_none());
+    }
+
+    create_multi_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let o in 2 .. T::MaxOptions::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), m);
+        let options = vec![T::Cid::default(); o as usize];
+        let count = Module::<T>::open_vote_counter();
+    }: _(RawOrigin::Signed(caller), options, OrgRep::Equal(org), TallyMethod::RankedChoice, 10u32.into())
+    verify {
+        assert_eq!(Module::<T>::open_vote_counter(), count + 1);
+    }
+
+    submit_ballot {
+        let c in 1 .. T::MaxOptions::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), 2);
+        let vote_id = Module::<T>::open_multi_vote(
+            vec![T::Cid::default(); T::MaxOptions::get() as usize],
+            OrgRep::Equal(org),
+            TallyMethod::RankedChoice,
+            10u32.into(),
+        )?;
+        let choices = (0..c).rev().collect::<Vec<u32>>();
+    }: _(RawOrigin::Signed(caller.clone()), vote_id, choices.clone())
+    verify {
+        assert_eq!(Module::<T>::ballots(vote_id, &caller), Some(choices));
+    }
+
+    close_votes {
+        let v in 1 .. MAX_PER_BLOCK;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), 2);
+        let ends = <frame_system::Module<T>>::block_number() + 10u32.into();
+        for _ in 0..v {
+            let vote_id = Module::<T>::open_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(2u32.into(), None),
+                Some(10u32.into()),
+            )?;
+            Module::<T>::submit_vote(RawOrigin::Signed(caller.clone()).into(), vote_id, VoterView::InFavor, None)?;
+        }
+    }: { Module::<T>::on_finalize(ends) }
+    verify {
+        assert_eq!(Module::<T>::open_vote_counter(), 0);
+    }
+
+    close_multi_vote {
+        let b in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), b);
+        let ends = <frame_system::Module<T>>::block_number() + 10u32.into();
+        let vote_id = Module::<T>::open_multi_vote(
+            vec![T::Cid::default(), T::Cid::default()],
+            OrgRep::Equal(org),
+            TallyMethod::RankedChoice,
+            10u32.into(),
+        )?;
+        Module::<T>::submit_ballot(RawOrigin::Signed(caller).into(), vote_id, vec![1, 0])?;
+        for i in 1..b {
+            let voter: T::AccountId = account("member", i, SEED);
+            Module::<T>::submit_ballot(RawOrigin::Signed(voter).into(), vote_id, vec![0, 1])?;
+        }
+    }: { Module::<T>::on_finalize(ends) }
+    verify {
+        assert_eq!(Module::<T>::open_vote_counter(), 0);
+    }
+
+    prune_votes {
+        let v in 1 .. MAX_PER_BLOCK;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), 2);
+        let now = <frame_system::Module<T>>::block_number();
+        let mut pruned = Vec::new();
+        for _ in 0..v {
+            let vote_id = Module::<T>::open_vote(
+                None,
+                OrgRep::Equal(org),
+                Threshold::new(2u32.into(), None),
+                None,
+            )?;
+            Module::<T>::submit_vote(RawOrigin::Signed(caller.clone()).into(), vote_id, VoterView::InFavor, None)?;
+            pruned.push(vote_id);
+        }
+        <VotePrunings<T>>::insert(now, pruned);
+    }: { Module::<T>::on_finalize(now) }
+    verify {
+        assert!(Module::<T>::vote_prunings(now).is_empty());
+    }
+
+    expire_locks {
+        let l in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = whitelisted_caller();
+        let org = org_with_members::<T>(caller.clone(), l);
+        let now = <frame_system::Module<T>>::block_number();
+        Module::<T>::lock_for_conviction(org, &caller, now)?;
+        for i in 1..l {
+            let member: T::AccountId = account("member", i, SEED);
+            Module::<T>::lock_for_conviction(org, &member, now)?;
+        }
+    }: { Module::<T>::on_finalize(now) }
+    verify {
+        assert!(Module::<T>::conviction_locks(org, &caller).is_none());
+    }
+}
@@ -0,0 +1,247 @@
+//! Default weights for the vote pallet, to be regenerated from `benchmarking.rs`
+//! with the `benchmark` subcommand of a node built with `runtime-benchmarks`
+//!
+//! The runtime charges database access at its own `DbWeight` with `SubstrateWeight`,
+//! `()` charges `RocksDbWeight` for the mocks
+
+use frame_support::{
+    traits::Get,
+    weights::{
+        constants::RocksDbWeight as DbWeight,
+        Weight,
+    },
+};
+use sp_std::marker::PhantomData;
+
+impl crate::WeightInfo for () {
+    fn create_signal_vote(m: u32) -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn create_percent_vote(m: u32) -> Weight {
+        (57_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn create_conviction_vote(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn set_threshold_default() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn submit_vote(d: u32) -> Weight {
+        (48_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads(5 * d as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(DbWeight::get().writes(3 * d as Weight))
+    }
+    fn submit_conviction_vote(d: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads(5 * d as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add(DbWeight::get().writes(3 * d as Weight))
+    }
+    fn delegate() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn undelegate() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn delegate_vote(d: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().reads(5 * d as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+            .saturating_add(DbWeight::get().writes(3 * d as Weight))
+    }
+    fn undelegate_vote() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn create_multi_vote(m: u32, o: u32) -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add((1_000_000 as Weight).saturating_mul(o as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads(m as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(DbWeight::get().writes(m as Weight))
+    }
+    fn submit_ballot(c: u32) -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add((1_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn close_votes(v: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((30_000_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads(v as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes(3 * v as Weight))
+    }
+    fn close_multi_vote(b: u32) -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add((12_000_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().reads(2 * b as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn prune_votes(v: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((35_000_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes(7 * v as Weight))
+    }
+    fn expire_locks(l: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((20_000_000 as Weight).saturating_mul(l as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads(2 * l as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes(2 * l as Weight))
+    }
+}
+
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> crate::WeightInfo for SubstrateWeight<T> {
+    fn create_signal_vote(m: u32) -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn create_percent_vote(m: u32) -> Weight {
+        (57_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn create_conviction_vote(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn set_threshold_default() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn submit_vote(d: u32) -> Weight {
+        (48_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 * d as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 * d as Weight))
+    }
+    fn submit_conviction_vote(d: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 * d as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 * d as Weight))
+    }
+    fn delegate() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn undelegate() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn delegate_vote(d: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(10 as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 * d as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 * d as Weight))
+    }
+    fn undelegate_vote() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn create_multi_vote(m: u32, o: u32) -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add((1_000_000 as Weight).saturating_mul(o as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads(m as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(m as Weight))
+    }
+    fn submit_ballot(c: u32) -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add((1_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn close_votes(v: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((30_000_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(v as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 * v as Weight))
+    }
+    fn close_multi_vote(b: u32) -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add((12_000_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 * b as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn prune_votes(v: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((35_000_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 * v as Weight))
+    }
+    fn expire_locks(l: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((20_000_000 as Weight).saturating_mul(l as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 * l as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 * l as Weight))
+    }
+}
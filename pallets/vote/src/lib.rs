@@ -7,6 +7,9 @@
 //! Voting module for collecting signatures from organizations for simple and weighted
 //! thresholds for on-chain decision making.
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod default_weights;
 #[cfg(test)]
 mod tests;
 
@@ -17,6 +20,8 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
+    traits::Get,
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -27,8 +32,10 @@ use sp_runtime::{
     traits::{
         AtLeast32BitUnsigned,
         CheckedSub,
+        IntegerSquareRoot,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
@@ -47,9 +54,11 @@ use util::{
         ApplyVote,
         CheckVoteStatus,
         ConfigureThreshold,
+        EnsureOrgOrigin,
         GenerateUniqueID,
         GetGroup,
         GetVoteOutcome,
+        GroupMembership,
         IDIsAvailable,
         MintableSignal,
         OpenVote,
@@ -60,6 +69,9 @@ use util::{
         VoteVector,
     },
     vote::{
+        Conviction,
+        MultiVoteState,
+        TallyMethod,
         Threshold,
         ThresholdConfig,
         ThresholdInput,
@@ -86,6 +98,30 @@ type VoteSt<T> = VoteState<
     <T as org::Trait>::Cid,
 >;
 type VoteVec<T> = Vote<<T as Trait>::Signal, <T as org::Trait>::Cid>;
+type MultiVoteSt<T> = MultiVoteState<
+    <T as Trait>::Signal,
+    <T as frame_system::Trait>::BlockNumber,
+    <T as org::Trait>::Cid,
+>;
+
+pub trait WeightInfo {
+    fn create_signal_vote(m: u32) -> Weight;
+    fn create_percent_vote(m: u32) -> Weight;
+    fn create_conviction_vote(m: u32) -> Weight;
+    fn set_threshold_default() -> Weight;
+    fn submit_vote(d: u32) -> Weight;
+    fn submit_conviction_vote(d: u32) -> Weight;
+    fn delegate() -> Weight;
+    fn undelegate() -> Weight;
+    fn delegate_vote(d: u32) -> Weight;
+    fn undelegate_vote() -> Weight;
+    fn create_multi_vote(m: u32, o: u32) -> Weight;
+    fn submit_ballot(c: u32) -> Weight;
+    fn close_votes(v: u32) -> Weight;
+    fn close_multi_vote(b: u32) -> Weight;
+    fn prune_votes(v: u32) -> Weight;
+    fn expire_locks(l: u32) -> Weight;
+}
 
 pub trait Trait: frame_system::Trait + org::Trait {
     /// The overarching event type
@@ -130,6 +166,24 @@ pub trait Trait: frame_system::Trait + org::Trait {
         + PartialOrd
         + PartialEq
         + Zero;
+
+    /// Blocks that shares stay locked per conviction lock period after the vote ends
+    type ConvictionLockPeriod: Get<Self::BlockNumber>;
+
+    /// Maximum length of a delegation chain, delegated signal is not carried further
+    type MaxDelegationDepth: Get<u32>;
+
+    /// Maximum number of accounts delegating to one delegate, for the org and for each vote
+    type MaxDelegators: Get<u32>;
+
+    /// Maximum number of options in a multi-option vote, which also bounds the choices on a ballot
+    type MaxOptions: Get<u32>;
+
+    /// Blocks that individual votes are kept after a vote closes, `None` keeps them forever
+    type VoteRetentionPeriod: Get<Option<Self::BlockNumber>>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -138,10 +192,27 @@ decl_event!(
         <T as frame_system::Trait>::AccountId,
         <T as Trait>::VoteId,
         <T as Trait>::ThresholdId,
+        <T as org::Trait>::OrgId,
     {
         ThresholdSet(ThresholdId),
         NewVoteStarted(AccountId, VoteId),
         Voted(VoteId, AccountId, VoterView),
+        /// Vote Identifier, Voter, Direction, Conviction
+        ConvictionVoted(VoteId, AccountId, VoterView, Conviction),
+        /// Organization Identifier, Voter whose shares were unlocked
+        ConvictionLockExpired(OrgId, AccountId),
+        /// Organization Identifier, Delegator, Delegate
+        SignalDelegated(OrgId, AccountId, AccountId),
+        /// Organization Identifier, Delegator
+        SignalUndelegated(OrgId, AccountId),
+        /// Vote Identifier, Delegator, Delegate
+        VoteDelegated(VoteId, AccountId, AccountId),
+        /// Vote Identifier, Delegator
+        VoteUndelegated(VoteId, AccountId),
+        /// Vote Identifier, Voter
+        BallotSubmitted(VoteId, AccountId),
+        /// Vote Identifier, Final Outcome
+        VoteClosed(VoteId, VoteOutcome),
     }
 );
 
@@ -161,6 +232,23 @@ decl_error! {
         InputThresholdExceedsBounds,
         OnlySupervisorCanSetGenericThresholds,
         CannotInvokeThresholdThatDNE,
+        ConvictionOnlyAppliesToFirstVote,
+        VoteDoesNotAcceptConviction,
+        CannotLockSharesForVoteWithoutOrg,
+        CannotDelegateToSelf,
+        MustBeOrgMemberToDelegate,
+        DelegateMustBeOrgMember,
+        DelegateHasTooManyDelegators,
+        CannotDelegateForVoteWithoutOrg,
+        DelegationChainExceedsMaxDepth,
+        DelegationWouldFormCycle,
+        NoDelegationToRemove,
+        MultiVoteRequiresAtLeastTwoOptions,
+        NoMultiVoteStateForBallot,
+        BallotInvalidForTallyMethod,
+        MultiVoteOnlyAcceptsBallots,
+        MultiVoteExceedsMaxOptions,
+        BallotExceedsMaxOptions,
     }
 }
 
@@ -191,6 +279,66 @@ decl_storage! {
         pub VoteLogger get(fn vote_logger): double_map
             hasher(blake2_128_concat) T::VoteId,
             hasher(blake2_128_concat) T::AccountId  => Option<VoteVec<T>>;
+
+        /// The organization whose members were minted signal for the vote
+        pub VoteOrg get(fn vote_org): map
+            hasher(blake2_128_concat) T::VoteId => Option<T::OrgId>;
+
+        /// Votes opened for conviction voting, their turnout and thresholds
+        /// are scaled by the maximum conviction multiplier
+        pub ConvictionVotes get(fn conviction_votes): map
+            hasher(blake2_128_concat) T::VoteId => bool;
+
+        /// The block at which the member's shares are unlocked after conviction voting
+        pub ConvictionLocks get(fn conviction_locks): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
+
+        /// Conviction locks scheduled to expire at each block
+        LockExpiries get(fn lock_expiries): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<(T::OrgId, T::AccountId)>;
+
+        /// The standing delegate of each member for all votes of the org
+        pub OrgDelegates get(fn org_delegates): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::AccountId>;
+
+        /// The members delegating to each delegate for all votes of the org, at most `MaxDelegators`
+        pub OrgDelegators get(fn org_delegators): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+
+        /// The delegate of each voter for a single vote, overrides the org delegate
+        pub VoteDelegates get(fn vote_delegates): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::AccountId>;
+
+        /// The voters delegating to each delegate for a single vote, at most `MaxDelegators`
+        pub VoteDelegators get(fn vote_delegators): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+
+        /// The delegate that cast each voter's signal, cleared when the voter votes directly
+        pub DelegatedVotes get(fn delegated_votes): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::AccountId>;
+
+        /// The state of a vote between several options
+        pub MultiVoteStates get(fn multi_vote_states): map
+            hasher(blake2_128_concat) T::VoteId => Option<MultiVoteSt<T>>;
+
+        /// The option indices backed by each voter, in order of preference for ranked choice
+        pub Ballots get(fn ballots): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId => Option<Vec<u32>>;
+
+        /// Votes scheduled to close at the end of each block
+        VoteEndings get(fn vote_endings): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::VoteId>;
+
+        /// Closed votes whose individual votes are pruned at each block
+        VotePrunings get(fn vote_prunings): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::VoteId>;
     }
 }
 
@@ -199,7 +347,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_signal_vote(<org::Module<T>>::member_count(organization.org()))]
         pub fn create_signal_vote(
             origin,
             topic: Option<T::Cid>,
@@ -222,7 +370,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_percent_vote(<org::Module<T>>::member_count(organization.org()))]
         pub fn create_percent_vote(
             origin,
             topic: Option<T::Cid>,
@@ -245,21 +393,39 @@ decl_module! {
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_conviction_vote(<org::Module<T>>::member_count(organization.org()))]
+        pub fn create_conviction_vote(
+            origin,
+            topic: Option<T::Cid>,
+            organization: OrgRep<T::OrgId>,
+            threshold: Threshold<Permill>,
+            duration: T::BlockNumber,
+        ) -> DispatchResult {
+            let vote_creator = ensure_signed(origin)?;
+            // default authentication is organization supervisor
+            let authentication: bool = <org::Module<T>>::is_organization_supervisor(organization.org(), &vote_creator);
+            ensure!(authentication, Error::<T>::NotAuthorizedToCreateVoteForOrganization);
+            let new_vote_id = Self::open_conviction_vote(
+                topic,
+                organization,
+                threshold,
+                duration,
+            )?;
+            Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, new_vote_id));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::set_threshold_default()]
         fn set_threshold_default(
             origin,
             threshold: ThreshInput<T>,
         ) -> DispatchResult {
-            let setter = ensure_signed(origin)?;
-            ensure!(
-                <org::Module<T>>::is_organization_supervisor(threshold.org().org(), &setter),
-                Error::<T>::OnlySupervisorCanSetGenericThresholds
-            );
+            org::EnsureOrgSupervisor::<T>::try_org_origin(origin, threshold.org().org())
+                .map_err(|_| Error::<T>::OnlySupervisorCanSetGenericThresholds)?;
             let id = Self::register_threshold(threshold)?;
             Self::deposit_event(RawEvent::ThresholdSet(id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_vote(Self::max_delegated_votes())]
         pub fn submit_vote(
             origin,
             vote_id: T::VoteId,
@@ -271,6 +437,205 @@ decl_module! {
             Self::deposit_event(RawEvent::Voted(vote_id, voter, direction));
             Ok(())
         }
+        #[weight = <T as Trait>::WeightInfo::submit_conviction_vote(Self::max_delegated_votes())]
+        pub fn submit_conviction_vote(
+            origin,
+            vote_id: T::VoteId,
+            direction: VoterView,
+            conviction: Conviction,
+            justification: Option<T::Cid>,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            Self::vote_with_conviction(vote_id, voter.clone(), direction, conviction, justification)?;
+            Self::deposit_event(RawEvent::ConvictionVoted(vote_id, voter, direction, conviction));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::delegate()]
+        fn delegate(
+            origin,
+            org: T::OrgId,
+            delegate: T::AccountId,
+        ) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            ensure!(delegator != delegate, Error::<T>::CannotDelegateToSelf);
+            ensure!(
+                <org::Module<T>>::is_member_of_group(org, &delegator),
+                Error::<T>::MustBeOrgMemberToDelegate
+            );
+            ensure!(
+                <org::Module<T>>::is_member_of_group(org, &delegate),
+                Error::<T>::DelegateMustBeOrgMember
+            );
+            Self::check_delegator_count(&<OrgDelegators<T>>::get(org, &delegate), &delegator)?;
+            Self::check_delegation_chain(None, org, &delegator, &delegate)?;
+            if let Some(old) = <OrgDelegates<T>>::get(org, &delegator) {
+                <OrgDelegators<T>>::mutate(org, &old, |d| d.retain(|a| a != &delegator));
+            }
+            <OrgDelegates<T>>::insert(org, &delegator, &delegate);
+            <OrgDelegators<T>>::mutate(org, &delegate, |d| d.push(delegator.clone()));
+            Self::deposit_event(RawEvent::SignalDelegated(org, delegator, delegate));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::undelegate()]
+        fn undelegate(origin, org: T::OrgId) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            let old = <OrgDelegates<T>>::take(org, &delegator)
+                .ok_or(Error::<T>::NoDelegationToRemove)?;
+            <OrgDelegators<T>>::mutate(org, &old, |d| d.retain(|a| a != &delegator));
+            Self::deposit_event(RawEvent::SignalUndelegated(org, delegator));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::delegate_vote(Self::max_delegated_votes())]
+        fn delegate_vote(
+            origin,
+            vote_id: T::VoteId,
+            delegate: T::AccountId,
+        ) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            ensure!(delegator != delegate, Error::<T>::CannotDelegateToSelf);
+            let vote_state = Self::binary_vote_state(vote_id)?;
+            ensure!(
+                !Self::check_vote_expired(&vote_state),
+                Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
+            );
+            ensure!(
+                <VoteLogger<T>>::contains_key(vote_id, &delegator),
+                Error::<T>::SignalNotMintedForVoter
+            );
+            let org = <VoteOrg<T>>::get(vote_id)
+                .ok_or(Error::<T>::CannotDelegateForVoteWithoutOrg)?;
+            ensure!(
+                <org::Module<T>>::is_member_of_group(org, &delegate),
+                Error::<T>::DelegateMustBeOrgMember
+            );
+            Self::check_delegator_count(&<VoteDelegators<T>>::get(vote_id, &delegate), &delegator)?;
+            Self::check_delegation_chain(Some(vote_id), org, &delegator, &delegate)?;
+            if let Some(old) = <VoteDelegates<T>>::get(vote_id, &delegator) {
+                <VoteDelegators<T>>::mutate(vote_id, &old, |d| d.retain(|a| a != &delegator));
+            }
+            <VoteDelegates<T>>::insert(vote_id, &delegator, &delegate);
+            <VoteDelegators<T>>::mutate(vote_id, &delegate, |d| d.push(delegator.clone()));
+            // the delegate may have already voted
+            if let Some(delegate_vote) = <VoteLogger<T>>::get(vote_id, &delegate) {
+                if delegate_vote.direction() != VoterView::Uninitialized {
+                    Self::cast_delegated_vote(
+                        vote_id,
+                        org,
+                        &delegator,
+                        &delegate,
+                        delegate_vote.direction(),
+                        0,
+                    );
+                }
+            }
+            Self::deposit_event(RawEvent::VoteDelegated(vote_id, delegator, delegate));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::undelegate_vote()]
+        fn undelegate_vote(origin, vote_id: T::VoteId) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            let old = <VoteDelegates<T>>::take(vote_id, &delegator)
+                .ok_or(Error::<T>::NoDelegationToRemove)?;
+            <VoteDelegators<T>>::mutate(vote_id, &old, |d| d.retain(|a| a != &delegator));
+            Self::deposit_event(RawEvent::VoteUndelegated(vote_id, delegator));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::create_multi_vote(<org::Module<T>>::member_count(organization.org()), options.len() as u32)]
+        pub fn create_multi_vote(
+            origin,
+            options: Vec<T::Cid>,
+            organization: OrgRep<T::OrgId>,
+            method: TallyMethod,
+            duration: T::BlockNumber,
+        ) -> DispatchResult {
+            let vote_creator = ensure_signed(origin)?;
+            // default authentication is organization supervisor
+            let authentication: bool = <org::Module<T>>::is_organization_supervisor(organization.org(), &vote_creator);
+            ensure!(authentication, Error::<T>::NotAuthorizedToCreateVoteForOrganization);
+            let new_vote_id = Self::open_multi_vote(
+                options,
+                organization,
+                method,
+                duration,
+            )?;
+            Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, new_vote_id));
+            Ok(())
+        }
+        /// Delegation and conviction do not apply to multi-option votes,
+        /// each ballot is counted with the voter's minted signal
+        #[weight = <T as Trait>::WeightInfo::submit_ballot(choices.len() as u32)]
+        pub fn submit_ballot(
+            origin,
+            vote_id: T::VoteId,
+            choices: Vec<u32>,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            ensure!(
+                choices.len() as u32 <= T::MaxOptions::get(),
+                Error::<T>::BallotExceedsMaxOptions
+            );
+            let vote_state = <MultiVoteStates<T>>::get(vote_id)
+                .ok_or(Error::<T>::NoMultiVoteStateForBallot)?;
+            if let Some(n) = vote_state.ends() {
+                ensure!(
+                    n >= <frame_system::Module<T>>::block_number(),
+                    Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
+                );
+            }
+            let signal = <VoteLogger<T>>::get(vote_id, &voter)
+                .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+            ensure!(
+                vote_state.valid_ballot(&choices),
+                Error::<T>::BallotInvalidForTallyMethod
+            );
+            // replacing a ballot does not change turnout
+            if !<Ballots<T>>::contains_key(vote_id, &voter) {
+                <MultiVoteStates<T>>::insert(vote_id, vote_state.add_turnout(signal.magnitude()));
+            }
+            <Ballots<T>>::insert(vote_id, &voter, choices);
+            Self::deposit_event(RawEvent::BallotSubmitted(vote_id, voter));
+            Ok(())
+        }
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            // charges the work `on_finalize` does in this block
+            let mut closing = 0u32;
+            let mut weight = T::DbWeight::get().reads(3);
+            for vote_id in <VoteEndings<T>>::get(n) {
+                if <MultiVoteStates<T>>::contains_key(vote_id) {
+                    // the winner is tallied from every ballot, counting them reads the same keys
+                    let ballots = <Ballots<T>>::iter_prefix(vote_id).count() as u32;
+                    weight = weight
+                        .saturating_add(<T as Trait>::WeightInfo::close_multi_vote(ballots))
+                        .saturating_add(T::DbWeight::get().reads(ballots as Weight + 1));
+                } else {
+                    closing += 1;
+                }
+            }
+            let pruned = <VotePrunings<T>>::get(n).len() as u32;
+            let expiring = <LockExpiries<T>>::get(n).len() as u32;
+            weight
+                .saturating_add(<T as Trait>::WeightInfo::close_votes(closing))
+                .saturating_add(T::DbWeight::get().reads(closing as Weight))
+                .saturating_add(<T as Trait>::WeightInfo::prune_votes(pruned))
+                .saturating_add(<T as Trait>::WeightInfo::expire_locks(expiring))
+        }
+        fn on_finalize(n: T::BlockNumber) {
+            <VoteEndings<T>>::take(n).into_iter().for_each(|vote_id| {
+                if let Some(outcome) = Self::close_vote(vote_id, n) {
+                    Self::deposit_event(RawEvent::VoteClosed(vote_id, outcome));
+                }
+            });
+            <VotePrunings<T>>::take(n).into_iter().for_each(Self::prune_votes);
+            <LockExpiries<T>>::take(n).into_iter().for_each(|(org, who)| {
+                // the lock may have been extended by a later conviction vote
+                if <ConvictionLocks<T>>::get(org, &who) == Some(n) {
+                    <ConvictionLocks<T>>::remove(org, &who);
+                    // any lock set through the org is left in place
+                    <org::Module<T>>::remove_share_lock(org, &who);
+                    Self::deposit_event(RawEvent::ConvictionLockExpired(org, who));
+                }
+            });
+        }
     }
 }
 
@@ -300,6 +665,387 @@ impl<T: Trait> Module<T> {
         };
         Threshold::new(in_favor_t, against_t)
     }
+    fn mint_signal(
+        vote_id: T::VoteId,
+        organization: OrgRep<T::OrgId>,
+    ) -> Result<T::Signal, DispatchError> {
+        match organization {
+            OrgRep::Weighted(org_id) => {
+                Self::batch_mint_signal(vote_id, org_id)
+            }
+            OrgRep::Equal(org_id) => {
+                Self::batch_mint_equal_signal(vote_id, org_id)
+            }
+            OrgRep::Quadratic(org_id) => {
+                Self::batch_mint_quadratic_signal(vote_id, org_id)
+            }
+        }
+    }
+    /// Opens a percent vote which accepts conviction votes, the turnout and
+    /// thresholds are scaled by the maximum multiplier so amplified signal
+    /// cannot pass a threshold set for unamplified signal
+    pub fn open_conviction_vote(
+        topic: Option<T::Cid>,
+        organization: OrgRep<T::OrgId>,
+        threshold: Threshold<Permill>,
+        duration: T::BlockNumber,
+    ) -> Result<T::VoteId, DispatchError> {
+        let now = system::Module::<T>::block_number();
+        let ends = now + duration;
+        let new_vote_id = Self::generate_unique_id();
+        // votes without conviction count the minted signal once
+        let total_possible_turnout =
+            Self::mint_signal(new_vote_id, organization)?
+                .saturating_mul(Conviction::max_multiplier().into());
+        let signal_threshold =
+            Self::from_permill_to_signal(&threshold, total_possible_turnout);
+        ensure!(
+            Self::valid_signal_threshold(
+                &signal_threshold,
+                total_possible_turnout
+            ),
+            Error::<T>::InputThresholdExceedsBounds
+        );
+        let new_vote_state = VoteState::new(
+            topic,
+            total_possible_turnout,
+            signal_threshold,
+            now,
+            Some(ends),
+        );
+        <VoteStates<T>>::insert(new_vote_id, new_vote_state);
+        <VoteOrg<T>>::insert(new_vote_id, organization.org());
+        <ConvictionVotes<T>>::insert(new_vote_id, true);
+        <VoteEndings<T>>::mutate(ends, |v| v.push(new_vote_id));
+        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
+        <OpenVoteCounter>::put(new_vote_count);
+        Ok(new_vote_id)
+    }
+    /// Votes with signal amplified by the conviction multiplier and locks the
+    /// voter's shares until the conviction lock expires
+    pub fn vote_with_conviction(
+        vote_id: T::VoteId,
+        voter: T::AccountId,
+        direction: VoterView,
+        conviction: Conviction,
+        justification: Option<T::Cid>,
+    ) -> DispatchResult {
+        let vote_state = Self::binary_vote_state(vote_id)?;
+        // only conviction votes scale their thresholds for amplified signal
+        ensure!(
+            <ConvictionVotes<T>>::get(vote_id),
+            Error::<T>::VoteDoesNotAcceptConviction
+        );
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+        let old_vote = <VoteLogger<T>>::get(vote_id, &voter)
+            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        // the amplified magnitude must be applied to an empty vote
+        ensure!(
+            old_vote.direction() == VoterView::Uninitialized,
+            Error::<T>::ConvictionOnlyAppliesToFirstVote
+        );
+        ensure!(
+            direction != VoterView::Uninitialized,
+            Error::<T>::OldVoteDirectionEqualsNewVoteDirectionSoNoChange
+        );
+        let magnitude = old_vote
+            .magnitude()
+            .saturating_mul(conviction.multiplier().into());
+        let ends = vote_state.ends();
+        let new_state = Self::apply_vote(
+            vote_state,
+            magnitude,
+            VoterView::Uninitialized,
+            direction,
+        )
+        .ok_or(Error::<T>::VoteChangeNotSupported)?;
+        if conviction.lock_periods() > 0 {
+            let org = <VoteOrg<T>>::get(vote_id)
+                .ok_or(Error::<T>::CannotLockSharesForVoteWithoutOrg)?;
+            let lock_start =
+                ends.unwrap_or_else(<frame_system::Module<T>>::block_number);
+            let unlock_at = lock_start
+                + T::ConvictionLockPeriod::get()
+                    * conviction.lock_periods().into();
+            Self::lock_for_conviction(org, &voter, unlock_at)?;
+        }
+        <VoteLogger<T>>::insert(
+            vote_id,
+            &voter,
+            Vote::new(magnitude, direction, justification),
+        );
+        <VoteStates<T>>::insert(vote_id, new_state);
+        Self::cast_delegated_votes(vote_id, &voter, direction, 0);
+        Ok(())
+    }
+    /// Effective delegate of the voter, a vote delegate overrides the org delegate
+    fn delegate_of(
+        vote_id: Option<T::VoteId>,
+        org: T::OrgId,
+        who: &T::AccountId,
+    ) -> Option<T::AccountId> {
+        vote_id
+            .and_then(|v| <VoteDelegates<T>>::get(v, who))
+            .or_else(|| <OrgDelegates<T>>::get(org, who))
+    }
+    /// Accounts whose effective delegate for the vote is `delegate`
+    fn delegators_of(
+        vote_id: T::VoteId,
+        org: T::OrgId,
+        delegate: &T::AccountId,
+    ) -> Vec<T::AccountId> {
+        let mut delegators = <VoteDelegators<T>>::get(vote_id, delegate);
+        <OrgDelegators<T>>::get(org, delegate)
+            .into_iter()
+            .filter(|m| !<VoteDelegates<T>>::contains_key(vote_id, m))
+            .for_each(|m| delegators.push(m));
+        delegators
+    }
+    /// Rejects a new delegator once the delegate has `MaxDelegators`, re-delegating is allowed
+    fn check_delegator_count(
+        delegators: &[T::AccountId],
+        delegator: &T::AccountId,
+    ) -> DispatchResult {
+        let others = delegators.iter().filter(|d| d != &delegator).count();
+        ensure!(
+            others < T::MaxDelegators::get() as usize,
+            Error::<T>::DelegateHasTooManyDelegators
+        );
+        Ok(())
+    }
+    /// The most votes one vote may cast for delegators, used for weighing calls
+    /// -> each delegate casts for up to `MaxDelegators` org and vote delegators per level
+    pub fn max_delegated_votes() -> u32 {
+        let per_delegate = T::MaxDelegators::get().saturating_mul(2);
+        (0..T::MaxDelegationDepth::get())
+            .fold((0u32, 1u32), |(total, level), _| {
+                let level = level.saturating_mul(per_delegate);
+                (total.saturating_add(level), level)
+            })
+            .0
+    }
+    /// Rejects delegations that form a cycle or a chain longer than `MaxDelegationDepth`
+    fn check_delegation_chain(
+        vote_id: Option<T::VoteId>,
+        org: T::OrgId,
+        delegator: &T::AccountId,
+        delegate: &T::AccountId,
+    ) -> DispatchResult {
+        let mut next = Some(delegate.clone());
+        let mut depth = 0u32;
+        while let Some(d) = next {
+            ensure!(&d != delegator, Error::<T>::DelegationWouldFormCycle);
+            depth += 1;
+            ensure!(
+                depth <= T::MaxDelegationDepth::get(),
+                Error::<T>::DelegationChainExceedsMaxDepth
+            );
+            next = Self::delegate_of(vote_id, org, &d);
+        }
+        Ok(())
+    }
+    /// Casts the signal of everyone delegating to `delegate` in the same direction
+    fn cast_delegated_votes(
+        vote_id: T::VoteId,
+        delegate: &T::AccountId,
+        direction: VoterView,
+        depth: u32,
+    ) {
+        if let Some(org) = <VoteOrg<T>>::get(vote_id) {
+            Self::delegators_of(vote_id, org, delegate).iter().for_each(
+                |delegator| {
+                    Self::cast_delegated_vote(
+                        vote_id, org, delegator, delegate, direction, depth,
+                    )
+                },
+            );
+        }
+    }
+    /// Casts the delegator's signal unless they voted directly
+    fn cast_delegated_vote(
+        vote_id: T::VoteId,
+        org: T::OrgId,
+        delegator: &T::AccountId,
+        delegate: &T::AccountId,
+        direction: VoterView,
+        depth: u32,
+    ) {
+        if depth >= T::MaxDelegationDepth::get() {
+            return
+        }
+        let (old_vote, vote_state) = match (
+            <VoteLogger<T>>::get(vote_id, delegator),
+            <VoteStates<T>>::get(vote_id),
+        ) {
+            (Some(v), Some(s)) => (v, s),
+            _ => return,
+        };
+        let cast_by = <DelegatedVotes<T>>::get(vote_id, delegator);
+        let not_voted = old_vote.direction() == VoterView::Uninitialized
+            && cast_by.is_none();
+        if !(not_voted || cast_by.as_ref() == Some(delegate))
+            || old_vote.direction() == direction
+        {
+            return
+        }
+        if let Some(new_state) = Self::apply_vote(
+            vote_state,
+            old_vote.magnitude(),
+            old_vote.direction(),
+            direction,
+        ) {
+            <VoteStates<T>>::insert(vote_id, new_state);
+            <VoteLogger<T>>::insert(
+                vote_id,
+                delegator,
+                Vote::new(old_vote.magnitude(), direction, None),
+            );
+            <DelegatedVotes<T>>::insert(vote_id, delegator, delegate);
+            Self::delegators_of(vote_id, org, delegator)
+                .iter()
+                .for_each(|next| {
+                    Self::cast_delegated_vote(
+                        vote_id,
+                        org,
+                        next,
+                        delegator,
+                        direction,
+                        depth + 1,
+                    )
+                });
+        }
+    }
+    fn lock_for_conviction(
+        org: T::OrgId,
+        who: &T::AccountId,
+        unlock_at: T::BlockNumber,
+    ) -> DispatchResult {
+        match <ConvictionLocks<T>>::get(org, who) {
+            // existing lock already covers the new period
+            Some(existing) if existing >= unlock_at => return Ok(()),
+            Some(_) => (),
+            None => <org::Module<T>>::add_share_lock(org, who)?,
+        }
+        <ConvictionLocks<T>>::insert(org, who, unlock_at);
+        <LockExpiries<T>>::mutate(unlock_at, |expiring| {
+            expiring.push((org, who.clone()))
+        });
+        Ok(())
+    }
+    /// Opens a vote between the options, the signal of the organization's
+    /// members is minted as for binary votes
+    /// -> the vote always ends so the winner is eventually closed
+    pub fn open_multi_vote(
+        options: Vec<T::Cid>,
+        organization: OrgRep<T::OrgId>,
+        method: TallyMethod,
+        duration: T::BlockNumber,
+    ) -> Result<T::VoteId, DispatchError> {
+        ensure!(
+            options.len() >= 2,
+            Error::<T>::MultiVoteRequiresAtLeastTwoOptions
+        );
+        ensure!(
+            options.len() as u32 <= T::MaxOptions::get(),
+            Error::<T>::MultiVoteExceedsMaxOptions
+        );
+        let now = system::Module::<T>::block_number();
+        let ends = Some(now + duration);
+        let new_vote_id = Self::generate_unique_id();
+        let total_possible_turnout =
+            Self::mint_signal(new_vote_id, organization)?;
+        let new_vote_state = MultiVoteState::new(
+            options,
+            method,
+            total_possible_turnout,
+            now,
+            ends,
+        );
+        <MultiVoteStates<T>>::insert(new_vote_id, new_vote_state);
+        <VoteOrg<T>>::insert(new_vote_id, organization.org());
+        if let Some(end) = ends {
+            <VoteEndings<T>>::mutate(end, |v| v.push(new_vote_id));
+        }
+        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
+        <OpenVoteCounter>::put(new_vote_count);
+        Ok(new_vote_id)
+    }
+    /// The state of a binary vote for voting or delegating,
+    /// multi-option votes only accept ballots
+    fn binary_vote_state(
+        vote_id: T::VoteId,
+    ) -> Result<VoteSt<T>, DispatchError> {
+        <VoteStates<T>>::get(vote_id).ok_or_else(|| {
+            if <MultiVoteStates<T>>::contains_key(vote_id) {
+                Error::<T>::MultiVoteOnlyAcceptsBallots.into()
+            } else {
+                Error::<T>::NoVoteStateForVoteRequest.into()
+            }
+        })
+    }
+    /// Index of the winning option of the multi-option vote,
+    /// `None` if no signal was cast or first place is tied
+    pub fn multi_vote_winner(
+        vote_id: T::VoteId,
+    ) -> Result<Option<u32>, DispatchError> {
+        let vote_state = <MultiVoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForOutcomeQuery)?;
+        // ballots may have been pruned after the vote closed
+        if vote_state.outcome() != VoteOutcome::Voting {
+            return Ok(vote_state.winner())
+        }
+        let ballots = <Ballots<T>>::iter_prefix(vote_id)
+            .filter_map(|(voter, choices)| {
+                <VoteLogger<T>>::get(vote_id, voter)
+                    .map(|v| (v.magnitude(), choices))
+            })
+            .collect::<Vec<_>>();
+        Ok(vote_state.tally(&ballots))
+    }
+    /// Sets the final outcome of the vote ending at block `n`,
+    /// `None` if the vote does not exist or was extended past `n`
+    fn close_vote(
+        vote_id: T::VoteId,
+        n: T::BlockNumber,
+    ) -> Option<VoteOutcome> {
+        let outcome = if let Some(state) = <VoteStates<T>>::get(vote_id) {
+            if state.ends() != Some(n) {
+                return None
+            }
+            let closed = state.close();
+            let outcome = closed.outcome();
+            <VoteStates<T>>::insert(vote_id, closed);
+            outcome
+        } else {
+            let state = <MultiVoteStates<T>>::get(vote_id)?;
+            if state.ends() != Some(n) {
+                return None
+            }
+            let closed = state.close(Self::multi_vote_winner(vote_id).ok()?);
+            let outcome = closed.outcome();
+            <MultiVoteStates<T>>::insert(vote_id, closed);
+            outcome
+        };
+        <OpenVoteCounter>::mutate(|count| *count = count.saturating_sub(1));
+        if let Some(retention) = T::VoteRetentionPeriod::get() {
+            <VotePrunings<T>>::mutate(n + retention, |v| v.push(vote_id));
+        }
+        Some(outcome)
+    }
+    /// Removes the individual votes, ballots and delegations of a closed vote,
+    /// the final vote state is kept for outcome queries
+    fn prune_votes(vote_id: T::VoteId) {
+        <VoteLogger<T>>::remove_prefix(vote_id);
+        <Ballots<T>>::remove_prefix(vote_id);
+        <VoteDelegates<T>>::remove_prefix(vote_id);
+        <VoteDelegators<T>>::remove_prefix(vote_id);
+        <DelegatedVotes<T>>::remove_prefix(vote_id);
+        <TotalSignalIssuance<T>>::remove(vote_id);
+        <ConvictionVotes<T>>::remove(vote_id);
+    }
     fn generate_threshold_uid() -> T::ThresholdId {
         let mut thresh_counter = <ThresholdIdCounter<T>>::get() + 1u32.into();
         while <VoteThresholds<T>>::get(thresh_counter).is_some() {
@@ -313,13 +1059,14 @@ impl<T: Trait> Module<T> {
 impl<T: Trait> IDIsAvailable<T::VoteId> for Module<T> {
     fn id_is_available(id: T::VoteId) -> bool {
         <VoteStates<T>>::get(id).is_none()
+            && <MultiVoteStates<T>>::get(id).is_none()
     }
 }
 
 impl<T: Trait> GenerateUniqueID<T::VoteId> for Module<T> {
     fn generate_unique_id() -> T::VoteId {
         let mut id_counter = <VoteIdCounter<T>>::get() + 1u32.into();
-        while <VoteStates<T>>::get(id_counter).is_some() {
+        while !Self::id_is_available(id_counter) {
             id_counter += 1u32.into();
         }
         <VoteIdCounter<T>>::put(id_counter);
@@ -332,7 +1079,10 @@ impl<T: Trait> GetVoteOutcome<T::VoteId> for Module<T> {
     fn get_vote_outcome(
         vote_id: T::VoteId,
     ) -> Result<Self::Outcome, DispatchError> {
-        let vote_state = <VoteStates<T>>::get(vote_id)
+        if let Some(vote_state) = <VoteStates<T>>::get(vote_id) {
+            return Ok(vote_state.outcome())
+        }
+        let vote_state = <MultiVoteStates<T>>::get(vote_id)
             .ok_or(Error::<T>::NoVoteStateForOutcomeQuery)?;
         Ok(vote_state.outcome())
     }
@@ -394,15 +1144,9 @@ impl<T: Trait>
         };
         // generate new vote_id
         let new_vote_id = Self::generate_unique_id();
-        // by default, this call mints signal based on weighted ownership in group
-        let total_possible_turnout = match organization {
-            OrgRep::Weighted(org_id) => {
-                Self::batch_mint_signal(new_vote_id, org_id)?
-            }
-            OrgRep::Equal(org_id) => {
-                Self::batch_mint_equal_signal(new_vote_id, org_id)?
-            }
-        };
+        // mint signal for the group according to its representation
+        let total_possible_turnout =
+            Self::mint_signal(new_vote_id, organization)?;
         ensure!(
             Self::valid_signal_threshold(&threshold, total_possible_turnout),
             Error::<T>::InputThresholdExceedsBounds
@@ -412,6 +1156,10 @@ impl<T: Trait>
             VoteState::new(topic, total_possible_turnout, threshold, now, ends);
         // insert the VoteState
         <VoteStates<T>>::insert(new_vote_id, new_vote_state);
+        <VoteOrg<T>>::insert(new_vote_id, organization.org());
+        if let Some(end) = ends {
+            <VoteEndings<T>>::mutate(end, |v| v.push(new_vote_id));
+        }
         // increment open vote count
         let new_vote_count = <OpenVoteCounter>::get() + 1u32;
         <OpenVoteCounter>::put(new_vote_count);
@@ -432,15 +1180,9 @@ impl<T: Trait>
         };
         // generate new vote_id
         let new_vote_id = Self::generate_unique_id();
-        // by default, this call mints signal based on weighted ownership in group
-        let total_possible_turnout = match organization {
-            OrgRep::Weighted(org_id) => {
-                Self::batch_mint_signal(new_vote_id, org_id)?
-            }
-            OrgRep::Equal(org_id) => {
-                Self::batch_mint_equal_signal(new_vote_id, org_id)?
-            }
-        };
+        // mint signal for the group according to its representation
+        let total_possible_turnout =
+            Self::mint_signal(new_vote_id, organization)?;
         let signal_threshold =
             Self::from_permill_to_signal(&threshold, total_possible_turnout);
         ensure!(
@@ -460,6 +1202,10 @@ impl<T: Trait>
         );
         // insert the VoteState
         <VoteStates<T>>::insert(new_vote_id, new_vote_state);
+        <VoteOrg<T>>::insert(new_vote_id, organization.org());
+        if let Some(end) = ends {
+            <VoteEndings<T>>::mutate(end, |v| v.push(new_vote_id));
+        }
         // increment open vote count
         let new_vote_count = <OpenVoteCounter>::get() + 1u32;
         <OpenVoteCounter>::put(new_vote_count);
@@ -495,6 +1241,8 @@ impl<T: Trait> UpdateVote<T::VoteId, T::Cid, T::BlockNumber> for Module<T> {
             if e < new_end_time {
                 let nvs = pvs.set_ends(new_end_time);
                 <VoteStates<T>>::insert(vote_id, nvs);
+                // the close scheduled at the old end is skipped
+                <VoteEndings<T>>::mutate(new_end_time, |v| v.push(vote_id));
             }
         }
         Ok(())
@@ -542,6 +1290,26 @@ impl<T: Trait> MintableSignal<T::AccountId, T::OrgId, T::VoteId, T::Signal>
         <TotalSignalIssuance<T>>::insert(vote_id, total_minted);
         Ok(total_minted)
     }
+    /// Mints signal equal to the square root of each member's shares
+    /// -> dampens the influence of large holders
+    fn batch_mint_quadratic_signal(
+        vote_id: T::VoteId,
+        organization: T::OrgId,
+    ) -> Result<T::Signal, DispatchError> {
+        let new_vote_group =
+            <org::Module<T>>::get_membership_with_shape(organization)
+                .ok_or(Error::<T>::CannotMintSignalBecauseMembershipShapeDNE)?;
+        let mut total_minted = T::Signal::zero();
+        new_vote_group.vec().into_iter().for_each(|(who, shares)| {
+            let minted_signal: T::Signal = shares.integer_sqrt().into();
+            total_minted += minted_signal;
+            let new_vote =
+                Vote::new(minted_signal, VoterView::Uninitialized, None);
+            <VoteLogger<T>>::insert(vote_id, who, new_vote);
+        });
+        <TotalSignalIssuance<T>>::insert(vote_id, total_minted);
+        Ok(total_minted)
+    }
 }
 
 impl<T: Trait> ApplyVote<T::Cid> for Module<T> {
@@ -578,8 +1346,7 @@ impl<T: Trait> VoteOnProposal<T::AccountId, T::VoteId, T::Cid> for Module<T> {
         justification: Option<T::Cid>,
     ) -> DispatchResult {
         // get the vote state
-        let vote_state = <VoteStates<T>>::get(vote_id)
-            .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
+        let vote_state = Self::binary_vote_state(vote_id)?;
         // TODO: add permissioned method for adding time to the vote state because of this restriction but this is a legitimate restriction
         // -> every standard vote has a recognized end to establish when the decision must be made based on collected input
         ensure!(
@@ -589,6 +1356,17 @@ impl<T: Trait> VoteOnProposal<T::AccountId, T::VoteId, T::Cid> for Module<T> {
         // get the organization associated with this vote_state
         let old_vote = <VoteLogger<T>>::get(vote_id, voter.clone())
             .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        let delegated = <DelegatedVotes<T>>::contains_key(vote_id, &voter);
+        if delegated && old_vote.direction() == direction {
+            // voting directly in the delegate's direction claims the vote
+            <DelegatedVotes<T>>::remove(vote_id, &voter);
+            <VoteLogger<T>>::insert(
+                vote_id,
+                voter,
+                Vote::new(old_vote.magnitude(), direction, justification),
+            );
+            return Ok(())
+        }
         let new_vote = old_vote.set_new_view(direction, justification).ok_or(
             Error::<T>::OldVoteDirectionEqualsNewVoteDirectionSoNoChange,
         )?;
@@ -600,9 +1378,12 @@ impl<T: Trait> VoteOnProposal<T::AccountId, T::VoteId, T::Cid> for Module<T> {
         )
         .ok_or(Error::<T>::VoteChangeNotSupported)?;
         // set the new vote for the voter's profile
-        <VoteLogger<T>>::insert(vote_id, voter, new_vote);
+        <VoteLogger<T>>::insert(vote_id, &voter, new_vote);
         // commit new vote state to storage
         <VoteStates<T>>::insert(vote_id, new_state);
+        // a direct vote overrides the delegate
+        <DelegatedVotes<T>>::remove(vote_id, &voter);
+        Self::cast_delegated_votes(vote_id, &voter, direction, 0);
         Ok(())
     }
 }
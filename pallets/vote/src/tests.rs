@@ -5,6 +5,7 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::OnFinalize,
     weights::Weight,
 };
 use sp_core::H256;
@@ -13,12 +14,15 @@ use sp_runtime::{
     traits::IdentityLookup,
     Perbill,
 };
+use util::traits::LockProfile;
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
 
 impl_outer_origin! {
-    pub enum Origin for Test where system = frame_system {}
+    pub enum Origin for Test where system = frame_system {
+        org<T>,
+    }
 }
 
 #[derive(Clone, Eq, PartialEq)]
@@ -56,17 +60,57 @@ impl frame_system::Trait for Test {
     type BaseCallFilter = ();
     type SystemWeightInfo = ();
 }
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ElectionThreshold: Permill = Permill::from_percent(51);
+    pub const ElectionDuration: u64 = 5;
+    pub const SupervisorTermLength: u64 = 10;
+    pub const MaxConsecutiveTerms: u32 = 2;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type Cid = u32;
     type OrgId = u64;
     type Shares = u64;
+    type OrgOrigin = org::EnsureOrg<Test>;
+    type ElectionId = u64;
+    type SupervisorVote = Module<Test>;
+    type ElectionThreshold = ElectionThreshold;
+    type ElectionDuration = ElectionDuration;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveTerms = MaxConsecutiveTerms;
+    type Currency = Balances;
+    type OrderId = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const MaxDelegators: u32 = 3;
+    pub const MaxOptions: u32 = 5;
+    pub const VoteRetentionPeriod: Option<u64> = Some(10);
 }
 impl Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
     type ThresholdId = u64;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type MaxDelegationDepth = MaxDelegationDepth;
+    type MaxDelegators = MaxDelegators;
+    type MaxOptions = MaxOptions;
+    type VoteRetentionPeriod = VoteRetentionPeriod;
+    type WeightInfo = ();
 }
 
 mod vote {
@@ -76,14 +120,16 @@ mod vote {
 impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
+        pallet_balances<T>,
         org<T>,
         vote<T>,
     }
 }
 pub type System = system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
 pub type Vote = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -98,6 +144,14 @@ fn get_last_event() -> RawEvent<u64, u64, u64> {
         .unwrap()
 }
 
+/// Auxiliary method for simulating block time passing
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Vote::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+    }
+}
+
 fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::default()
         .build_storage::<Test>()
@@ -260,3 +314,433 @@ fn changing_votes_upholds_invariants() {
         assert_eq!(outcome_almost_passed, VoteOutcome::Approved);
     });
 }
+
+#[test]
+fn quadratic_vote_dampens_large_holders() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(org::Module::<Test>::register_weighted_org(
+            one.clone(),
+            Some(1),
+            None,
+            1739,
+            vec![(1, 81), (2, 9), (3, 9), (4, 9), (5, 9)],
+        ));
+        assert_ok!(Vote::create_percent_vote(
+            one.clone(),
+            None,
+            OrgRep::Quadratic(2),
+            Threshold::new(Permill::from_percent(50), None),
+            None
+        ));
+        // signal is the square root of shares
+        assert_eq!(Vote::total_signal_issuance(1), Some(21));
+        assert_eq!(Vote::vote_logger(1, 1).unwrap().magnitude(), 9);
+        assert_eq!(Vote::vote_logger(1, 2).unwrap().magnitude(), 3);
+        // the largest holder no longer passes the vote alone
+        assert_ok!(Vote::submit_vote(one, 1, VoterView::InFavor, None));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Voting);
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::get_vote_outcome(1).unwrap(), VoteOutcome::Approved);
+    });
+}
+
+#[test]
+fn conviction_vote_amplifies_signal_and_locks_shares() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        assert_ok!(Vote::create_conviction_vote(
+            one,
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(Permill::from_percent(51), None),
+            5
+        ));
+        // six members minted 1 signal each, scaled by the 6x maximum
+        assert_eq!(Vote::vote_states(1).unwrap().all_possible_turnout(), 36);
+        assert_ok!(Vote::submit_conviction_vote(
+            two.clone(),
+            1,
+            VoterView::InFavor,
+            Conviction::Locked3x,
+            None
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConvictionVoted(
+                1,
+                2,
+                VoterView::InFavor,
+                Conviction::Locked3x
+            )
+        );
+        assert_eq!(Vote::vote_logger(1, 2).unwrap().magnitude(), 3);
+        assert_eq!(Vote::vote_states(1).unwrap().in_favor(), 3);
+        // vote ends at block 6 and the lock lasts 3 periods of 10 blocks
+        assert_eq!(Vote::conviction_locks(1, 2), Some(36));
+        assert!(org::Module::<Test>::shares_locked(1, 2));
+        // the conviction lock leaves the member's profile state alone
+        assert!(org::Module::<Test>::members(1, 2).unwrap().is_unlocked());
+        assert_noop!(
+            Vote::submit_conviction_vote(
+                two,
+                1,
+                VoterView::Against,
+                Conviction::Locked6x,
+                None
+            ),
+            Error::<Test>::ConvictionOnlyAppliesToFirstVote
+        );
+        run_to_block(36);
+        assert!(org::Module::<Test>::shares_locked(1, 2));
+        run_to_block(37);
+        assert_eq!(get_last_event(), RawEvent::ConvictionLockExpired(1, 2));
+        assert!(Vote::conviction_locks(1, 2).is_none());
+        assert!(!org::Module::<Test>::shares_locked(1, 2));
+    });
+}
+
+#[test]
+fn conviction_lock_expiry_keeps_org_lock() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(org::Module::<Test>::lock_profile(1, &2));
+        assert_ok!(Vote::create_conviction_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(Permill::from_percent(51), None),
+            5
+        ));
+        assert_ok!(Vote::submit_conviction_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            Conviction::Locked2x,
+            None
+        ));
+        assert_eq!(Vote::conviction_locks(1, 2), Some(26));
+        run_to_block(27);
+        assert_eq!(get_last_event(), RawEvent::ConvictionLockExpired(1, 2));
+        // the lock set through the org outlives the conviction lock
+        assert!(!org::Module::<Test>::members(1, 2).unwrap().is_unlocked());
+        assert!(org::Module::<Test>::shares_locked(1, 2));
+    });
+}
+
+#[test]
+fn conviction_cannot_pass_majority_vote_with_minority_stake() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_percent_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(Permill::from_percent(51), None),
+            Some(5)
+        ));
+        // unscaled thresholds do not accept amplified signal
+        assert_noop!(
+            Vote::submit_conviction_vote(
+                Origin::signed(2),
+                1,
+                VoterView::InFavor,
+                Conviction::Locked6x,
+                None
+            ),
+            Error::<Test>::VoteDoesNotAcceptConviction
+        );
+        assert_ok!(Vote::create_conviction_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(Permill::from_percent(51), None),
+            5
+        ));
+        // one of six members at the maximum conviction
+        assert_ok!(Vote::submit_conviction_vote(
+            Origin::signed(2),
+            2,
+            VoterView::InFavor,
+            Conviction::Locked6x,
+            None
+        ));
+        assert_eq!(Vote::vote_states(2).unwrap().in_favor(), 6);
+        assert_eq!(Vote::get_vote_outcome(2), Ok(VoteOutcome::Voting));
+        // votes without conviction still count once
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            2,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::vote_states(2).unwrap().in_favor(), 7);
+        run_to_block(7);
+        assert_eq!(Vote::get_vote_outcome(2), Ok(VoteOutcome::Rejected));
+    });
+}
+
+#[test]
+fn delegated_signal_follows_delegate_until_overridden() {
+    new_test_ext().execute_with(|| {
+        // 4 -> 3 -> 2 for every vote of the org
+        assert_ok!(Vote::delegate(Origin::signed(3), 1, 2));
+        assert_eq!(get_last_event(), RawEvent::SignalDelegated(1, 3, 2));
+        assert_ok!(Vote::delegate(Origin::signed(4), 1, 3));
+        assert_noop!(
+            Vote::delegate(Origin::signed(3), 1, 3),
+            Error::<Test>::CannotDelegateToSelf
+        );
+        assert_noop!(
+            Vote::delegate(Origin::signed(2), 1, 4),
+            Error::<Test>::DelegationWouldFormCycle
+        );
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(5, None),
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(Vote::vote_states(1).unwrap().in_favor(), 3);
+        assert_eq!(Vote::delegated_votes(1, 4), Some(3));
+        // delegating for a single vote casts the delegate's existing vote
+        assert_ok!(Vote::delegate_vote(Origin::signed(5), 1, 2));
+        assert_eq!(Vote::vote_states(1).unwrap().in_favor(), 4);
+        // a direct vote overrides the delegate and carries its own delegators
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::Against,
+            None
+        ));
+        assert!(Vote::delegated_votes(1, 3).is_none());
+        assert_eq!(Vote::vote_states(1).unwrap().in_favor(), 2);
+        assert_eq!(Vote::vote_states(1).unwrap().against(), 2);
+        // delegators who voted directly no longer follow the delegate
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            None
+        ));
+        assert_eq!(Vote::vote_states(1).unwrap().in_favor(), 0);
+        assert_eq!(Vote::vote_states(1).unwrap().against(), 4);
+        assert_eq!(
+            Vote::vote_logger(1, 3).unwrap().direction(),
+            VoterView::Against
+        );
+    });
+}
+
+#[test]
+fn delegation_chains_are_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::delegate(Origin::signed(3), 1, 2));
+        assert_ok!(Vote::delegate(Origin::signed(4), 1, 3));
+        assert_ok!(Vote::delegate(Origin::signed(5), 1, 4));
+        // 6 -> 5 -> 4 -> 3 -> 2 exceeds the maximum depth of 3
+        assert_noop!(
+            Vote::delegate(Origin::signed(6), 1, 5),
+            Error::<Test>::DelegationChainExceedsMaxDepth
+        );
+        assert_noop!(
+            Vote::delegate(Origin::signed(22), 1, 5),
+            Error::<Test>::MustBeOrgMemberToDelegate
+        );
+        assert_ok!(Vote::undelegate(Origin::signed(5), 1));
+        assert_eq!(get_last_event(), RawEvent::SignalUndelegated(1, 5));
+        assert!(Vote::org_delegators(1, 4).is_empty());
+        assert_noop!(
+            Vote::undelegate(Origin::signed(5), 1),
+            Error::<Test>::NoDelegationToRemove
+        );
+    });
+}
+
+#[test]
+fn delegates_must_be_members_with_room_for_delegators() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Vote::delegate(Origin::signed(3), 1, 22),
+            Error::<Test>::DelegateMustBeOrgMember
+        );
+        assert_ok!(Vote::delegate(Origin::signed(3), 1, 2));
+        assert_ok!(Vote::delegate(Origin::signed(4), 1, 2));
+        assert_ok!(Vote::delegate(Origin::signed(5), 1, 2));
+        // 2 already has the maximum of 3 delegators
+        assert_noop!(
+            Vote::delegate(Origin::signed(6), 1, 2),
+            Error::<Test>::DelegateHasTooManyDelegators
+        );
+        // redelegating to the same delegate does not count twice
+        assert_ok!(Vote::delegate(Origin::signed(5), 1, 2));
+        assert_ok!(Vote::undelegate(Origin::signed(5), 1));
+        assert_ok!(Vote::delegate(Origin::signed(6), 1, 2));
+    });
+}
+
+#[test]
+fn ranked_choice_vote_eliminates_weakest_option() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Vote::create_multi_vote(
+                Origin::signed(1),
+                vec![10],
+                OrgRep::Equal(1),
+                TallyMethod::RankedChoice,
+                10
+            ),
+            Error::<Test>::MultiVoteRequiresAtLeastTwoOptions
+        );
+        assert_noop!(
+            Vote::create_multi_vote(
+                Origin::signed(1),
+                vec![10, 11, 12, 13, 14, 15],
+                OrgRep::Equal(1),
+                TallyMethod::RankedChoice,
+                10
+            ),
+            Error::<Test>::MultiVoteExceedsMaxOptions
+        );
+        assert_ok!(Vote::create_multi_vote(
+            Origin::signed(1),
+            vec![10, 11, 12],
+            OrgRep::Equal(1),
+            TallyMethod::RankedChoice,
+            10
+        ));
+        assert_eq!(get_last_event(), RawEvent::NewVoteStarted(1, 1));
+        assert_noop!(
+            Vote::submit_ballot(Origin::signed(1), 1, vec![0, 0]),
+            Error::<Test>::BallotInvalidForTallyMethod
+        );
+        assert_noop!(
+            Vote::submit_ballot(Origin::signed(1), 1, vec![3]),
+            Error::<Test>::BallotInvalidForTallyMethod
+        );
+        assert_noop!(
+            Vote::submit_ballot(Origin::signed(1), 1, vec![0, 1, 2, 0, 1, 2]),
+            Error::<Test>::BallotExceedsMaxOptions
+        );
+        vec![
+            (1, vec![0]),
+            (2, vec![0]),
+            (3, vec![1]),
+            (4, vec![1]),
+            (5, vec![2, 1]),
+        ]
+        .into_iter()
+        .for_each(|(voter, choices)| {
+            assert_ok!(Vote::submit_ballot(Origin::signed(voter), 1, choices));
+        });
+        assert_eq!(get_last_event(), RawEvent::BallotSubmitted(1, 5));
+        assert_eq!(Vote::multi_vote_states(1).unwrap().turnout(), 5);
+        // option 2 is eliminated and its ballot transfers to option 1
+        assert_eq!(Vote::multi_vote_winner(1), Ok(Some(1)));
+    });
+}
+
+#[test]
+fn plurality_vote_counts_single_choices() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_multi_vote(
+            Origin::signed(1),
+            vec![10, 11],
+            OrgRep::Equal(1),
+            TallyMethod::Plurality,
+            10
+        ));
+        assert_noop!(
+            Vote::submit_ballot(Origin::signed(1), 1, vec![0, 1]),
+            Error::<Test>::BallotInvalidForTallyMethod
+        );
+        // ballots only count minted signal
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(1), 1, VoterView::InFavor, None),
+            Error::<Test>::MultiVoteOnlyAcceptsBallots
+        );
+        assert_noop!(
+            Vote::delegate_vote(Origin::signed(2), 1, 1),
+            Error::<Test>::MultiVoteOnlyAcceptsBallots
+        );
+        assert_eq!(Vote::multi_vote_winner(1), Ok(None));
+        assert_ok!(Vote::submit_ballot(Origin::signed(1), 1, vec![0]));
+        assert_ok!(Vote::submit_ballot(Origin::signed(2), 1, vec![1]));
+        // first place is tied
+        assert_eq!(Vote::multi_vote_winner(1), Ok(None));
+        assert_ok!(Vote::submit_ballot(Origin::signed(3), 1, vec![0]));
+        assert_eq!(Vote::multi_vote_winner(1), Ok(Some(0)));
+        // replacing a ballot moves signal without adding turnout
+        assert_ok!(Vote::submit_ballot(Origin::signed(3), 1, vec![1]));
+        assert_eq!(Vote::multi_vote_states(1).unwrap().turnout(), 3);
+        assert_eq!(Vote::multi_vote_winner(1), Ok(Some(1)));
+        assert_eq!(Vote::open_vote_counter(), 1);
+        run_to_block(12);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteClosed(1, VoteOutcome::Approved)
+        );
+        assert_eq!(Vote::open_vote_counter(), 0);
+    });
+}
+
+#[test]
+fn expired_votes_close_and_prune_individual_votes() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Vote::create_signal_vote(
+            Origin::signed(1),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(4, None),
+            Some(5)
+        ));
+        assert_ok!(Vote::create_multi_vote(
+            Origin::signed(1),
+            vec![10, 11],
+            OrgRep::Equal(1),
+            TallyMethod::Plurality,
+            5
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_ballot(Origin::signed(1), 2, vec![0]));
+        assert_eq!(Vote::open_vote_counter(), 2);
+        run_to_block(6);
+        assert_eq!(Vote::get_vote_outcome(1), Ok(VoteOutcome::Voting));
+        run_to_block(7);
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteClosed(2, VoteOutcome::Approved)
+        );
+        // the threshold was never met
+        assert_eq!(Vote::get_vote_outcome(1), Ok(VoteOutcome::Rejected));
+        assert_eq!(Vote::open_vote_counter(), 0);
+        assert!(Vote::vote_logger(1, 1).is_some());
+        run_to_block(17);
+        assert!(Vote::vote_logger(1, 1).is_none());
+        assert!(Vote::ballots(2, 1).is_none());
+        assert_eq!(Vote::multi_vote_winner(2), Ok(Some(0)));
+        assert_eq!(Vote::get_vote_outcome(1), Ok(VoteOutcome::Rejected));
+    });
+}
@@ -3,8 +3,18 @@ use codec::{
     Decode,
     Encode,
 };
-use sp_runtime::traits::Zero;
-use sp_std::prelude::*;
+use sp_runtime::traits::{
+    CheckedAdd,
+    Zero,
+};
+use sp_std::{
+    ops::{
+        Div,
+        Rem,
+        Sub,
+    },
+    prelude::*,
+};
 
 #[derive(
     new, PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
@@ -53,6 +63,21 @@ impl<
             false
         }
     }
+    pub fn set_controller(&self, controller: Option<AccountId>) -> Self {
+        Self {
+            controller,
+            ..self.clone()
+        }
+    }
+}
+
+/// Changes to the bank itself which are executed if the vote with the bank threshold passes
+#[derive(Clone, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug)]
+pub enum BankProposal<AccountId> {
+    /// Closes the bank and distributes its funds among the org members
+    Close,
+    /// Replaces the controller, `None` leaves the bank without one
+    ChangeController(Option<AccountId>),
 }
 
 #[derive(
@@ -63,6 +88,54 @@ pub enum SpendState<VoteId> {
     Voting(VoteId),
     ApprovedButNotExecuted,
     ApprovedAndExecuted,
+    ApprovedAndScheduled,
+    Rejected,
+}
+
+/// When an approved spend pays out its amount
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub enum SpendSchedule<BlockNumber> {
+    /// Pays once the block is reached
+    At(BlockNumber),
+    /// Pays every period length for the number of remaining payments
+    Every(BlockNumber, u32),
+}
+
+impl<
+        BlockNumber: Copy
+            + PartialOrd
+            + Zero
+            + Rem<Output = BlockNumber>
+            + Sub<Output = BlockNumber>,
+    > SpendSchedule<BlockNumber>
+{
+    /// The first block from `from` on which a payment is due
+    /// -> recurring payments are due on the same blocks a drip of the same period pays out
+    pub fn next_due(&self, from: BlockNumber) -> BlockNumber {
+        match self {
+            SpendSchedule::At(block) if *block > from => *block,
+            SpendSchedule::At(_) => from,
+            SpendSchedule::Every(period_length, _) => {
+                let since_last = from % *period_length;
+                if since_last.is_zero() {
+                    from
+                } else {
+                    from + *period_length - since_last
+                }
+            }
+        }
+    }
+    /// The schedule after one payment, `None` if it was the last payment
+    pub fn paid(self) -> Option<Self> {
+        match self {
+            SpendSchedule::Every(period_length, left) if left > 1 => {
+                Some(SpendSchedule::Every(period_length, left - 1))
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(
@@ -118,3 +191,70 @@ impl<
         }
     }
 }
+
+/// A spending cap which resets every period
+#[derive(
+    new, Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub struct SpendCap<Currency, BlockNumber> {
+    cap: Currency,
+    period_length: BlockNumber,
+}
+
+impl<Currency: Copy, BlockNumber: Copy> SpendCap<Currency, BlockNumber> {
+    pub fn cap(&self) -> Currency {
+        self.cap
+    }
+    pub fn period_length(&self) -> BlockNumber {
+        self.period_length
+    }
+}
+
+/// Tracks the amount spent against a cap in the current period
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+pub struct Allowance<Currency, BlockNumber> {
+    cap: SpendCap<Currency, BlockNumber>,
+    // index of the period in which `spent` was spent
+    period: BlockNumber,
+    spent: Currency,
+}
+
+impl<
+        Currency: Copy + PartialOrd + Zero + CheckedAdd,
+        BlockNumber: Copy + PartialEq + Zero + Div<Output = BlockNumber>,
+    > Allowance<Currency, BlockNumber>
+{
+    pub fn new(cap: SpendCap<Currency, BlockNumber>) -> Self {
+        Self {
+            cap,
+            period: BlockNumber::zero(),
+            spent: Currency::zero(),
+        }
+    }
+    pub fn cap(&self) -> SpendCap<Currency, BlockNumber> {
+        self.cap
+    }
+    /// The amount spent so far in the period containing `now`
+    pub fn spent_at(&self, now: BlockNumber) -> Currency {
+        if now / self.cap.period_length() == self.period {
+            self.spent
+        } else {
+            Currency::zero()
+        }
+    }
+    /// The allowance after spending `amount` at `now`, `None` if it exceeds the cap
+    pub fn spend(&self, amount: Currency, now: BlockNumber) -> Option<Self> {
+        let spent = self.spent_at(now).checked_add(&amount)?;
+        if spent > self.cap.cap() {
+            None
+        } else {
+            Some(Self {
+                cap: self.cap,
+                period: now / self.cap.period_length(),
+                spent,
+            })
+        }
+    }
+}
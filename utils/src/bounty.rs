@@ -9,6 +9,8 @@ use sp_std::prelude::*;
 pub enum BountyState<VoteId> {
     NoPendingChallenges,
     ChallengedToClose(VoteId),
+    /// The refund approved by the vote could not be paid out
+    RefundFailed(VoteId),
 }
 
 impl<VoteId> Default for BountyState<VoteId> {
@@ -170,6 +172,7 @@ impl<
 /// All variants hold identifiers which point to larger objects in runtime storage maps
 pub enum SubmissionState {
     SubmittedAwaitingResponse,
+    PartiallyPaid,
     ApprovedAndExecuted,
 }
 
@@ -183,9 +186,15 @@ impl SubmissionState {
     pub fn awaiting_review(&self) -> bool {
         matches!(self, SubmissionState::SubmittedAwaitingResponse)
     }
+    pub fn partially_paid(&self) -> bool {
+        matches!(self, SubmissionState::PartiallyPaid)
+    }
     pub fn approved(&self) -> bool {
         matches!(self, SubmissionState::ApprovedAndExecuted)
     }
+    pub fn payable(&self) -> bool {
+        self.awaiting_review() || self.partially_paid()
+    }
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
@@ -194,6 +203,8 @@ pub enum SubmissionState2<BlockNumber, VoteId> {
     SubmittedAwaitingResponse,
     ApprovedAndScheduled(BlockNumber),
     ChallengedAndUnderReview(VoteId),
+    /// The approved payment could not be executed
+    PaymentFailed,
 }
 
 impl<BlockNumber: Copy, VoteId: Copy> Default
@@ -307,7 +318,20 @@ impl<
             ..self.clone()
         }
     }
+    pub fn revise(&self, submission_ref: IpfsReference, amount: Currency) -> Self {
+        BountySubmission {
+            submission_ref,
+            amount,
+            ..self.clone()
+        }
+    }
     pub fn state(&self) -> State {
         self.state
     }
+    pub fn set_state(&self, s: State) -> Self {
+        BountySubmission {
+            state: s,
+            ..self.clone()
+        }
+    }
 }
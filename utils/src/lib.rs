@@ -17,6 +17,7 @@ pub mod kickback;
 pub mod meta;
 pub mod moloch;
 pub mod organization;
+pub mod proposal;
 pub mod share;
 pub mod traits;
 pub mod vote;
@@ -12,6 +12,8 @@ pub enum OrgRep<OrgId> {
     Weighted(OrgId),
     // equal for all members
     Equal(OrgId),
+    // weighted by the square root of ownership
+    Quadratic(OrgId),
 }
 
 impl<OrgId: Copy> OrgRep<OrgId> {
@@ -19,6 +21,7 @@ impl<OrgId: Copy> OrgRep<OrgId> {
         match self {
             OrgRep::Weighted(o) => *o,
             OrgRep::Equal(o) => *o,
+            OrgRep::Quadratic(o) => *o,
         }
     }
 }
@@ -40,6 +43,9 @@ impl<AccountId: Clone + PartialEq, OrgId: Copy, IpfsRef: Clone>
     pub fn id(&self) -> OrgId {
         self.id
     }
+    pub fn sudo(&self) -> Option<AccountId> {
+        self.sudo.clone()
+    }
     pub fn constitution(&self) -> IpfsRef {
         self.constitution.clone()
     }
@@ -64,6 +70,24 @@ impl<AccountId: Clone + PartialEq, OrgId: Copy, IpfsRef: Clone>
     }
 }
 
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// A pending vote to elect a new supervisor or recall the current one
+pub struct SupervisorElection<AccountId, VoteId> {
+    /// The vote deciding the change
+    vote_id: VoteId,
+    /// The candidate to elect, `None` recalls the current supervisor
+    candidate: Option<AccountId>,
+}
+
+impl<AccountId: Clone, VoteId: Copy> SupervisorElection<AccountId, VoteId> {
+    pub fn vote_id(&self) -> VoteId {
+        self.vote_id
+    }
+    pub fn candidate(&self) -> Option<AccountId> {
+        self.candidate.clone()
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 /// The pieces of information used to register an organization in `org`
 pub enum OrganizationSource<AccountId, Shares> {
@@ -0,0 +1,31 @@
+use codec::{
+    Decode,
+    Encode,
+};
+use sp_runtime::RuntimeDebug;
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// A call the organization dispatches as itself if the vote passes
+pub struct Proposal<OrgId, AccountId, Call> {
+    // organization which dispatches the call
+    org: OrgId,
+    // member who made the proposal
+    proposer: AccountId,
+    // the call dispatched upon approval
+    call: Call,
+}
+
+impl<OrgId: Copy, AccountId: Clone, Call> Proposal<OrgId, AccountId, Call> {
+    pub fn org(&self) -> OrgId {
+        self.org
+    }
+    pub fn proposer(&self) -> AccountId {
+        self.proposer.clone()
+    }
+    pub fn call(self) -> Call {
+        self.call
+    }
+    pub fn call_ref(&self) -> &Call {
+        &self.call
+    }
+}
@@ -9,7 +9,11 @@ use codec::{
 };
 use frame_support::Parameter;
 use sp_runtime::{
-    traits::Zero,
+    traits::{
+        AtLeast32BitUnsigned,
+        SaturatedConversion,
+        Zero,
+    },
     RuntimeDebug,
 };
 use sp_std::prelude::*;
@@ -35,6 +39,143 @@ pub enum ProfileState {
     Unlocked,
 }
 
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+/// Restrictions on who may receive shares transferred between accounts
+pub enum TransferPolicy {
+    /// Shares may be transferred to any account
+    Unrestricted,
+    /// Shares may only be transferred to existing members
+    MembersOnly,
+    /// Every transfer must first be approved by the supervisor or the org
+    ApprovalRequired,
+}
+
+impl Default for TransferPolicy {
+    fn default() -> TransferPolicy {
+        TransferPolicy::Unrestricted
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// Offer to sell shares in an org at a fixed price per share
+pub struct SellOrder<OrgId, AccountId, Shares, Balance> {
+    org: OrgId,
+    seller: AccountId,
+    /// Shares still for sale
+    amount: Shares,
+    /// Price of one share in the native currency
+    price: Balance,
+}
+
+impl<
+        OrgId: Copy,
+        AccountId: Clone,
+        Shares: Copy + PartialOrd + sp_std::ops::Sub<Output = Shares> + Zero,
+        Balance: Copy,
+    > SellOrder<OrgId, AccountId, Shares, Balance>
+{
+    pub fn org(&self) -> OrgId {
+        self.org
+    }
+    pub fn seller(&self) -> AccountId {
+        self.seller.clone()
+    }
+    pub fn amount(&self) -> Shares {
+        self.amount
+    }
+    pub fn price(&self) -> Balance {
+        self.price
+    }
+    /// Removes `amount` from the order, `None` once nothing is left for sale
+    pub fn fill(self, amount: Shares) -> Option<Self> {
+        if amount >= self.amount {
+            None
+        } else {
+            Some(SellOrder {
+                amount: self.amount - amount,
+                ..self
+            })
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+/// Whether unvested shares count toward voting power
+pub enum VestingVotePolicy {
+    /// Only vested shares mint signal
+    VestedOnly,
+    /// Vested and unvested shares mint signal
+    AllShares,
+}
+
+impl Default for VestingVotePolicy {
+    fn default() -> VestingVotePolicy {
+        VestingVotePolicy::VestedOnly
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// Shares which unlock linearly from `start` until `start + duration`, none before the cliff
+pub struct VestingSchedule<AccountId, Shares, BlockNumber> {
+    /// Receives the unvested shares if the vesting is revoked, `None` for the org itself
+    issuer: Option<AccountId>,
+    total: Shares,
+    start: BlockNumber,
+    /// Blocks after `start` before any shares vest
+    cliff: BlockNumber,
+    /// Blocks after `start` until every share is vested
+    duration: BlockNumber,
+}
+
+impl<
+        AccountId: Clone,
+        Shares: Copy + AtLeast32BitUnsigned,
+        BlockNumber: Copy + AtLeast32BitUnsigned,
+    > VestingSchedule<AccountId, Shares, BlockNumber>
+{
+    pub fn issuer(&self) -> Option<AccountId> {
+        self.issuer.clone()
+    }
+    pub fn total(&self) -> Shares {
+        self.total
+    }
+    pub fn cliff_ends(&self) -> BlockNumber {
+        self.start.saturating_add(self.cliff)
+    }
+    pub fn vesting_ends(&self) -> BlockNumber {
+        self.start.saturating_add(self.duration)
+    }
+    pub fn vested_at(&self, now: BlockNumber) -> Shares {
+        if now < self.cliff_ends() {
+            Shares::zero()
+        } else if now >= self.vesting_ends() {
+            self.total
+        } else {
+            let elapsed: u128 = (now - self.start).saturated_into();
+            let duration: u128 = self.duration.saturated_into();
+            let total: u128 = self.total.saturated_into();
+            (total.saturating_mul(elapsed) / duration).saturated_into()
+        }
+    }
+    pub fn unvested_at(&self, now: BlockNumber) -> Shares {
+        self.total.saturating_sub(self.vested_at(now))
+    }
+    /// Takes `amount` out of the shares still unvested at `now`
+    /// -> the rest keeps vesting on the same timeline
+    pub fn reduce_unvested(self, now: BlockNumber, amount: Shares) -> Self {
+        let unvested: u128 = self.unvested_at(now).saturated_into();
+        if unvested == 0 {
+            return self
+        }
+        let left = unvested.saturating_sub(amount.saturated_into());
+        let total: u128 = self.total.saturated_into();
+        VestingSchedule {
+            total: (total.saturating_mul(left) / unvested).saturated_into(),
+            ..self
+        }
+    }
+}
+
 #[derive(new, PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
 /// share profile reserves the total share amount every time but (might) have a limit on total reservations
 pub struct ShareProfile<Shares, State> {
@@ -1,8 +1,18 @@
+use codec::FullCodec;
+use frame_support::traits::ExistenceRequirement;
 use sp_runtime::{
+    traits::{
+        AtLeast32BitUnsigned,
+        BadOrigin,
+        MaybeSerializeDeserialize,
+    },
     DispatchError,
     DispatchResult,
 };
-use sp_std::prelude::*;
+use sp_std::{
+    fmt::Debug,
+    prelude::*,
+};
 
 pub type Result<T> = sp_std::result::Result<T, DispatchError>;
 
@@ -33,6 +43,21 @@ pub trait OrganizationSupervisorPermissions<OrgId, AccountId> {
     ) -> DispatchResult;
 }
 
+/// Like `EnsureOrigin` but scoped to the organization the call acts for
+pub trait EnsureOrgOrigin<OuterOrigin, OrgId> {
+    type Success;
+    fn try_org_origin(
+        o: OuterOrigin,
+        org: OrgId,
+    ) -> sp_std::result::Result<Self::Success, OuterOrigin>;
+    fn ensure_org_origin(
+        o: OuterOrigin,
+        org: OrgId,
+    ) -> sp_std::result::Result<Self::Success, BadOrigin> {
+        Self::try_org_origin(o, org).map_err(|_| BadOrigin)
+    }
+}
+
 // ---------- Membership Logic ----------
 
 /// Checks that the `AccountId` is a member of a share group in an organization
@@ -227,6 +252,10 @@ pub trait MintableSignal<AccountId, OrgId, VoteId, Signal> {
         vote_id: VoteId,
         organization: OrgId,
     ) -> Result<Signal>;
+    fn batch_mint_quadratic_signal(
+        vote_id: VoteId,
+        organization: OrgId,
+    ) -> Result<Signal>;
 }
 
 pub trait VoteOnProposal<AccountId, VoteId, Hash>:
@@ -308,3 +337,54 @@ pub trait MolochMembership<AccountId, BankId, Currency, Shares, MProp> {
     fn poll_membership_proposal(prop: MProp) -> Result<Self::PropState>;
     fn _burn_shares(caller: AccountId, bank_id: BankId) -> DispatchResult;
 }
+
+// ~~~~~~~~ Tokens Module ~~~~~~~~
+
+/// Balances for any number of assets, keyed by `CurrencyId`
+pub trait MultiCurrency<AccountId> {
+    type CurrencyId: FullCodec
+        + Eq
+        + PartialEq
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + Default;
+    type Balance: AtLeast32BitUnsigned
+        + FullCodec
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + Default;
+    /// The identifier of the chain's native token
+    fn native_currency_id() -> Self::CurrencyId;
+    /// The existential deposit of the asset, zero if it has none
+    fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance;
+    fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance;
+    fn total_balance(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+    ) -> Self::Balance;
+    fn free_balance(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+    ) -> Self::Balance;
+    fn transfer(
+        currency_id: Self::CurrencyId,
+        from: &AccountId,
+        to: &AccountId,
+        amount: Self::Balance,
+        existence_requirement: ExistenceRequirement,
+    ) -> DispatchResult;
+    /// Mints `amount` into `who`, increasing total issuance
+    fn deposit(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+    /// Burns `amount` from `who`, decreasing total issuance
+    fn withdraw(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+}
@@ -32,6 +32,56 @@ impl Default for VoterView {
     }
 }
 
+#[derive(
+    Clone, Copy, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Lock multiplier chosen by the voter to amplify their signal
+pub enum Conviction {
+    /// Signal is not amplified and shares are not locked
+    None,
+    /// 2x signal, shares locked for 2 lock periods after the vote ends
+    Locked2x,
+    /// 3x signal, shares locked for 3 lock periods after the vote ends
+    Locked3x,
+    /// 4x signal, shares locked for 4 lock periods after the vote ends
+    Locked4x,
+    /// 5x signal, shares locked for 5 lock periods after the vote ends
+    Locked5x,
+    /// 6x signal, shares locked for 6 lock periods after the vote ends
+    Locked6x,
+}
+
+impl Default for Conviction {
+    fn default() -> Conviction {
+        Conviction::None
+    }
+}
+
+impl Conviction {
+    /// Factor applied to the voter's minted signal
+    pub fn multiplier(&self) -> u32 {
+        match self {
+            Conviction::None => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 3,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 5,
+            Conviction::Locked6x => 6,
+        }
+    }
+    /// Largest factor applied by any conviction
+    pub fn max_multiplier() -> u32 {
+        Conviction::Locked6x.multiplier()
+    }
+    /// Number of lock periods the voter's shares stay locked after the vote ends
+    pub fn lock_periods(&self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            c => c.multiplier(),
+        }
+    }
+}
+
 #[derive(
     new, Clone, Copy, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug,
 )]
@@ -246,6 +296,18 @@ impl<
     pub fn outcome(&self) -> VoteOutcome {
         self.outcome
     }
+    /// Final state once the vote ends, a vote that met no threshold is rejected
+    pub fn close(&self) -> Self {
+        let closed = self.set_outcome();
+        if closed.outcome == VoteOutcome::Voting {
+            VoteState {
+                outcome: VoteOutcome::Rejected,
+                ..closed
+            }
+        } else {
+            closed
+        }
+    }
     pub fn update_topic_and_clear_state(&self, new_topic: Hash) -> Self {
         VoteState {
             in_favor: 0u32.into(),
@@ -427,6 +489,177 @@ impl<
     }
 }
 
+#[derive(
+    Clone, Copy, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// How the ballots of a vote between several options are counted
+pub enum TallyMethod {
+    /// Each voter backs one option, the option with the most signal wins
+    Plurality,
+    /// Each voter backs any number of options with their full signal
+    Approval,
+    /// Voters rank options, the weakest option is eliminated until one holds a majority
+    RankedChoice,
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug)]
+/// The state of an ongoing vote between several options
+pub struct MultiVoteState<Signal, BlockNumber, Hash> {
+    /// The options, each anchored to offchain state
+    options: Vec<Hash>,
+    /// How ballots are counted
+    method: TallyMethod,
+    /// All signal that votes at all
+    turnout: Signal,
+    /// All signal that can vote
+    all_possible_turnout: Signal,
+    /// The time at which this vote state is initialized
+    initialized: BlockNumber,
+    /// The time at which this vote state expires
+    ends: Option<BlockNumber>,
+    /// The index of the winning option, set when the vote closes
+    winner: Option<u32>,
+    /// The vote outcome, approved if an option won when the vote closed
+    outcome: VoteOutcome,
+}
+
+impl<
+        Signal: Copy + From<u32> + PartialOrd + sp_std::ops::Add<Output = Signal>,
+        BlockNumber: Copy,
+        Hash: Clone,
+    > MultiVoteState<Signal, BlockNumber, Hash>
+{
+    pub fn new(
+        options: Vec<Hash>,
+        method: TallyMethod,
+        all_possible_turnout: Signal,
+        initialized: BlockNumber,
+        ends: Option<BlockNumber>,
+    ) -> MultiVoteState<Signal, BlockNumber, Hash> {
+        MultiVoteState {
+            options,
+            method,
+            turnout: 0u32.into(),
+            all_possible_turnout,
+            initialized,
+            ends,
+            winner: None,
+            outcome: VoteOutcome::Voting,
+        }
+    }
+    pub fn options(&self) -> Vec<Hash> {
+        self.options.clone()
+    }
+    pub fn method(&self) -> TallyMethod {
+        self.method
+    }
+    pub fn turnout(&self) -> Signal {
+        self.turnout
+    }
+    pub fn all_possible_turnout(&self) -> Signal {
+        self.all_possible_turnout
+    }
+    pub fn initialized(&self) -> BlockNumber {
+        self.initialized
+    }
+    pub fn ends(&self) -> Option<BlockNumber> {
+        self.ends
+    }
+    pub fn winner(&self) -> Option<u32> {
+        self.winner
+    }
+    pub fn outcome(&self) -> VoteOutcome {
+        self.outcome
+    }
+    /// Final state once the vote ends with the winning option of the tally
+    pub fn close(&self, winner: Option<u32>) -> Self {
+        let outcome = if winner.is_some() {
+            VoteOutcome::Approved
+        } else {
+            VoteOutcome::Rejected
+        };
+        MultiVoteState {
+            winner,
+            outcome,
+            ..self.clone()
+        }
+    }
+    pub fn add_turnout(&self, magnitude: Signal) -> Self {
+        MultiVoteState {
+            turnout: self.turnout + magnitude,
+            ..self.clone()
+        }
+    }
+    /// Choices are distinct option indices, plurality ballots back exactly one
+    pub fn valid_ballot(&self, choices: &[u32]) -> bool {
+        let in_range =
+            choices.iter().all(|c| (*c as usize) < self.options.len());
+        let distinct = choices
+            .iter()
+            .enumerate()
+            .all(|(i, c)| !choices[..i].contains(c));
+        let count_ok = match self.method {
+            TallyMethod::Plurality => choices.len() == 1,
+            _ => !choices.is_empty(),
+        };
+        in_range && distinct && count_ok
+    }
+    /// Index of the winning option for the weighted ballots,
+    /// `None` if no signal was cast or first place is tied
+    pub fn tally(&self, ballots: &[(Signal, Vec<u32>)]) -> Option<u32> {
+        let mut active: Vec<bool> = self.options.iter().map(|_| true).collect();
+        loop {
+            let mut counts: Vec<Signal> =
+                self.options.iter().map(|_| 0u32.into()).collect();
+            let mut total: Signal = 0u32.into();
+            ballots.iter().for_each(|(magnitude, choices)| {
+                let backed: Vec<&u32> = match self.method {
+                    TallyMethod::RankedChoice => {
+                        choices
+                            .iter()
+                            .find(|c| active[**c as usize])
+                            .into_iter()
+                            .collect()
+                    }
+                    _ => choices.iter().collect(),
+                };
+                backed.into_iter().for_each(|c| {
+                    counts[*c as usize] = counts[*c as usize] + *magnitude;
+                    total = total + *magnitude;
+                });
+            });
+            let remaining: Vec<usize> =
+                (0..active.len()).filter(|i| active[*i]).collect();
+            let mut ranked = remaining.clone();
+            ranked.sort_by(|a, b| {
+                counts[*b]
+                    .partial_cmp(&counts[*a])
+                    .unwrap_or(sp_std::cmp::Ordering::Equal)
+            });
+            let leader = *ranked.first()?;
+            let top = counts[leader];
+            if top == 0u32.into() {
+                return None
+            }
+            let unique =
+                ranked.get(1).map(|i| counts[*i] < top).unwrap_or(true);
+            if self.method != TallyMethod::RankedChoice {
+                return if unique { Some(leader as u32) } else { None }
+            }
+            if top + top > total {
+                return Some(leader as u32)
+            }
+            if remaining.len() <= 2 {
+                // no majority between the final two options is a tie
+                return None
+            }
+            // eliminate the weakest option, the later option among ties
+            let weakest = *ranked.last()?;
+            active[weakest] = false;
+        }
+    }
+}
+
 #[derive(
     PartialEq, Eq, Copy, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
 )]